@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+
+const WIDTHS: [usize; 4] = [64, 256, 1024, 4096];
+
+// Deterministic pseudo-random words, so that every run measures the same values.
+fn words(n: usize, seed: usize) -> Vec<usize> {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15 ^ seed as u64;
+
+    (0..n)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        })
+        .collect()
+}
+
+// A 2-state value, or a 4-state value with a few X/Z bits spread across its words.
+fn primlit(size: usize, seed: usize, xz: bool) -> SvPrimaryLiteralIntegral {
+    let n = size / usize::BITS as usize;
+
+    SvPrimaryLiteralIntegral {
+        data_01: words(n, seed),
+        data_xz: if xz {
+            Some((0..n).map(|x| 1 << (x % usize::BITS as usize)).collect())
+        } else {
+            None
+        },
+        size,
+        signed: false,
+    }
+}
+
+fn binary<F>(c: &mut Criterion, name: &str, f: F)
+where
+    F: Fn(&SvPrimaryLiteralIntegral, SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral,
+{
+    let mut group = c.benchmark_group(name);
+
+    for xz in [false, true] {
+        let state = if xz { "4state" } else { "2state" };
+
+        for width in WIDTHS {
+            let left = primlit(width, 1, xz);
+            let right = primlit(width, 2, xz);
+
+            group.bench_with_input(BenchmarkId::new(state, width), &width, |b, _| {
+                b.iter_batched(
+                    || right.clone(),
+                    |right| f(black_box(&left), black_box(right)),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn unary<F>(c: &mut Criterion, name: &str, f: F)
+where
+    F: Fn(&SvPrimaryLiteralIntegral, usize) -> SvPrimaryLiteralIntegral,
+{
+    let mut group = c.benchmark_group(name);
+
+    for xz in [false, true] {
+        let state = if xz { "4state" } else { "2state" };
+
+        for width in WIDTHS {
+            let value = primlit(width, 1, xz);
+
+            group.bench_with_input(BenchmarkId::new(state, width), &width, |b, width| {
+                b.iter(|| f(black_box(&value), black_box(*width)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn addition(c: &mut Criterion) {
+    binary(c, "add_primlit", |a, b| a.add_primlit(b));
+}
+
+fn multiplication(c: &mut Criterion) {
+    binary(c, "mul_unsigned", |a, b| a.mul_unsigned(b));
+}
+
+fn inversion(c: &mut Criterion) {
+    unary(c, "inv", |a, _| a.inv());
+}
+
+fn shifting(c: &mut Criterion) {
+    unary(c, "lsl", |a, width| a.lsl(width / 3));
+    unary(c, "lsr", |a, width| a.lsr(width / 3));
+}
+
+fn comparison(c: &mut Criterion) {
+    binary(c, "lt", |a, b| a.lt(b));
+    binary(c, "logical_eq", |a, b| a.logical_eq(b));
+}
+
+criterion_group!(
+    benches,
+    addition,
+    multiplication,
+    inversion,
+    shifting,
+    comparison
+);
+criterion_main!(benches);
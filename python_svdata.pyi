@@ -1,7 +1,11 @@
+from collections.abc import Callable, Iterator
+from typing import Any
 from enum import Enum
 
 SvPackedDimension = tuple[str, str]
 SvUnpackedDimension = tuple[str, str | None]
+SvAttribute = tuple[str, str | None]
+SvSpan = tuple[int, int]
 
 class SvParamType(Enum):
     Paramater = "Parameter"
@@ -14,9 +18,22 @@ class SvPortDirection(Enum):
     Ref = "Ref"
     IMPLICIT = "IMPLICIT"
 
+SvModportPort = tuple[str, SvPortDirection]
+
+class SvPortConnectionKind(Enum):
+    Explicit = "Explicit"
+    Implicit = "Implicit"
+    Wildcard = "Wildcard"
+
+class SvGenerateKind(Enum):
+    For = "For"
+    If = "If"
+    Else = "Else"
+
 class SvDataKind(Enum):
     Net = "Net"
     Variable = "Variable"
+    Interface = "Interface"
     IMPLICIT = "IMPLICIT"
 
 class SvDataType(Enum):
@@ -65,9 +82,56 @@ class SvNetType(Enum):
 
 class SvInstance:
     module_identifier: str
-    hierarchical_identifier: str
+    hierarchical_instance: str
     hierarchy: list[str]
+    parameter_overrides: list[list[str]]
+    parameter_values: list[SvPrimaryLiteralIntegral | None]
     connections: list[list[str]]
+    connection_kinds: list[SvPortConnectionKind]
+    span: SvSpan
+    def resolve_parameters(self, parameters: list[SvParameter]) -> list[SvParameter]: ...
+
+class SvGenerateBlock:
+    kind: SvGenerateKind
+    label: str | None
+    genvar: str | None
+    init: str | None
+    condition: str | None
+    step: str | None
+    count: int | None
+    indices: list[int] | None
+    instances: list[SvInstance]
+    signals: list[SvSignal]
+    blocks: list[SvGenerateBlock]
+    span: SvSpan
+
+class SvPrimaryLiteralIntegral:
+    data_01: list[int]
+    data_xz: list[int] | None
+    size: int
+    signed: bool
+    def __init__(self, size: int = 1, signed: bool = False) -> None: ...
+    @staticmethod
+    def from_binary_str(bits: str, signed: bool = False) -> SvPrimaryLiteralIntegral: ...
+    @staticmethod
+    def from_int(value: int, width: int, signed: bool = False) -> SvPrimaryLiteralIntegral: ...
+    def to_int(self) -> int: ...
+    def __add__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __sub__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __mul__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __lshift__(self, n: int) -> SvPrimaryLiteralIntegral: ...
+    def __rshift__(self, n: int) -> SvPrimaryLiteralIntegral: ...
+    def __and__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __or__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __xor__(self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral: ...
+    def __invert__(self) -> SvPrimaryLiteralIntegral: ...
+    def __neg__(self) -> SvPrimaryLiteralIntegral: ...
+    def __lt__(self, other: SvPrimaryLiteralIntegral) -> bool | None: ...
+    def __le__(self, other: SvPrimaryLiteralIntegral) -> bool | None: ...
+    def __gt__(self, other: SvPrimaryLiteralIntegral) -> bool | None: ...
+    def __ge__(self, other: SvPrimaryLiteralIntegral) -> bool | None: ...
+    def __eq__(self, other: object) -> bool | None: ...  # type: ignore[override]
+    def __ne__(self, other: object) -> bool | None: ...  # type: ignore[override]
 
 class SvParameter:
     identifier: str
@@ -82,6 +146,8 @@ class SvParameter:
     packed_dimensions: list[SvPackedDimension]
     unpacked_dimensions: list[SvUnpackedDimension]
     comment: list[str]
+    value: SvPrimaryLiteralIntegral | None
+    span: SvSpan
 
 class SvPort:
     identifier: str
@@ -93,23 +159,211 @@ class SvPort:
     signedness: SvSignedness | None
     packed_dimensions: list[SvPackedDimension]
     unpacked_dimensions: list[SvUnpackedDimension]
+    width: int | None
+    interface_name: str | None
+    modport_name: str | None
     comment: list[str] | None
+    attributes: list[SvAttribute]
+    doc_comment: str | None
+    span: SvSpan
+
+class SvSignal:
+    identifier: str
+    datakind: SvDataKind
+    datatype: str | None
+    packed_dimensions: list[SvPackedDimension]
+    unpacked_dimensions: list[SvUnpackedDimension]
+    signed: bool
+    span: SvSpan
+
+class SvTypedefKind(Enum):
+    Struct = "Struct"
+    Union = "Union"
+    Enum = "Enum"
+    Scalar = "Scalar"
+
+class SvTypedefMember:
+    identifier: str
+    datatype: str | None
+    packed_dimensions: list[SvPackedDimension]
+    unpacked_dimensions: list[SvUnpackedDimension]
+    expression: str | None
+    value: SvPrimaryLiteralIntegral | None
+
+class SvTypedef:
+    identifier: str
+    kind: SvTypedefKind
+    datatype: str | None
+    packed_dimensions: list[SvPackedDimension]
+    unpacked_dimensions: list[SvUnpackedDimension]
+    members: list[SvTypedefMember]
+    span: SvSpan
+
+class SvArgument:
+    identifier: str
+    direction: SvPortDirection
+    datatype: str
+
+class SvFunction:
+    identifier: str
+    return_type: str | None
+    arguments: list[SvArgument]
+    span: SvSpan
+
+class SvTask:
+    identifier: str
+    arguments: list[SvArgument]
+    span: SvSpan
 
 class SvModuleDeclaration:
     identifier: str
     parameters: list[SvParameter]
     ports: list[SvPort]
     instances: list[SvInstance]
+    typedefs: list[SvTypedef]
+    functions: list[SvFunction]
+    tasks: list[SvTask]
+    signals: list[SvSignal]
+    generate_blocks: list[SvGenerateBlock]
     filepath: str
     comments: list[str]
+    attributes: list[SvAttribute]
+    doc_comment: str | None
+    span: SvSpan
+    is_ansi: bool
+    def port_by_name(self, identifier: str) -> SvPort | None: ...
+    def inputs(self) -> list[SvPort]: ...
+    def outputs(self) -> list[SvPort]: ...
+    def inouts(self) -> list[SvPort]: ...
 
 class SvPackageDeclaration:
     identifier: str
     parameters: list[SvParameter]
+    typedefs: list[SvTypedef]
+    functions: list[SvFunction]
+    tasks: list[SvTask]
     filepath: str
+    span: SvSpan
+
+class SvModport:
+    identifier: str
+    ports: list[SvModportPort]
+
+class SvInterface:
+    identifier: str
+    ports: list[SvPort]
+    modports: list[SvModport]
+    filepath: str
+    span: SvSpan
+
+class SvClass:
+    identifier: str
+    extends: str | None
+    is_virtual: bool
+    parameters: list[SvParameter]
+    functions: list[SvFunction]
+    tasks: list[SvTask]
+    filepath: str
+    span: SvSpan
+
+class SvDirective:
+    name: str
+    arguments: list[str]
+    location: str
+    span: SvSpan
 
 class SvData:
     modules: list[SvModuleDeclaration]
     packages: list[SvPackageDeclaration]
+    interfaces: list[SvInterface]
+    classes: list[SvClass]
+    warnings: list[str]
+    directives: list[SvDirective]
+    def __iter__(self) -> Iterator[SvModuleDeclaration]: ...
+    def __len__(self) -> int: ...
+    def find_module(self, identifier: str) -> SvModuleDeclaration | None: ...
+    def hierarchy(self) -> SvHierarchy: ...
+    def elaborate(self, top: str) -> SvElaboratedDesign: ...
+    def merge(
+        self, other: SvData, overwrite: bool = False, strict: bool = False
+    ) -> None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    def diff(self, other: SvData) -> SvDiff: ...
+
+class SvHierarchy:
+    children: dict[str, list[str]]
+    black_boxes: dict[str, list[str]]
+    cycles: list[list[str]]
+
+class SvElaboratedInstance:
+    path: str
+    module_identifier: str
+    leaf: bool
+    black_box: bool
+
+class SvElaboratedDesign:
+    top: str
+    instances: list[SvElaboratedInstance]
+    def leaves(self) -> list[SvElaboratedInstance]: ...
+    def black_boxes(self) -> list[SvElaboratedInstance]: ...
+
+class SvModuleDiff:
+    identifier: str
+    added_ports: list[str]
+    removed_ports: list[str]
+    renamed_ports: list[tuple[str, str]]
+    retyped_ports: list[str]
+    added_parameters: list[str]
+    removed_parameters: list[str]
+    changed_parameters: list[str]
+    def is_empty(self) -> bool: ...
+
+class SvDiff:
+    added_modules: list[str]
+    removed_modules: list[str]
+    changed_modules: list[SvModuleDiff]
+    def is_empty(self) -> bool: ...
+
+class SvSource:
+    file_path: str
+    def __init__(
+        self,
+        file_path: str,
+        defines: dict[str, str | None] | None = None,
+        include_dirs: list[str] | None = None,
+    ) -> None: ...
+    def data(self, strict: bool = False) -> SvData: ...
+    def modules(self) -> list[SvModuleDeclaration]: ...
+    def packages(self) -> list[SvPackageDeclaration]: ...
+    def interfaces(self) -> list[SvInterface]: ...
+    def walk(self, callback: Callable[[str, str, bool], None]) -> None: ...
+
+class ParseError(ValueError): ...
 
-def read_sv_file(file_path: str) -> SvData: ...
+def read_sv_file(
+    file_path: str,
+    defines: dict[str, str | None] | None = None,
+    include_dirs: list[str] | None = None,
+    strict: bool = False,
+) -> SvData: ...
+def read_sv_files(
+    file_paths: list[str],
+    defines: dict[str, str | None] | None = None,
+    include_dirs: list[str] | None = None,
+    parallel: bool = False,
+    strict: bool = False,
+) -> SvData: ...
+def walk_sv(
+    file_path: str,
+    callback: Callable[[str, str, bool], None],
+    defines: dict[str, str | None] | None = None,
+    include_dirs: list[str] | None = None,
+) -> None: ...
+def line_column(file_path: str, offset: int) -> tuple[int, int] | None: ...
+def write_sv_data(svdata: SvData, path: str) -> None: ...
+def read_sv_data(path: str) -> SvData: ...
+def logic_0() -> SvPrimaryLiteralIntegral: ...
+def logic_1() -> SvPrimaryLiteralIntegral: ...
+def logic_x() -> SvPrimaryLiteralIntegral: ...
+def bit_0() -> SvPrimaryLiteralIntegral: ...
+def bit_1() -> SvPrimaryLiteralIntegral: ...
@@ -1,81 +1,734 @@
-use std::{collections::HashMap, path::PathBuf};
+#![recursion_limit = "256"]
 
-use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use pyo3::prelude::*;
+use rayon::prelude::*;
 use structures::{
-    SvData, SvDataKind, SvDataType, SvInstance, SvModuleDeclaration, SvNetType,
-    SvPackageDeclaration, SvParamType, SvParameter, SvPort, SvPortDirection, SvSignedness,
+    SvArgument, SvClass, SvData, SvDataKind, SvDataType, SvDiff, SvDirective, SvElaboratedDesign,
+    SvElaboratedInstance, SvFunction, SvGenerateBlock, SvGenerateKind, SvHierarchy, SvInstance,
+    SvInterface, SvModport, SvModuleDeclaration, SvModuleDiff, SvNetType, SvPackageDeclaration,
+    SvParamType, SvParameter, SvPort, SvPortConnectionKind, SvPortDirection, SvSignal,
+    SvSignedness, SvTask, SvTypedef, SvTypedefKind, SvTypedefMember,
 };
+use sv_class::class_declaration;
+use sv_directive::compiler_directive;
+use sv_error::{ParseError, SvError, SvErrorLocation};
+use sv_interface::interface_declaration;
+use sv_misc::{identifier, node_text};
 use sv_module::module_declaration_ansi;
 use sv_package::package_declaration;
-use sv_parser::{parse_sv, NodeEvent, RefNode, SyntaxTree};
+use sv_parser::{
+    parse_sv, unwrap_locate, unwrap_node, Define, DefineText, Defines, NodeEvent, RefNode,
+    SyntaxTree,
+};
+use sv_primlit_integral::{
+    py_bit_0, py_bit_1, py_logic_0, py_logic_1, py_logic_x, SvPrimaryLiteralIntegral,
+};
+use sv_source::SvSource;
 
 pub mod structures;
+pub mod sv_class;
+pub mod sv_const_eval;
+pub mod sv_diff;
+pub mod sv_directive;
+pub mod sv_error;
+pub mod sv_generate;
+pub mod sv_hierarchy;
 pub mod sv_instance;
+pub mod sv_interface;
 pub mod sv_misc;
 pub mod sv_module;
 pub mod sv_package;
 pub mod sv_port;
 pub mod sv_primlit;
 pub mod sv_primlit_integral;
+pub mod sv_signal;
+pub mod sv_source;
+pub mod sv_subroutine;
+pub mod sv_typedef;
 
 /// Reads a systemverilog file and returns an `SvData` object.
+///
+/// Args:
+///    file_path (str): The path to the systemverilog file.
+///    defines (dict[str, str | None] | None): Preprocessor defines, mapping each macro name to
+///        its replacement text. A value of `None` defines the macro without replacement text.
+///    include_dirs (list[str] | None): Directories that are searched, in order, for files
+///        included with a relative path.
+///    strict (bool): Whether a module that is declared twice in the file, or a construct that is
+///        recognized but not supported, e.g. a program, raises a `ValueError` rather than being
+///        recorded in the warnings. The error lists every unsupported construct in the file.
+///
+/// The GIL is released while the file is read, so other Python threads keep running.
 #[pyfunction]
-pub fn read_sv_file(file_path: &str) -> PyResult<SvData> {
-    let defines = HashMap::new();
-    let includes: Vec<PathBuf> = Vec::new();
+#[pyo3(signature = (file_path, defines = None, include_dirs = None, strict = false))]
+pub fn read_sv_file(
+    py: Python,
+    file_path: &str,
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+    strict: bool,
+) -> PyResult<SvData> {
+    Ok(py.allow_threads(|| parse_sv_file(file_path, defines, include_dirs, strict))?)
+}
+
+/// Reads a systemverilog file and returns an `SvData` object, or an `SvError` describing why
+/// the file could not be read.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+/// let svdata = parse_sv_file("tests/systemverilog/parameters_module.sv", None, None, false).unwrap();
+/// let parameters = &svdata.modules[1].parameters;
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![1024],
+///     data_xz: None,
+///     size: 32,
+///     signed: true,
+/// };
+///
+/// assert_eq!(parameters[0].value, Some(exp));
+/// assert_eq!(parameters[2].value, None);
+/// ```
+/// A module declared twice in the same file
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// let file_path = "tests/systemverilog/module_redefinition.sv";
+///
+/// let svdata = parse_sv_file(file_path, None, None, false).unwrap();
+/// assert_eq!(svdata.modules.len(), 2);
+/// assert_eq!(svdata.warnings.len(), 1);
+///
+/// match parse_sv_file(file_path, None, None, true) {
+///     Err(SvError::ModuleRedefinition { identifier, .. }) => assert_eq!(identifier, "redefined"),
+///     _ => panic!("Expected a ModuleRedefinition error!"),
+/// }
+/// ```
+pub fn parse_sv_file(
+    file_path: &str,
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+    strict: bool,
+) -> Result<SvData, SvError> {
+    let defines = sv_defines(defines.unwrap_or_default());
+    let includes = sv_includes(include_dirs.unwrap_or_default());
 
     let mut svdata = SvData {
         modules: Vec::new(),
         packages: Vec::new(),
+        interfaces: Vec::new(),
+        classes: Vec::new(),
+        warnings: Vec::new(),
+        directives: Vec::new(),
+    };
+
+    sv_parse(file_path, &defines, &includes, strict, &mut svdata)?;
+
+    Ok(svdata)
+}
+
+/// Walks the syntax tree of a systemverilog file, calling `callback(kind, text, enter)` when
+/// entering and again when leaving each node, in source order.
+///
+/// Args:
+///    file_path (str): The path to the systemverilog file.
+///    callback (Callable[[str, str, bool], None]): Called with the kind of the node, e.g.
+///        `ModuleIdentifier`, its source text, which includes any whitespace and comments that
+///        follow its last token, and whether the node is being entered rather than left.
+///    defines (dict[str, str | None] | None): Preprocessor defines, mapping each macro name to
+///        its replacement text. A value of `None` defines the macro without replacement text.
+///    include_dirs (list[str] | None): Directories that are searched, in order, for files
+///        included with a relative path.
+///
+/// An exception raised by the callback stops the walk and is raised again.
+#[pyfunction]
+#[pyo3(signature = (file_path, callback, defines = None, include_dirs = None))]
+pub fn walk_sv(
+    py: Python,
+    file_path: &str,
+    callback: PyObject,
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+) -> PyResult<()> {
+    walk_sv_file(
+        file_path,
+        defines,
+        include_dirs,
+        |kind, text, enter| -> PyResult<()> {
+            callback.call1(py, (kind, text, enter))?;
+            Ok(())
+        },
+    )
+}
+
+/// Walks the syntax tree of a systemverilog file, calling `visit(kind, text, enter)` when entering
+/// and again when leaving each node, in source order. This is the same traversal that
+/// `parse_sv_file` uses to extract the modules, packages and interfaces.
+///
+/// The walk stops at the first error returned by `visit`, which is returned. An `SvError`
+/// describing why the file could not be read is converted into the error type of `visit`.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::walk_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// let mut identifiers: Vec<String> = Vec::new();
+///
+/// walk_sv_file("tests/systemverilog/port_directions.sv", None, None, |kind, text, enter| {
+///     if enter && kind == "PortIdentifier" {
+///         identifiers.push(text.trim().to_string());
+///     }
+///     Ok::<(), SvError>(())
+/// })
+/// .unwrap();
+///
+/// assert_eq!(identifiers[..5], ["a", "b", "c", "d", "e"]);
+/// ```
+pub fn walk_sv_file<E, F>(
+    file_path: &str,
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+    visit: F,
+) -> Result<(), E>
+where
+    E: From<SvError>,
+    F: FnMut(&str, &str, bool) -> Result<(), E>,
+{
+    let defines = sv_defines(defines.unwrap_or_default());
+    let includes = sv_includes(include_dirs.unwrap_or_default());
+    let (syntax_tree, _) = sv_syntax_tree(file_path, &defines, &includes)?;
+
+    sv_walk_text(&syntax_tree, visit)
+}
+
+// Walks the syntax tree, calling visit with the kind and source text of each node
+fn sv_walk_text<E, F>(syntax_tree: &SyntaxTree, mut visit: F) -> Result<(), E>
+where
+    F: FnMut(&str, &str, bool) -> Result<(), E>,
+{
+    sv_walk(syntax_tree, |node, enter| {
+        let text = node_text(node.clone(), syntax_tree).unwrap_or_default();
+        visit(&node.to_string(), text, enter)
+    })
+}
+
+/// Converts a byte offset within a file, e.g. either end of a `span`, to a line and column.
+///
+/// Args:
+///    file_path (str): The path to the file.
+///    offset (int): The byte offset within the file.
+///
+/// Returns `(line, column)`, both counted from 1, or `None` if the file cannot be read or the
+/// offset lies outside of it.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::{line_column, parse_sv_file};
+/// let svdata = parse_sv_file("tests/systemverilog/ansi_module.sv", None, None, false).unwrap();
+/// let port = &svdata.modules[0].ports[0];
+///
+/// assert_eq!(port.span, (25, 42));
+/// assert_eq!(line_column("tests/systemverilog/ansi_module.sv", port.span.0), Some((2, 3)));
+/// ```
+#[pyfunction]
+pub fn line_column(file_path: &str, offset: usize) -> Option<(usize, usize)> {
+    SvErrorLocation::from_file(Path::new(file_path), offset).map(|x| (x.line, x.column))
+}
+
+/// Reads a list of systemverilog files and returns a single `SvData` object covering all of
+/// them.
+///
+/// Args:
+///    file_paths (list[str]): The paths to the systemverilog files.
+///    defines (dict[str, str | None] | None): Preprocessor defines, mapping each macro name to
+///        its replacement text. A value of `None` defines the macro without replacement text.
+///    include_dirs (list[str] | None): Directories that are searched, in order, for files
+///        included with a relative path.
+///    parallel (bool): Whether the files are read in parallel.
+///    strict (bool): Whether a module or package that is declared twice, in one file or in two,
+///        or a construct that is recognized but not supported, raises a `ValueError` rather than
+///        being recorded in the warnings. See `read_sv_file`.
+///
+/// The files are read in order, and macros defined in a file are also defined in the files that
+/// follow it. When the files are read in parallel, each file only sees the given `defines`, but
+/// the modules, packages and interfaces are still returned in the order of `file_paths`.
+///
+/// The GIL is released while the files are read, so other Python threads keep running.
+#[pyfunction]
+#[pyo3(signature = (file_paths, defines = None, include_dirs = None, parallel = false, strict = false))]
+pub fn read_sv_files(
+    py: Python,
+    file_paths: Vec<String>,
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+    parallel: bool,
+    strict: bool,
+) -> PyResult<SvData> {
+    Ok(py.allow_threads(|| parse_sv_files(&file_paths, defines, include_dirs, parallel, strict))?)
+}
+
+/// Reads a list of systemverilog files and returns a single `SvData` object, or an `SvError`
+/// describing why the files could not be read.
+///
+/// # Examples
+///
+/// A module declared in two files, which is a warning unless `strict` is set
+/// ```
+/// # use python_svdata::parse_sv_files;
+/// # use python_svdata::sv_error::SvError;
+/// let file_paths = vec![
+///     String::from("tests/systemverilog/ansi_module.sv"),
+///     String::from("tests/systemverilog/duplicate_module.sv"),
+/// ];
+///
+/// let svdata = parse_sv_files(&file_paths, None, None, false, false).unwrap();
+/// assert_eq!(svdata.modules.len(), 2);
+/// assert_eq!(svdata.warnings.len(), 1);
+///
+/// match parse_sv_files(&file_paths, None, None, false, true) {
+///     Err(SvError::DuplicateModule { identifier, .. }) => assert_eq!(identifier, "ansi_module_a"),
+///     _ => panic!("Expected a DuplicateModule error!"),
+/// }
+/// ```
+/// Files read in parallel
+/// ```
+/// # use python_svdata::parse_sv_files;
+/// let file_paths = vec![
+///     String::from("tests/systemverilog/ansi_module.sv"),
+///     String::from("tests/systemverilog/interface.sv"),
+/// ];
+///
+/// let svdata = parse_sv_files(&file_paths, None, None, true, false).unwrap();
+///
+/// assert_eq!(svdata.modules[0].identifier, "ansi_module_a");
+/// assert_eq!(svdata.interfaces[0].identifier, "bus_if");
+/// ```
+pub fn parse_sv_files(
+    file_paths: &[String],
+    defines: Option<HashMap<String, Option<String>>>,
+    include_dirs: Option<Vec<String>>,
+    parallel: bool,
+    strict: bool,
+) -> Result<SvData, SvError> {
+    let mut defines = sv_defines(defines.unwrap_or_default());
+    let includes = sv_includes(include_dirs.unwrap_or_default());
+
+    let mut ret = SvData {
+        modules: Vec::new(),
+        packages: Vec::new(),
+        interfaces: Vec::new(),
+        classes: Vec::new(),
+        warnings: Vec::new(),
+        directives: Vec::new(),
     };
 
-    if let Ok((syntax_tree, _)) = parse_sv(&file_path, &defines, &includes, true, false) {
-        sv_to_structure(&syntax_tree, &file_path, &mut svdata);
+    if parallel {
+        // The results are collected in the order of the files, so that merging them, and the
+        // error that is reported, does not depend on which file finished first.
+        let parsed: Vec<Result<SvData, SvError>> = file_paths
+            .par_iter()
+            .map(|file_path| {
+                let mut svdata = SvData {
+                    modules: Vec::new(),
+                    packages: Vec::new(),
+                    interfaces: Vec::new(),
+                    classes: Vec::new(),
+                    warnings: Vec::new(),
+                    directives: Vec::new(),
+                };
+
+                sv_parse(file_path, &defines, &includes, strict, &mut svdata)?;
+                Ok(svdata)
+            })
+            .collect();
+
+        for svdata in parsed {
+            ret.merge(svdata?, false, strict)?;
+        }
     } else {
-        Err(PyValueError::new_err(format!(
-            "Could not parse {}.",
-            file_path
-        )))?
+        for file_path in file_paths {
+            let mut svdata = SvData {
+                modules: Vec::new(),
+                packages: Vec::new(),
+                interfaces: Vec::new(),
+                classes: Vec::new(),
+                warnings: Vec::new(),
+                directives: Vec::new(),
+            };
+
+            defines = sv_parse(file_path, &defines, &includes, strict, &mut svdata)?;
+            ret.merge(svdata, false, strict)?;
+        }
     }
 
-    Ok(svdata)
+    Ok(ret)
 }
 
-fn sv_to_structure(syntax_tree: &SyntaxTree, filepath: &str, svdata: &mut SvData) -> () {
-    for event in syntax_tree.into_iter().event() {
-        let enter_not_leave = match event {
-            NodeEvent::Enter(_) => true,
-            NodeEvent::Leave(_) => false,
-        };
-        let node = match event {
-            NodeEvent::Enter(x) => x,
-            NodeEvent::Leave(x) => x,
-        };
-
-        if enter_not_leave {
-            match node {
-                RefNode::ModuleDeclarationAnsi(_) => {
-                    svdata
-                        .modules
-                        .push(module_declaration_ansi(node, syntax_tree, filepath).clone());
-                }
-                RefNode::PackageDeclaration(_) => {
-                    svdata
-                        .packages
-                        .push(package_declaration(node, syntax_tree, filepath).clone());
+/// Writes an `SvData` object to a file, in the format given by the extension of `path`.
+///
+/// Args:
+///    svdata (SvData): The data to write, e.g. as returned by `read_sv_file`.
+///    path (str): The path to the file, which must end in `.json`. An existing file is
+///        replaced.
+///
+/// The data is written as indented JSON, using the same keys as `SvData.to_dict`, and can be
+/// read back with `read_sv_data`.
+#[pyfunction]
+pub fn write_sv_data(py: Python, svdata: SvData, path: &str) -> PyResult<()> {
+    Ok(py.allow_threads(|| save_sv_data(&svdata, path))?)
+}
+
+/// Reads an `SvData` object from a file written by `write_sv_data`.
+///
+/// Args:
+///    path (str): The path to the file, which must end in `.json`.
+#[pyfunction]
+pub fn read_sv_data(py: Python, path: &str) -> PyResult<SvData> {
+    Ok(py.allow_threads(|| load_sv_data(path))?)
+}
+
+/// Writes an `SvData` object to a file, or returns an `SvError` describing why it could not be
+/// written.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::{load_sv_data, parse_sv_file, save_sv_data};
+/// let svdata = parse_sv_file("tests/systemverilog/ansi_module.sv", None, None, false).unwrap();
+/// let path = std::env::temp_dir().join("python_svdata_save_sv_data.json");
+/// let path = path.to_str().unwrap();
+///
+/// save_sv_data(&svdata, path).unwrap();
+/// assert_eq!(load_sv_data(path).unwrap(), svdata);
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub fn save_sv_data(svdata: &SvData, path: &str) -> Result<(), SvError> {
+    sv_data_format(path)?;
+
+    let text = serde_json::to_string_pretty(svdata).map_err(|source| SvError::Json {
+        path: String::from(path),
+        source,
+    })?;
+
+    fs::write(path, text).map_err(|source| SvError::Io {
+        path: String::from(path),
+        source,
+    })
+}
+
+/// Reads an `SvData` object from a file written by `save_sv_data`, or returns an `SvError`
+/// describing why it could not be read. Fields that were added after the file was written are
+/// left empty.
+pub fn load_sv_data(path: &str) -> Result<SvData, SvError> {
+    sv_data_format(path)?;
+
+    let text = fs::read(path).map_err(|source| SvError::Io {
+        path: String::from(path),
+        source,
+    })?;
+
+    serde_json::from_slice(&text).map_err(|source| SvError::Json {
+        path: String::from(path),
+        source,
+    })
+}
+
+// Only JSON is supported, so any other extension is rejected before the file is touched
+fn sv_data_format(path: &str) -> Result<(), SvError> {
+    match Path::new(path).extension().and_then(|x| x.to_str()) {
+        Some(x) if x.eq_ignore_ascii_case("json") => Ok(()),
+        _ => Err(SvError::Format(String::from(path))),
+    }
+}
+
+// Parses a single file into svdata, returning the defines at the end of the file
+fn sv_parse(
+    file_path: &str,
+    defines: &Defines,
+    includes: &[PathBuf],
+    strict: bool,
+    svdata: &mut SvData,
+) -> Result<Defines, SvError> {
+    let (syntax_tree, defines) = sv_syntax_tree(file_path, defines, includes)?;
+    sv_to_structure(
+        &syntax_tree,
+        file_path,
+        strict,
+        svdata,
+        &mut SvSourceTexts::new(),
+    )?;
+
+    Ok(defines)
+}
+
+// Preprocesses and parses a single file, returning its syntax tree and the defines at the end of
+// the file
+fn sv_syntax_tree(
+    file_path: &str,
+    defines: &Defines,
+    includes: &[PathBuf],
+) -> Result<(SyntaxTree, Defines), SvError> {
+    match parse_sv(&file_path, defines, includes, false, false) {
+        Ok(x) => Ok(x),
+        Err(sv_parser::Error::File { source, .. }) => Err(SvError::Io {
+            path: String::from(file_path),
+            source,
+        }),
+        Err(sv_parser::Error::Include { source }) => Err(SvError::Include {
+            path: String::from(file_path),
+            include: include_path(&source),
+        }),
+        Err(sv_parser::Error::Parse(Some((path, pos))))
+        | Err(sv_parser::Error::Preprocess(Some((path, pos)))) => Err(SvError::Parse {
+            path: path.display().to_string(),
+            location: SvErrorLocation::from_file(&path, pos),
+        }),
+        Err(_) => Err(SvError::Parse {
+            path: String::from(file_path),
+            location: None,
+        }),
+    }
+}
+
+fn sv_includes(include_dirs: Vec<String>) -> Vec<PathBuf> {
+    include_dirs.into_iter().map(PathBuf::from).collect()
+}
+
+fn sv_defines(defines: HashMap<String, Option<String>>) -> Defines {
+    defines
+        .into_iter()
+        .map(|(identifier, text)| {
+            let define = Define::new(
+                identifier.clone(),
+                Vec::new(),
+                text.map(|text| DefineText::new(text, None)),
+            );
+            (identifier, Some(define))
+        })
+        .collect()
+}
+
+fn include_path(error: &sv_parser::Error) -> String {
+    match error {
+        sv_parser::Error::Include { source } => include_path(source),
+        sv_parser::Error::File { path, .. } => path.display().to_string(),
+        x => x.to_string(),
+    }
+}
+
+// The text of each file that a syntax tree originates from, so that every file is read at most
+// once to locate the nodes within it, or None if the file cannot be read
+type SvSourceTexts = HashMap<PathBuf, Option<String>>;
+
+fn sv_to_structure(
+    syntax_tree: &SyntaxTree,
+    filepath: &str,
+    strict: bool,
+    svdata: &mut SvData,
+    texts: &mut SvSourceTexts,
+) -> Result<(), SvError> {
+    // The location of each module, to report a module that is declared twice
+    let mut locations: HashMap<String, String> = HashMap::new();
+    // The constructs that are not supported, which are only collected in strict mode
+    let mut unsupported: Vec<String> = Vec::new();
+
+    sv_walk(
+        syntax_tree,
+        |node, enter_not_leave| -> Result<(), SvError> {
+            if enter_not_leave {
+                match node {
+                    RefNode::ModuleDeclarationAnsi(_) => {
+                        let location = sv_location(&node, syntax_tree, filepath, texts);
+                        let module = module_declaration_ansi(node, syntax_tree, filepath)?;
+
+                        match locations.get(&module.identifier) {
+                            Some(first) => {
+                                let error = SvError::ModuleRedefinition {
+                                    identifier: module.identifier.clone(),
+                                    first_location: first.clone(),
+                                    second_location: location,
+                                };
+
+                                if strict {
+                                    return Err(error);
+                                }
+                                svdata.warnings.push(error.to_string());
+                            }
+                            None => {
+                                locations.insert(module.identifier.clone(), location);
+                            }
+                        }
+
+                        svdata.modules.push(module);
+                    }
+                    RefNode::PackageDeclaration(_) => {
+                        svdata
+                            .packages
+                            .push(package_declaration(node, syntax_tree, filepath)?);
+                    }
+                    RefNode::InterfaceDeclaration(_) => {
+                        svdata
+                            .interfaces
+                            .push(interface_declaration(node, syntax_tree, filepath));
+                    }
+                    RefNode::ClassDeclaration(x) => {
+                        svdata
+                            .classes
+                            .push(class_declaration(x, syntax_tree, filepath)?);
+                    }
+                    RefNode::TimescaleCompilerDirective(_)
+                    | RefNode::DefaultNettypeCompilerDirective(_)
+                    | RefNode::CelldefineDriveCompilerDirective(_)
+                    | RefNode::EndcelldefineDriveCompilerDirective(_)
+                    | RefNode::ResetallCompilerDirective(_) => {
+                        let location = sv_location(&node, syntax_tree, filepath, texts);
+                        svdata
+                            .directives
+                            .push(compiler_directive(node, syntax_tree, location));
+                    }
+                    _ => {
+                        if let Some(warning) = sv_unsupported(&node, syntax_tree, filepath, texts) {
+                            if strict {
+                                unsupported.push(warning);
+                            } else {
+                                svdata.warnings.push(warning);
+                            }
+                        }
+                    }
                 }
-                _ => (),
+            }
+
+            Ok(())
+        },
+    )?;
+
+    if unsupported.is_empty() {
+        Ok(())
+    } else {
+        Err(SvError::Unsupported(unsupported))
+    }
+}
+
+// Calls visit when entering and again when leaving each node of the syntax tree, stopping at the
+// first error
+fn sv_walk<E, F>(syntax_tree: &SyntaxTree, mut visit: F) -> Result<(), E>
+where
+    F: FnMut(RefNode, bool) -> Result<(), E>,
+{
+    for event in syntax_tree.into_iter().event() {
+        match event {
+            NodeEvent::Enter(x) => visit(x, true)?,
+            NodeEvent::Leave(x) => visit(x, false)?,
+        }
+    }
+
+    Ok(())
+}
+
+// Describes a construct that is recognized but not extracted, e.g. a program, along with its
+// location
+fn sv_unsupported(
+    node: &RefNode,
+    syntax_tree: &SyntaxTree,
+    filepath: &str,
+    texts: &mut SvSourceTexts,
+) -> Option<String> {
+    let (kind, id) = match node {
+        RefNode::CheckerDeclaration(_) => {
+            ("Checker", unwrap_node!(node.clone(), CheckerIdentifier))
+        }
+        RefNode::ConfigDeclaration(_) => ("Config", unwrap_node!(node.clone(), ConfigIdentifier)),
+        RefNode::ProgramDeclaration(_) => {
+            ("Program", unwrap_node!(node.clone(), ProgramIdentifier))
+        }
+        RefNode::UdpDeclaration(_) => ("Primitive", unwrap_node!(node.clone(), UdpIdentifier)),
+        RefNode::BindDirective(_) => (
+            "Bind",
+            unwrap_node!(node.clone(), BindTargetScope, BindTargetInstance),
+        ),
+        RefNode::ModuleDeclarationNonansi(_) => (
+            "Non-ANSI module",
+            unwrap_node!(node.clone(), ModuleIdentifier),
+        ),
+        _ => return None,
+    };
+
+    let id = id
+        .and_then(|x| identifier(x, syntax_tree))
+        .unwrap_or_default();
+
+    Some(format!(
+        "{} {} in {} is not supported.",
+        kind,
+        id,
+        sv_location(node, syntax_tree, filepath, texts)
+    ))
+}
+
+// Describes where a node starts, e.g. "top.sv at line 3, column 1", falling back to the path of
+// the file when the position cannot be found
+fn sv_location(
+    node: &RefNode,
+    syntax_tree: &SyntaxTree,
+    filepath: &str,
+    texts: &mut SvSourceTexts,
+) -> String {
+    let origin = unwrap_locate!(node.clone()).and_then(|x| syntax_tree.get_origin(x));
+
+    match origin {
+        Some((path, pos)) => {
+            let text = texts
+                .entry(path.to_path_buf())
+                .or_insert_with(|| fs::read_to_string(path).ok());
+
+            match text
+                .as_deref()
+                .and_then(|x| SvErrorLocation::from_text(x, pos))
+            {
+                Some(x) => format!("{} at line {}, column {}", path.display(), x.line, x.column),
+                None => path.display().to_string(),
             }
         }
+        None => String::from(filepath),
     }
 }
 
 #[pymodule]
-fn python_svdata(_py: Python, m: &PyModule) -> PyResult<()> {
+fn python_svdata(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(read_sv_file, m)?)?;
+    m.add_function(wrap_pyfunction!(read_sv_files, m)?)?;
+    m.add_function(wrap_pyfunction!(walk_sv, m)?)?;
+    m.add_function(wrap_pyfunction!(line_column, m)?)?;
+    m.add_function(wrap_pyfunction!(write_sv_data, m)?)?;
+    m.add_function(wrap_pyfunction!(read_sv_data, m)?)?;
+    m.add_function(wrap_pyfunction!(py_logic_0, m)?)?;
+    m.add_function(wrap_pyfunction!(py_logic_1, m)?)?;
+    m.add_function(wrap_pyfunction!(py_logic_x, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bit_0, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bit_1, m)?)?;
+    m.add("ParseError", py.get_type::<ParseError>())?;
     m.add_class::<SvData>()?;
+    m.add_class::<SvSource>()?;
+    m.add_class::<SvHierarchy>()?;
+    m.add_class::<SvElaboratedDesign>()?;
+    m.add_class::<SvElaboratedInstance>()?;
+    m.add_class::<SvDiff>()?;
+    m.add_class::<SvModuleDiff>()?;
+    m.add_class::<SvDirective>()?;
     m.add_class::<SvModuleDeclaration>()?;
     m.add_class::<SvPort>()?;
+    m.add_class::<SvSignal>()?;
     m.add_class::<SvPortDirection>()?;
     m.add_class::<SvPackageDeclaration>()?;
     m.add_class::<SvParameter>()?;
@@ -85,6 +738,19 @@ fn python_svdata(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<SvDataType>()?;
     m.add_class::<SvNetType>()?;
     m.add_class::<SvInstance>()?;
+    m.add_class::<SvGenerateBlock>()?;
+    m.add_class::<SvGenerateKind>()?;
+    m.add_class::<SvPortConnectionKind>()?;
+    m.add_class::<SvInterface>()?;
+    m.add_class::<SvClass>()?;
+    m.add_class::<SvModport>()?;
+    m.add_class::<SvTypedef>()?;
+    m.add_class::<SvTypedefKind>()?;
+    m.add_class::<SvTypedefMember>()?;
+    m.add_class::<SvFunction>()?;
+    m.add_class::<SvTask>()?;
+    m.add_class::<SvArgument>()?;
+    m.add_class::<SvPrimaryLiteralIntegral>()?;
 
     Ok(())
 }
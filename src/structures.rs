@@ -1,4 +1,15 @@
+use crate::sv_diff::sv_diff;
+use crate::sv_error::SvError;
+use crate::sv_hierarchy::{sv_elaborate, sv_hierarchy};
+use crate::sv_instance::inst_resolve_parameters;
+use crate::sv_primlit_integral::SvPrimaryLiteralIntegral;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// This is the main data structure that is returned by the parser.
@@ -6,65 +17,887 @@ use std::fmt;
 /// Args:
 ///    modules (list[SvModuleDeclaration]): A list of all the modules in the file.
 ///    packages (list[SvPackageDeclaration]): A list of all the packages in the file.
-#[derive(Debug, Clone, PartialEq)]
+///    interfaces (list[SvInterface]): A list of all the interfaces in the file.
+///    classes (list[SvClass]): A list of all the classes in the file, including the classes
+///        declared in packages and modules.
+///    warnings (list[str]): A description of every construct that was recognized but not
+///        extracted, e.g. a program, along with its location.
+///    directives (list[SvDirective]): The compiler directives in the file, e.g. `timescale, in
+///        order of appearance.
+///
+/// Two `SvData` objects are equal when all of their modules, packages, interfaces, classes,
+/// warnings and directives are equal.
+/// `SvData` objects can be pickled, e.g. to cache the result of parsing a large design. A field
+/// that was added to a declaration after the first version of the saved format is empty when it
+/// is missing, so the data written or pickled by an older version can still be read.
+///
+/// Iterating over an `SvData` object yields its modules, and its length is the number of modules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass(module = "python_svdata")]
+pub struct SvData {
+    #[pyo3(get, set)]
+    pub modules: Vec<SvModuleDeclaration>,
+    #[pyo3(get, set)]
+    pub packages: Vec<SvPackageDeclaration>,
+    #[pyo3(get, set)]
+    pub interfaces: Vec<SvInterface>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub classes: Vec<SvClass>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub directives: Vec<SvDirective>,
+}
+#[pymethods]
+impl SvData {
+    #[new]
+    fn new() -> Self {
+        SvData {
+            modules: Vec::new(),
+            packages: Vec::new(),
+            interfaces: Vec::new(),
+            classes: Vec::new(),
+            warnings: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        format!(
+            "SvData(modules={}, packages={}, interfaces={}, classes={})",
+            self.modules.len(),
+            self.packages.len(),
+            self.interfaces.len(),
+            self.classes.len()
+        )
+    }
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python) -> PyObject {
+        match op {
+            CompareOp::Eq => (self == other).into_py(py),
+            CompareOp::Ne => (self != other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+    fn __iter__(&self, py: Python) -> PyResult<PyObject> {
+        let modules: PyObject = self.modules.clone().into_py(py);
+        Ok(modules.as_ref(py).iter()?.into())
+    }
+    fn __len__(&self) -> usize {
+        self.modules.len()
+    }
+    /// Returns the module with the given identifier, or `None` if there is no such module.
+    fn find_module(&self, identifier: &str) -> Option<SvModuleDeclaration> {
+        self.modules
+            .iter()
+            .find(|x| x.identifier == identifier)
+            .cloned()
+    }
+    /// Returns the hierarchy of the modules, resolving their instances against the modules and
+    /// interfaces of this `SvData` object.
+    pub fn hierarchy(&self) -> SvHierarchy {
+        sv_hierarchy(self)
+    }
+    /// Returns the modules which were added or removed in another `SvData` object, and the
+    /// changes to the ports and parameters of the modules which are declared in both.
+    #[pyo3(name = "diff")]
+    fn py_diff(&self, other: SvData) -> SvDiff {
+        self.diff(&other)
+    }
+    /// Returns every instance below the module `top`, with its full instance path and the module
+    /// that it instantiates.
+    ///
+    /// Raises a `ValueError` if `top` is not declared, or if a module below it instantiates
+    /// itself.
+    #[pyo3(name = "elaborate")]
+    fn py_elaborate(&self, top: &str) -> PyResult<SvElaboratedDesign> {
+        Ok(self.elaborate(top)?)
+    }
+    /// Appends the modules, packages, interfaces, classes, warnings and directives of another
+    /// `SvData` object.
+    ///
+    /// A module or package that is declared in both is kept twice and listed in the warnings, as
+    /// `read_sv_file` does for a module declared twice in one file. If `strict` is set, a
+    /// `ValueError` is raised instead. If `overwrite` is set, the declaration of the other
+    /// `SvData` object replaces the existing one.
+    #[pyo3(name = "merge", signature = (other, overwrite = false, strict = false))]
+    fn py_merge(&mut self, other: SvData, overwrite: bool, strict: bool) -> PyResult<()> {
+        Ok(self.merge(other, overwrite, strict)?)
+    }
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        match serde_json::to_vec(self) {
+            Ok(x) => Ok(PyBytes::new(py, &x)),
+            Err(x) => Err(PyValueError::new_err(x.to_string())),
+        }
+    }
+    fn __setstate__(&mut self, state: &PyBytes) -> PyResult<()> {
+        match serde_json::from_slice(state.as_bytes()) {
+            Ok(x) => {
+                *self = x;
+                Ok(())
+            }
+            Err(x) => Err(PyValueError::new_err(x.to_string())),
+        }
+    }
+    /// Returns the modules, packages, interfaces, classes, warnings and directives as nested dicts
+    /// and lists, using the snake_case field names as keys. Enums are given by the name of their
+    /// variant, e.g. `"Input"`.
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        match serde_json::to_value(self) {
+            Ok(x) => json_to_py(py, &x),
+            Err(x) => Err(PyValueError::new_err(x.to_string())),
+        }
+    }
+}
+impl SvData {
+    /// Compares this `SvData` object, the old design, with another, the new design. See
+    /// `sv_diff`.
+    pub fn diff(&self, other: &SvData) -> SvDiff {
+        sv_diff(self, other)
+    }
+    /// Elaborates the instances below the module `top`. See `sv_elaborate`.
+    pub fn elaborate(&self, top: &str) -> Result<SvElaboratedDesign, SvError> {
+        sv_elaborate(self, top)
+    }
+    /// Appends the modules, packages, interfaces, classes, warnings and directives of another `SvData` object, keeping their order.
+    ///
+    /// A module or package that is declared in both is kept twice and listed in the warnings, the
+    /// same as a module that is declared twice in one file. If `strict` is set, the first one is
+    /// returned as an error instead, in which case nothing is appended. If `overwrite` is set, the
+    /// declaration of `other` replaces the existing one in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use python_svdata::parse_sv_file;
+    /// # use python_svdata::sv_error::SvError;
+    /// let mut svdata = parse_sv_file("tests/systemverilog/ansi_module.sv", None, None, false).unwrap();
+    /// let duplicate = parse_sv_file("tests/systemverilog/duplicate_module.sv", None, None, false).unwrap();
+    ///
+    /// match svdata.clone().merge(duplicate.clone(), false, true) {
+    ///     Err(SvError::DuplicateModule { identifier, .. }) => assert_eq!(identifier, "ansi_module_a"),
+    ///     _ => panic!("Expected a DuplicateModule error!"),
+    /// }
+    ///
+    /// let mut merged = svdata.clone();
+    /// merged.merge(duplicate.clone(), false, false).unwrap();
+    /// assert_eq!(merged.modules.len(), 2);
+    /// assert_eq!(merged.warnings.len(), 1);
+    ///
+    /// svdata.merge(duplicate, true, true).unwrap();
+    /// assert_eq!(svdata.modules[0].filepath, "tests/systemverilog/duplicate_module.sv");
+    /// ```
+    pub fn merge(&mut self, other: SvData, overwrite: bool, strict: bool) -> Result<(), SvError> {
+        let mut duplicates: Vec<SvError> = Vec::new();
+
+        if !overwrite {
+            for (i, module) in other.modules.iter().enumerate() {
+                if let Some(x) = self
+                    .modules
+                    .iter()
+                    .chain(&other.modules[..i])
+                    .find(|x| x.identifier == module.identifier)
+                {
+                    duplicates.push(SvError::DuplicateModule {
+                        identifier: module.identifier.clone(),
+                        first_path: x.filepath.clone(),
+                        second_path: module.filepath.clone(),
+                    });
+                }
+            }
+
+            for (i, package) in other.packages.iter().enumerate() {
+                if let Some(x) = self
+                    .packages
+                    .iter()
+                    .chain(&other.packages[..i])
+                    .find(|x| x.identifier == package.identifier)
+                {
+                    duplicates.push(SvError::DuplicatePackage {
+                        identifier: package.identifier.clone(),
+                        first_path: x.filepath.clone(),
+                        second_path: package.filepath.clone(),
+                    });
+                }
+            }
+
+            if strict && !duplicates.is_empty() {
+                return Err(duplicates.remove(0));
+            }
+        }
+
+        for module in other.modules {
+            match self
+                .modules
+                .iter_mut()
+                .find(|x| overwrite && x.identifier == module.identifier)
+            {
+                Some(x) => *x = module,
+                None => self.modules.push(module),
+            }
+        }
+
+        for package in other.packages {
+            match self
+                .packages
+                .iter_mut()
+                .find(|x| overwrite && x.identifier == package.identifier)
+            {
+                Some(x) => *x = package,
+                None => self.packages.push(package),
+            }
+        }
+
+        self.interfaces.extend(other.interfaces);
+        self.classes.extend(other.classes);
+        self.warnings.extend(other.warnings);
+        self.warnings
+            .extend(duplicates.into_iter().map(|x| x.to_string()));
+        self.directives.extend(other.directives);
+
+        Ok(())
+    }
+}
+
+/// A compiler directive, e.g. `` `timescale 1ns / 1ps ``.
+///
+/// Args:
+///    name (str): The name of the directive without the backtick, e.g. `timescale`.
+///    arguments (list[str]): The arguments of the directive, e.g. `["1ns", "1ps"]`.
+///    location (str): The file, line and column of the directive.
+///    span (SvSpan): The byte offsets of the start and end of the directive in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvDirective {
+    #[pyo3(get, set)]
+    pub name: String,
+    #[pyo3(get, set)]
+    pub arguments: Vec<String>,
+    #[pyo3(get, set)]
+    pub location: String,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvDirective {
+    #[new]
+    fn new() -> Self {
+        SvDirective {
+            name: String::new(),
+            arguments: Vec::new(),
+            location: String::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// The module hierarchy of an `SvData` object.
+///
+/// Args:
+///    children (dict[str, list[str]]): For every module, the module identifiers of its instances,
+///        without duplicates and in order of instantiation.
+///    black_boxes (dict[str, list[str]]): For every module with black boxes, the module identifiers
+///        of its instances which are not declared as a module or interface.
+///    cycles (list[list[str]]): Every cycle of modules which instantiate themselves, as the path
+///        from a module back to itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvHierarchy {
+    #[pyo3(get, set)]
+    pub children: BTreeMap<String, Vec<String>>,
+    #[pyo3(get, set)]
+    pub black_boxes: BTreeMap<String, Vec<String>>,
+    #[pyo3(get, set)]
+    pub cycles: Vec<Vec<String>>,
+}
+#[pymethods]
+impl SvHierarchy {
+    #[new]
+    fn new() -> Self {
+        SvHierarchy {
+            children: BTreeMap::new(),
+            black_boxes: BTreeMap::new(),
+            cycles: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// The instances of a design, elaborated from its top module.
+///
+/// Args:
+///    top (str): The identifier of the top module.
+///    instances (list[SvElaboratedInstance]): The top module and every instance below it, depth
+///        first and in order of instantiation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvElaboratedDesign {
+    #[pyo3(get, set)]
+    pub top: String,
+    #[pyo3(get, set)]
+    pub instances: Vec<SvElaboratedInstance>,
+}
+#[pymethods]
+impl SvElaboratedDesign {
+    #[new]
+    fn new() -> Self {
+        SvElaboratedDesign {
+            top: String::new(),
+            instances: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+    /// Returns the instances without instances of their own, i.e. the leaves of the hierarchy,
+    /// including the black boxes.
+    pub fn leaves(&self) -> Vec<SvElaboratedInstance> {
+        self.instances.iter().filter(|x| x.leaf).cloned().collect()
+    }
+    /// Returns the instances of modules which are not declared as a module or interface.
+    pub fn black_boxes(&self) -> Vec<SvElaboratedInstance> {
+        self.instances
+            .iter()
+            .filter(|x| x.black_box)
+            .cloned()
+            .collect()
+    }
+}
+
+/// An instance of an elaborated design.
+///
+/// Args:
+///    path (str): The full instance path, starting with the top module, e.g. `top.u_sub.u_leaf`.
+///    module_identifier (str): The identifier of the module or interface that is instantiated.
+///    leaf (bool): Whether the instance has no instances of its own.
+///    black_box (bool): Whether the instantiated module is not declared as a module or interface,
+///        in which case the instance is also a leaf.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvElaboratedInstance {
+    #[pyo3(get, set)]
+    pub path: String,
+    #[pyo3(get, set)]
+    pub module_identifier: String,
+    #[pyo3(get, set)]
+    pub leaf: bool,
+    #[pyo3(get, set)]
+    pub black_box: bool,
+}
+#[pymethods]
+impl SvElaboratedInstance {
+    #[new]
+    fn new() -> Self {
+        SvElaboratedInstance {
+            path: String::new(),
+            module_identifier: String::new(),
+            leaf: true,
+            black_box: false,
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// The differences between two `SvData` objects, from the old to the new one.
+///
+/// Args:
+///    added_modules (list[str]): The modules which are only declared in the new `SvData` object.
+///    removed_modules (list[str]): The modules which are only declared in the old `SvData` object.
+///    changed_modules (list[SvModuleDiff]): The changes to the ports and parameters of the
+///        modules which are declared in both, for every module with changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvDiff {
+    #[pyo3(get, set)]
+    pub added_modules: Vec<String>,
+    #[pyo3(get, set)]
+    pub removed_modules: Vec<String>,
+    #[pyo3(get, set)]
+    pub changed_modules: Vec<SvModuleDiff>,
+}
+#[pymethods]
+impl SvDiff {
+    #[new]
+    fn new() -> Self {
+        SvDiff {
+            added_modules: Vec::new(),
+            removed_modules: Vec::new(),
+            changed_modules: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+    /// Whether the two `SvData` objects declare the same modules, with the same ports and
+    /// parameters.
+    pub fn is_empty(&self) -> bool {
+        self.added_modules.is_empty()
+            && self.removed_modules.is_empty()
+            && self.changed_modules.is_empty()
+    }
+}
+
+/// The changes to the ports and parameters of a module which is declared in two `SvData`
+/// objects.
+///
+/// Ports and parameters are matched by identifier. A port is retyped when its direction, kind,
+/// type, signedness, width or dimensions change. A port which is removed and replaced by a port
+/// with the same type at the same position is renamed. A parameter is changed when its type,
+/// signedness, number of bits or value change.
+///
+/// Args:
+///    identifier (str): The identifier of the module.
+///    added_ports (list[str]): The ports which are only declared by the new module.
+///    removed_ports (list[str]): The ports which are only declared by the old module.
+///    renamed_ports (list[tuple[str, str]]): The old and new identifier of every renamed port.
+///    retyped_ports (list[str]): The ports whose type changed.
+///    added_parameters (list[str]): The parameters which are only declared by the new module.
+///    removed_parameters (list[str]): The parameters which are only declared by the old module.
+///    changed_parameters (list[str]): The parameters whose type or value changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvModuleDiff {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub added_ports: Vec<String>,
+    #[pyo3(get, set)]
+    pub removed_ports: Vec<String>,
+    #[pyo3(get, set)]
+    pub renamed_ports: Vec<(String, String)>,
+    #[pyo3(get, set)]
+    pub retyped_ports: Vec<String>,
+    #[pyo3(get, set)]
+    pub added_parameters: Vec<String>,
+    #[pyo3(get, set)]
+    pub removed_parameters: Vec<String>,
+    #[pyo3(get, set)]
+    pub changed_parameters: Vec<String>,
+}
+#[pymethods]
+impl SvModuleDiff {
+    #[new]
+    fn new() -> Self {
+        SvModuleDiff {
+            identifier: String::new(),
+            added_ports: Vec::new(),
+            removed_ports: Vec::new(),
+            renamed_ports: Vec::new(),
+            retyped_ports: Vec::new(),
+            added_parameters: Vec::new(),
+            removed_parameters: Vec::new(),
+            changed_parameters: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+    /// Whether the ports and parameters of the module are unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.added_ports.is_empty()
+            && self.removed_ports.is_empty()
+            && self.renamed_ports.is_empty()
+            && self.retyped_ports.is_empty()
+            && self.added_parameters.is_empty()
+            && self.removed_parameters.is_empty()
+            && self.changed_parameters.is_empty()
+    }
+}
+
+/// Store the information about a module.
+///
+/// Args:
+///
+///   identifier (str): The name of the module.
+///   parameters (list[SvParameter]): A list of all the parameters in the module.
+///   ports (list[SvPort]): A list of all the ports in the module.
+///   instances (list[SvInstance]): A list of all the instances in the module.
+///   typedefs (list[SvTypedef]): A list of all the typedefs in the module.
+///   functions (list[SvFunction]): A list of all the functions in the module.
+///   tasks (list[SvTask]): A list of all the tasks in the module.
+///   signals (list[SvSignal]): A list of all the nets and variables declared in the body of the
+///     module, excluding the ports.
+///   generate_blocks (list[SvGenerateBlock]): A list of the outermost generate loops and
+///     conditionals in the module. Their instances and signals are listed in the blocks rather
+///     than in `instances` and `signals`.
+///   filepath (str): The path to the file that contains the module.
+///   comments (list[str]): A list of all the comments in the module.
+///   attributes (list[SvAttribute]): The attributes of the module, e.g. `(* keep = "true" *)`.
+///   doc_comment (str | None): The comment on the lines immediately above the module, without
+///     the comment markers.
+///   span (SvSpan): The byte offsets of the start and end of the module in its file.
+///   is_ansi (bool): Whether the ports of the module are declared in its header, e.g.
+///     `module top (input logic clk);`, rather than in its body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvModuleDeclaration {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub parameters: Vec<SvParameter>,
+    #[pyo3(get, set)]
+    pub ports: Vec<SvPort>,
+    #[pyo3(get, set)]
+    pub instances: Vec<SvInstance>,
+    #[pyo3(get, set)]
+    pub typedefs: Vec<SvTypedef>,
+    #[pyo3(get, set)]
+    pub functions: Vec<SvFunction>,
+    #[pyo3(get, set)]
+    pub tasks: Vec<SvTask>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub signals: Vec<SvSignal>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub generate_blocks: Vec<SvGenerateBlock>,
+    #[pyo3(get, set)]
+    pub filepath: String,
+    #[pyo3(get, set)]
+    pub comments: Vec<String>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub attributes: Vec<SvAttribute>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub doc_comment: Option<String>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+    #[pyo3(get, set)]
+    #[serde(default = "module_is_ansi_default")]
+    pub is_ansi: bool,
+}
+
+// Only ANSI modules were extracted before the declaration style was recorded
+fn module_is_ansi_default() -> bool {
+    true
+}
+
+#[pymethods]
+impl SvModuleDeclaration {
+    #[new]
+    fn new() -> Self {
+        SvModuleDeclaration {
+            identifier: String::new(),
+            parameters: Vec::new(),
+            ports: Vec::new(),
+            instances: Vec::new(),
+            typedefs: Vec::new(),
+            functions: Vec::new(),
+            tasks: Vec::new(),
+            signals: Vec::new(),
+            generate_blocks: Vec::new(),
+            filepath: String::new(),
+            comments: Vec::new(),
+            attributes: Vec::new(),
+            doc_comment: None,
+            span: (0, 0),
+            is_ansi: true,
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+    /// Returns the port with the given identifier, or `None` if there is no such port.
+    #[pyo3(name = "port_by_name")]
+    fn py_port_by_name(&self, identifier: &str) -> Option<SvPort> {
+        self.port_by_name(identifier).cloned()
+    }
+    /// Returns the input ports, in order of declaration.
+    #[pyo3(name = "inputs")]
+    fn py_inputs(&self) -> Vec<SvPort> {
+        self.inputs().into_iter().cloned().collect()
+    }
+    /// Returns the output ports, in order of declaration.
+    #[pyo3(name = "outputs")]
+    fn py_outputs(&self) -> Vec<SvPort> {
+        self.outputs().into_iter().cloned().collect()
+    }
+    /// Returns the inout ports, in order of declaration.
+    #[pyo3(name = "inouts")]
+    fn py_inouts(&self) -> Vec<SvPort> {
+        self.inouts().into_iter().cloned().collect()
+    }
+}
+
+impl SvModuleDeclaration {
+    /// Returns the port with the given identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use python_svdata::parse_sv_file;
+    /// # use python_svdata::structures::SvPortDirection;
+    /// let svdata = parse_sv_file("tests/systemverilog/port_directions.sv", None, None, false).unwrap();
+    /// let module = &svdata.modules[0];
+    ///
+    /// assert_eq!(module.port_by_name("c").unwrap().direction, SvPortDirection::Inout);
+    /// assert_eq!(module.port_by_name("f"), None);
+    /// ```
+    pub fn port_by_name(&self, identifier: &str) -> Option<&SvPort> {
+        self.ports.iter().find(|x| x.identifier == identifier)
+    }
+
+    /// Returns the input ports, in order of declaration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use python_svdata::parse_sv_file;
+    /// # use python_svdata::structures::SvPort;
+    /// let svdata = parse_sv_file("tests/systemverilog/port_directions.sv", None, None, false).unwrap();
+    /// let module = &svdata.modules[0];
+    ///
+    /// fn identifiers(ports: Vec<&SvPort>) -> Vec<&str> {
+    ///     ports.iter().map(|x| x.identifier.as_str()).collect()
+    /// }
+    ///
+    /// assert_eq!(identifiers(module.inputs()), vec!["a", "d"]);
+    /// assert_eq!(identifiers(module.outputs()), vec!["b", "e"]);
+    /// assert_eq!(identifiers(module.inouts()), vec!["c"]);
+    /// ```
+    pub fn inputs(&self) -> Vec<&SvPort> {
+        self.ports_with_direction(SvPortDirection::Input)
+    }
+
+    /// Returns the output ports, in order of declaration.
+    pub fn outputs(&self) -> Vec<&SvPort> {
+        self.ports_with_direction(SvPortDirection::Output)
+    }
+
+    /// Returns the inout ports, in order of declaration.
+    pub fn inouts(&self) -> Vec<&SvPort> {
+        self.ports_with_direction(SvPortDirection::Inout)
+    }
+
+    fn ports_with_direction(&self, direction: SvPortDirection) -> Vec<&SvPort> {
+        self.ports
+            .iter()
+            .filter(|x| x.direction == direction)
+            .collect()
+    }
+}
+
+/// Store the information about a package.
+///
+/// Args:
+///
+///    identifier (str): The name of the package.
+///    parameters (list[SvParameter]): A list of all the parameters in the package.
+///    typedefs (list[SvTypedef]): A list of all the typedefs in the package.
+///    functions (list[SvFunction]): A list of all the functions in the package.
+///    tasks (list[SvTask]): A list of all the tasks in the package.
+///    filepath (str): The path to the file that contains the package.
+///    span (SvSpan): The byte offsets of the start and end of the package in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvPackageDeclaration {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub parameters: Vec<SvParameter>,
+    #[pyo3(get, set)]
+    pub typedefs: Vec<SvTypedef>,
+    #[pyo3(get, set)]
+    pub functions: Vec<SvFunction>,
+    #[pyo3(get, set)]
+    pub tasks: Vec<SvTask>,
+    #[pyo3(get, set)]
+    pub filepath: String,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvPackageDeclaration {
+    #[new]
+    fn new() -> Self {
+        SvPackageDeclaration {
+            identifier: String::new(),
+            parameters: Vec::new(),
+            typedefs: Vec::new(),
+            functions: Vec::new(),
+            tasks: Vec::new(),
+            filepath: String::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about an interface.
+///
+/// Args:
+///
+///    identifier (str): The name of the interface.
+///    ports (list[SvPort]): A list of all the ports in the interface.
+///    modports (list[SvModport]): A list of all the modports in the interface.
+///    filepath (str): The path to the file that contains the interface.
+///    span (SvSpan): The byte offsets of the start and end of the interface in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvInterface {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub ports: Vec<SvPort>,
+    #[pyo3(get, set)]
+    pub modports: Vec<SvModport>,
+    #[pyo3(get, set)]
+    pub filepath: String,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvInterface {
+    #[new]
+    fn new() -> Self {
+        SvInterface {
+            identifier: String::new(),
+            ports: Vec::new(),
+            modports: Vec::new(),
+            filepath: String::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about a modport.
+///
+/// Args:
+///
+///    identifier (str): The name of the modport.
+///    ports (list[SvModportPort]): A list of all the signals exposed by the modport.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvModport {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub ports: Vec<SvModportPort>,
+}
+#[pymethods]
+impl SvModport {
+    #[new]
+    fn new() -> Self {
+        SvModport {
+            identifier: String::new(),
+            ports: Vec::new(),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about a typedef.
+///
+/// Args:
+///
+///    identifier (str): The name of the type.
+///    kind (SvTypedefKind): The kind of the type.
+///    datatype (str | None): The base type of an enum, or the type of a scalar, as written in the source.
+///    packed_dimensions (list[SvPackedDimension]): A list of all the packed dimensions of the type.
+///    unpacked_dimensions (list[SvUnpackedDimension]): A list of all the unpacked dimensions of the type.
+///    members (list[SvTypedefMember]): A list of all the members of a struct, union or enum.
+///    span (SvSpan): The byte offsets of the start and end of the typedef in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
-pub struct SvData {
+pub struct SvTypedef {
     #[pyo3(get, set)]
-    pub modules: Vec<SvModuleDeclaration>,
+    pub identifier: String,
     #[pyo3(get, set)]
-    pub packages: Vec<SvPackageDeclaration>,
+    pub kind: SvTypedefKind,
+    #[pyo3(get, set)]
+    pub datatype: Option<String>,
+    #[pyo3(get, set)]
+    pub packed_dimensions: Vec<SvPackedDimension>,
+    #[pyo3(get, set)]
+    pub unpacked_dimensions: Vec<SvUnpackedDimension>,
+    #[pyo3(get, set)]
+    pub members: Vec<SvTypedefMember>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
 }
 #[pymethods]
-impl SvData {
+impl SvTypedef {
     #[new]
     fn new() -> Self {
-        SvData {
-            modules: Vec::new(),
-            packages: Vec::new(),
+        SvTypedef {
+            identifier: String::new(),
+            kind: SvTypedefKind::Scalar,
+            datatype: None,
+            packed_dimensions: Vec::new(),
+            unpacked_dimensions: Vec::new(),
+            members: Vec::new(),
+            span: (0, 0),
         }
     }
     fn __repr__(&self) -> String {
         self.to_string()
     }
 }
-/// Store the information about a module.
+
+/// Store the information about a member of a struct, union or enum.
 ///
 /// Args:
 ///
-///   identifier (str): The name of the module.
-///   parameters (list[SvParameter]): A list of all the parameters in the module.
-///   ports (list[SvPort]): A list of all the ports in the module.
-///   instances (list[SvInstance]): A list of all the instances in the module.
-///   filepath (str): The path to the file that contains the module.
-///   comments (list[str]): A list of all the comments in the module.
-#[derive(Debug, Clone, PartialEq)]
+///    identifier (str): The name of the member.
+///    datatype (str | None): The type of a struct or union member, as written in the source.
+///    packed_dimensions (list[SvPackedDimension]): A list of all the packed dimensions of the member.
+///    unpacked_dimensions (list[SvUnpackedDimension]): A list of all the unpacked dimensions of the member.
+///    expression (str | None): The explicit value of an enum member.
+///    value (SvPrimaryLiteralIntegral | None): The resolved value of an enum member, with the width
+///        and signedness of the base type of the enum, or `None` when its value cannot be resolved.
+///        A member without an explicit value follows the previous member, wrapping around at the
+///        width of the base type, and the first one is 0.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
-pub struct SvModuleDeclaration {
+pub struct SvTypedefMember {
     #[pyo3(get, set)]
     pub identifier: String,
     #[pyo3(get, set)]
-    pub parameters: Vec<SvParameter>,
+    pub datatype: Option<String>,
     #[pyo3(get, set)]
-    pub ports: Vec<SvPort>,
+    pub packed_dimensions: Vec<SvPackedDimension>,
     #[pyo3(get, set)]
-    pub instances: Vec<SvInstance>,
+    pub unpacked_dimensions: Vec<SvUnpackedDimension>,
     #[pyo3(get, set)]
-    pub filepath: String,
+    pub expression: Option<String>,
     #[pyo3(get, set)]
-    pub comments: Vec<String>,
+    pub value: Option<SvPrimaryLiteralIntegral>,
 }
-
 #[pymethods]
-impl SvModuleDeclaration {
+impl SvTypedefMember {
     #[new]
     fn new() -> Self {
-        SvModuleDeclaration {
+        SvTypedefMember {
             identifier: String::new(),
-            parameters: Vec::new(),
-            ports: Vec::new(),
-            instances: Vec::new(),
-            filepath: String::new(),
-            comments: Vec::new(),
+            datatype: None,
+            packed_dimensions: Vec::new(),
+            unpacked_dimensions: Vec::new(),
+            expression: None,
+            value: None,
         }
     }
     fn __repr__(&self) -> String {
@@ -72,31 +905,190 @@ impl SvModuleDeclaration {
     }
 }
 
-/// Store the information about a package.
+/// Typedef kinds.
+///
+/// Args:
+///     Struct (str): A struct.
+///     Union (str): A union.
+///     Enum (str): An enum.
+///     Scalar (str): Any other type, e.g. a vector.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub enum SvTypedefKind {
+    Struct,
+    Union,
+    Enum,
+    Scalar,
+}
+
+#[pymethods]
+impl SvTypedefKind {
+    #[new]
+    fn new() -> Self {
+        SvTypedefKind::Scalar
+    }
+
+    fn __repr__(&self) -> String {
+        match self {
+            SvTypedefKind::Struct => "Struct".to_string(),
+            SvTypedefKind::Union => "Union".to_string(),
+            SvTypedefKind::Enum => "Enum".to_string(),
+            SvTypedefKind::Scalar => "Scalar".to_string(),
+        }
+    }
+}
+
+/// Store the information about a class.
 ///
 /// Args:
 ///
-///    identifier (str): The name of the package.
-///    parameters (list[SvParameter]): A list of all the parameters in the package.
-///    filepath (str): The path to the file that contains the package.
-#[derive(Debug, Clone, PartialEq)]
+///    identifier (str): The name of the class.
+///    extends (str | None): The name of the base class, if the class extends one.
+///    is_virtual (bool): Whether the class is declared as a virtual class.
+///    parameters (list[SvParameter]): The value parameters of the parameter port list of the
+///        class. Type parameters are not extracted.
+///    functions (list[SvFunction]): The functions declared in the class, excluding the
+///        constructor and methods that are only declared as a prototype.
+///    tasks (list[SvTask]): The tasks declared in the class, excluding methods that are only
+///        declared as a prototype.
+///    filepath (str): The path to the file that contains the class.
+///    span (SvSpan): The byte offsets of the start and end of the class in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
-pub struct SvPackageDeclaration {
+pub struct SvClass {
     #[pyo3(get, set)]
     pub identifier: String,
     #[pyo3(get, set)]
+    pub extends: Option<String>,
+    #[pyo3(get, set)]
+    pub is_virtual: bool,
+    #[pyo3(get, set)]
     pub parameters: Vec<SvParameter>,
     #[pyo3(get, set)]
+    pub functions: Vec<SvFunction>,
+    #[pyo3(get, set)]
+    pub tasks: Vec<SvTask>,
+    #[pyo3(get, set)]
     pub filepath: String,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
 }
 #[pymethods]
-impl SvPackageDeclaration {
+impl SvClass {
     #[new]
     fn new() -> Self {
-        SvPackageDeclaration {
+        SvClass {
             identifier: String::new(),
+            extends: None,
+            is_virtual: false,
             parameters: Vec::new(),
+            functions: Vec::new(),
+            tasks: Vec::new(),
             filepath: String::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about a function.
+///
+/// Args:
+///
+///    identifier (str): The name of the function.
+///    return_type (str | None): The return type of the function, as written in the source.
+///    arguments (list[SvArgument]): A list of all the arguments of the function.
+///    span (SvSpan): The byte offsets of the start and end of the function in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvFunction {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub return_type: Option<String>,
+    #[pyo3(get, set)]
+    pub arguments: Vec<SvArgument>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvFunction {
+    #[new]
+    fn new() -> Self {
+        SvFunction {
+            identifier: String::new(),
+            return_type: None,
+            arguments: Vec::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about a task.
+///
+/// Args:
+///
+///    identifier (str): The name of the task.
+///    arguments (list[SvArgument]): A list of all the arguments of the task.
+///    span (SvSpan): The byte offsets of the start and end of the task in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvTask {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub arguments: Vec<SvArgument>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvTask {
+    #[new]
+    fn new() -> Self {
+        SvTask {
+            identifier: String::new(),
+            arguments: Vec::new(),
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Store the information about an argument of a function or task.
+///
+/// Args:
+///
+///    identifier (str): The name of the argument.
+///    direction (SvPortDirection): The direction of the argument.
+///    datatype (str): The type of the argument, as written in the source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvArgument {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub direction: SvPortDirection,
+    #[pyo3(get, set)]
+    pub datatype: String,
+}
+#[pymethods]
+impl SvArgument {
+    #[new]
+    fn new() -> Self {
+        SvArgument {
+            identifier: String::new(),
+            direction: SvPortDirection::Input,
+            datatype: String::new(),
         }
     }
     fn __repr__(&self) -> String {
@@ -119,7 +1111,12 @@ impl SvPackageDeclaration {
 ///    packed_dimensions (list[SvPackedDimension]): A list of all the packed dimensions of the parameter.
 ///    unpacked_dimensions (list[SvUnpackedDimension]): A list of all the unpacked dimensions of the parameter.
 ///    comment (list[str] | None): A list of all the comments of the parameter.
-#[derive(Debug, Clone, PartialEq)]
+///    value (SvPrimaryLiteralIntegral | None): The resolved value of the parameter, with the width
+///        and signedness of its declared type, or `None` when the parameter has no default or its
+///        default cannot be folded into a constant.
+///    span (SvSpan): The byte offsets of the start and end of the assignment of the parameter in
+///        its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub struct SvParameter {
     #[pyo3(get, set)]
@@ -146,6 +1143,11 @@ pub struct SvParameter {
     pub unpacked_dimensions: Vec<SvUnpackedDimension>,
     #[pyo3(get, set)]
     pub comment: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub value: Option<SvPrimaryLiteralIntegral>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
 }
 #[pymethods]
 impl SvParameter {
@@ -164,6 +1166,8 @@ impl SvParameter {
             packed_dimensions: Vec::new(),
             unpacked_dimensions: Vec::new(),
             comment: None,
+            value: None,
+            span: (0, 0),
         }
     }
     fn __repr__(&self) -> String {
@@ -176,7 +1180,7 @@ impl SvParameter {
 /// Args:
 ///   Parameter (str): A parameter.
 ///   LocalParam (str): A local parameter.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvParamType {
     Parameter,
@@ -206,7 +1210,7 @@ impl SvParamType {
 ///    Output (str): An output port.
 ///    Ref (str): A ref port.
 ///    IMPLICIT (str): An implicit port.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvPortDirection {
     Inout,
@@ -234,12 +1238,14 @@ impl SvPortDirection {
 /// Args:
 ///    Net (str): A net.
 ///    Variable (str): A variable.
+///    Interface (str): An interface instance, i.e. an interface port.
 ///    IMPLICIT (str): An implicit data kind.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvDataKind {
     Net,
     Variable,
+    Interface,
     IMPLICIT,
 }
 
@@ -249,6 +1255,7 @@ impl SvDataKind {
         match self {
             SvDataKind::Net => "Net".to_string(),
             SvDataKind::Variable => "Variable".to_string(),
+            SvDataKind::Interface => "Interface".to_string(),
             SvDataKind::IMPLICIT => "IMPLICIT".to_string(),
         }
     }
@@ -261,7 +1268,7 @@ impl SvDataKind {
 ///   Unsigned (str): An unsigned value.
 ///   Unsupported (str): An unsupported value.
 ///   IMPLICIT (str): An implicit value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvSignedness {
     Signed,
@@ -306,7 +1313,7 @@ impl SvSignedness {
 ///     String (str): A string type.
 ///     Unsupported (str): An unsupported type.
 ///     IMPLICIT (str): An implicit type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvDataType {
     Logic,
@@ -377,7 +1384,7 @@ impl SvDataType {
 ///     Supply0 (str): A supply0.
 ///     Supply1 (str): A supply1.
 ///     IMPLICIT (str): An implicit net type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub enum SvNetType {
     Wire,
@@ -423,11 +1430,26 @@ pub type SvPackedDimension = (String, String);
 /// The first element is the left bound, the second is the right bound.
 pub type SvUnpackedDimension = (String, Option<String>);
 
+/// Attributes, e.g. `(* keep = "true" *)`.
+/// The first element is the name of the attribute, the second is its value as written in the source, if any.
+pub type SvAttribute = (String, Option<String>);
+
+/// Spans of source text.
+/// The first element is the byte offset of the first character, the second is the byte offset just
+/// past the last character, both within the file that contains the text. Trailing whitespace and
+/// comments are not part of the span.
+pub type SvSpan = (usize, usize);
+
+/// Modport signals.
+/// The first element is the identifier of the signal, the second is its direction.
+pub type SvModportPort = (String, SvPortDirection);
+
 /// Ports.
 ///
 /// Args:
 ///    identifier (str): The identifier of the port.
-///    direction (SvPortDirection): The direction of the port.
+///    direction (SvPortDirection): The direction of the port. A port without a direction has the
+///        direction of the previous port, and the first port defaults to an inout.
 ///    datakind (SvDataKind): The data kind of the port.
 ///    datatype (SvDataType): The data type of the port.
 ///    classid (str): The class identifier of the port.
@@ -435,8 +1457,16 @@ pub type SvUnpackedDimension = (String, Option<String>);
 ///    signedness (SvSignedness): The signedness of the port.
 ///    packed_dimensions (List[SvPackedDimension]): The packed dimensions of the port.
 ///    unpacked_dimensions (List[SvUnpackedDimension]): The unpacked dimensions of the port.
+///    width (int | None): The number of bits of the port, if all packed dimensions are integer literals.
+///    interface_name (str | None): The interface of an interface port, or `None` for a data port or
+///        a generic interface port, i.e. `interface.master bus`.
+///    modport_name (str | None): The modport of an interface port, if any.
 ///    comment (List[str] | None): The comment of the port.
-#[derive(Debug, Clone, PartialEq)]
+///    attributes (List[SvAttribute]): The attributes of the port, e.g. `(* keep = "true" *)`.
+///    doc_comment (str | None): The comment on the lines immediately above the port, without the
+///        comment markers.
+///    span (SvSpan): The byte offsets of the start and end of the port declaration in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
 pub struct SvPort {
     #[pyo3(get, set)]
@@ -444,41 +1474,249 @@ pub struct SvPort {
     #[pyo3(get, set)]
     pub direction: SvPortDirection,
     #[pyo3(get, set)]
-    pub datakind: SvDataKind,
+    pub datakind: SvDataKind,
+    #[pyo3(get, set)]
+    pub datatype: SvDataType,
+    #[pyo3(get, set)]
+    pub classid: Option<String>,
+    #[pyo3(get, set)]
+    pub nettype: Option<SvNetType>,
+    #[pyo3(get, set)]
+    pub signedness: Option<SvSignedness>,
+    #[pyo3(get, set)]
+    pub packed_dimensions: Vec<SvPackedDimension>,
+    #[pyo3(get, set)]
+    pub unpacked_dimensions: Vec<SvUnpackedDimension>,
+    #[pyo3(get, set)]
+    pub width: Option<usize>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub interface_name: Option<String>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub modport_name: Option<String>,
+    #[pyo3(get, set)]
+    pub comment: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub attributes: Vec<SvAttribute>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub doc_comment: Option<String>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+
+/// Signals, i.e. nets and variables declared in the body of a module.
+///
+/// Args:
+///    identifier (str): The identifier of the signal.
+///    datakind (SvDataKind): Whether the signal is a net or a variable.
+///    datatype (str | None): The data type of the signal, as written in the source, or `None` for
+///        an implicit data type, e.g. `wire [3:0] a`.
+///    packed_dimensions (List[SvPackedDimension]): The packed dimensions of the signal.
+///    unpacked_dimensions (List[SvUnpackedDimension]): The unpacked dimensions of the signal.
+///    signed (bool): Whether the signal is signed.
+///    span (SvSpan): The byte offsets of the start and end of the identifier of the signal, along
+///        with its unpacked dimensions and initial value, in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvSignal {
+    #[pyo3(get, set)]
+    pub identifier: String,
+    #[pyo3(get, set)]
+    pub datakind: SvDataKind,
+    #[pyo3(get, set)]
+    pub datatype: Option<String>,
+    #[pyo3(get, set)]
+    pub packed_dimensions: Vec<SvPackedDimension>,
+    #[pyo3(get, set)]
+    pub unpacked_dimensions: Vec<SvUnpackedDimension>,
+    #[pyo3(get, set)]
+    pub signed: bool,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+#[pymethods]
+impl SvSignal {
+    #[new]
+    fn new() -> Self {
+        SvSignal {
+            identifier: String::new(),
+            datakind: SvDataKind::Net,
+            datatype: None,
+            packed_dimensions: Vec::new(),
+            unpacked_dimensions: Vec::new(),
+            signed: false,
+            span: (0, 0),
+        }
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Instances.
+///
+/// Args:
+///    module_identifier (str): The module identifier of the instance.
+///    hierarchical_instance (str): The hierarchical instance of the instance.
+///    hierarchy (List[str]): The hierarchy of the instance.
+///    parameter_overrides (List[List[str]]): The parameter overrides of the instance.
+///    parameter_values (List[SvPrimaryLiteralIntegral | None]): The value of each parameter
+///        override, folded against the parameters of the parent module, or `None` when it is
+///        empty or cannot be folded into a constant.
+///    connections (List[List[str]]): The connections of the instance. A wildcard connection
+///        (`.*`) is a single empty list.
+///    connection_kinds (List[SvPortConnectionKind]): The kind of each connection.
+///    span (SvSpan): The byte offsets of the start and end of the instantiation in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvInstance {
+    #[pyo3(get, set)]
+    pub module_identifier: String,
+    #[pyo3(get, set)]
+    pub hierarchical_instance: String,
+    #[pyo3(get, set)]
+    pub hierarchy: Vec<String>,
+    #[pyo3(get, set)]
+    pub parameter_overrides: Vec<Vec<String>>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub parameter_values: Vec<Option<SvPrimaryLiteralIntegral>>,
+    #[pyo3(get, set)]
+    pub connections: Vec<Vec<String>>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub connection_kinds: Vec<SvPortConnectionKind>,
+    #[pyo3(get, set)]
+    #[serde(default)]
+    pub span: SvSpan,
+}
+
+#[pymethods]
+impl SvInstance {
+    /// Returns the parameters of the module that the instance instantiates, given the parameters
+    /// declared by that module, with the parameter overrides of the instance applied.
+    #[pyo3(name = "resolve_parameters")]
+    fn py_resolve_parameters(&self, parameters: Vec<SvParameter>) -> Vec<SvParameter> {
+        self.resolve_parameters(&parameters)
+    }
+}
+
+impl SvInstance {
+    /// Applies the parameter overrides of the instance to the parameters of the module that it
+    /// instantiates. See `inst_resolve_parameters`.
+    pub fn resolve_parameters(&self, parameters: &[SvParameter]) -> Vec<SvParameter> {
+        inst_resolve_parameters(self, parameters)
+    }
+}
+
+/// Port connection kinds.
+///
+/// Args:
+///    Explicit (str): A connection to an expression, either by name (`.a(x)`) or by order.
+///    Implicit (str): A connection by name to a signal of the same name (`.a`).
+///    Wildcard (str): A connection of all remaining ports to signals of the same name (`.*`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub enum SvPortConnectionKind {
+    Explicit,
+    Implicit,
+    Wildcard,
+}
+
+#[pymethods]
+impl SvPortConnectionKind {
+    fn __repr__(&self) -> String {
+        match self {
+            SvPortConnectionKind::Explicit => "Explicit".to_string(),
+            SvPortConnectionKind::Implicit => "Implicit".to_string(),
+            SvPortConnectionKind::Wildcard => "Wildcard".to_string(),
+        }
+    }
+}
+
+/// Generate blocks, i.e. the body of a generate loop or a branch of a generate conditional.
+///
+/// Args:
+///    kind (SvGenerateKind): Whether the block is the body of a loop, or the branch of a
+///        conditional that is taken when its condition holds or otherwise.
+///    label (str | None): The label of the block, e.g. `gen_lane` in `begin : gen_lane`.
+///    genvar (str | None): The loop variable of a loop.
+///    init (str | None): The initial value of the loop variable, as written in the source.
+///    condition (str | None): The condition of a loop or an `if` branch, as written in the source.
+///    step (str | None): The iteration of the loop variable, as written in the source, e.g. `i++`.
+///    count (int | None): The number of iterations of a loop, when its bounds can be resolved
+///        against the parameters of the module.
+///    indices (list[int] | None): The value of the genvar in each iteration of a loop, which
+///        indexes the instances of the block, e.g. `gen_lane[0]`, when `count` is known.
+///    instances (list[SvInstance]): The instances declared directly in the block.
+///    signals (list[SvSignal]): The nets and variables declared directly in the block.
+///    blocks (list[SvGenerateBlock]): The generate blocks nested in the block.
+///    span (SvSpan): The byte offsets of the start and end of the loop or conditional in its file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[pyclass]
+pub struct SvGenerateBlock {
+    #[pyo3(get, set)]
+    pub kind: SvGenerateKind,
+    #[pyo3(get, set)]
+    pub label: Option<String>,
+    #[pyo3(get, set)]
+    pub genvar: Option<String>,
+    #[pyo3(get, set)]
+    pub init: Option<String>,
+    #[pyo3(get, set)]
+    pub condition: Option<String>,
     #[pyo3(get, set)]
-    pub datatype: SvDataType,
+    pub step: Option<String>,
     #[pyo3(get, set)]
-    pub classid: Option<String>,
+    pub count: Option<usize>,
     #[pyo3(get, set)]
-    pub nettype: Option<SvNetType>,
+    #[serde(default)]
+    pub indices: Option<Vec<i64>>,
     #[pyo3(get, set)]
-    pub signedness: Option<SvSignedness>,
+    pub instances: Vec<SvInstance>,
     #[pyo3(get, set)]
-    pub packed_dimensions: Vec<SvPackedDimension>,
+    pub signals: Vec<SvSignal>,
     #[pyo3(get, set)]
-    pub unpacked_dimensions: Vec<SvUnpackedDimension>,
+    pub blocks: Vec<SvGenerateBlock>,
     #[pyo3(get, set)]
-    pub comment: Option<Vec<String>>,
+    pub span: SvSpan,
 }
 
-/// Instances.
+#[pymethods]
+impl SvGenerateBlock {
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Generate block kinds.
 ///
 /// Args:
-///    module_identifier (str): The module identifier of the instance.
-///    hierarchical_instance (str): The hierarchical instance of the instance.
-///    hierarchy (List[str]): The hierarchy of the instance.
-///    connections (List[List[str]]): The connections of the instance.
-#[derive(Debug, Clone, PartialEq)]
+///    For (str): The body of a generate loop.
+///    If (str): The branch of a generate conditional that is taken when its condition holds.
+///    Else (str): The branch of a generate conditional that is taken otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[pyclass]
-pub struct SvInstance {
-    #[pyo3(get, set)]
-    pub module_identifier: String,
-    #[pyo3(get, set)]
-    pub hierarchical_instance: String,
-    #[pyo3(get, set)]
-    pub hierarchy: Vec<String>,
-    #[pyo3(get, set)]
-    pub connections: Vec<Vec<String>>,
+pub enum SvGenerateKind {
+    For,
+    If,
+    Else,
+}
+
+#[pymethods]
+impl SvGenerateKind {
+    fn __repr__(&self) -> String {
+        match self {
+            SvGenerateKind::For => "For".to_string(),
+            SvGenerateKind::If => "If".to_string(),
+            SvGenerateKind::Else => "Else".to_string(),
+        }
+    }
 }
 
 impl fmt::Display for SvData {
@@ -489,17 +1727,110 @@ impl fmt::Display for SvData {
         for package in &self.packages {
             write!(f, "{}", package)?;
         }
+        for interface in &self.interfaces {
+            write!(f, "{}", interface)?;
+        }
+        for class in &self.classes {
+            write!(f, "{}", class)?;
+        }
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvDirective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Directive:")?;
+        writeln!(f, "  Name: {}", self.name)?;
+        writeln!(f, "  Span: {:?}", self.span)?;
+        writeln!(f, "  Arguments: {:?}", self.arguments)?;
+        writeln!(f, "  Location: {}", self.location)?;
 
         write!(f, "")
     }
 }
 
+impl fmt::Display for SvHierarchy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Hierarchy:")?;
+
+        for (module, children) in &self.children {
+            writeln!(f, "  {}: {:?}", module, children)?;
+        }
+
+        writeln!(f, "  Black boxes: {:?}", self.black_boxes)?;
+        writeln!(f, "  Cycles: {:?}", self.cycles)
+    }
+}
+
+impl fmt::Display for SvElaboratedDesign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "ElaboratedDesign:")?;
+        writeln!(f, "  Top: {}", self.top)?;
+
+        for instance in &self.instances {
+            write!(f, "{}", instance)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for SvElaboratedInstance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  ElaboratedInstance:")?;
+        writeln!(f, "    Path: {}", self.path)?;
+        writeln!(f, "    ModuleIdentifier: {}", self.module_identifier)?;
+        writeln!(f, "    Leaf: {}", self.leaf)?;
+        writeln!(f, "    BlackBox: {}", self.black_box)
+    }
+}
+
+impl fmt::Display for SvDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Diff:")?;
+        writeln!(f, "  AddedModules: {:?}", self.added_modules)?;
+        writeln!(f, "  RemovedModules: {:?}", self.removed_modules)?;
+
+        for module in &self.changed_modules {
+            write!(f, "{}", module)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for SvModuleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  ModuleDiff:")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    AddedPorts: {:?}", self.added_ports)?;
+        writeln!(f, "    RemovedPorts: {:?}", self.removed_ports)?;
+        writeln!(f, "    RenamedPorts: {:?}", self.renamed_ports)?;
+        writeln!(f, "    RetypedPorts: {:?}", self.retyped_ports)?;
+        writeln!(f, "    AddedParameters: {:?}", self.added_parameters)?;
+        writeln!(f, "    RemovedParameters: {:?}", self.removed_parameters)?;
+        writeln!(f, "    ChangedParameters: {:?}", self.changed_parameters)
+    }
+}
+
 impl fmt::Display for SvModuleDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "Module:")?;
         writeln!(f, "  Identifier: {}", self.identifier)?;
+        writeln!(f, "  Ansi: {}", self.is_ansi)?;
+        writeln!(f, "  Span: {:?}", self.span)?;
         writeln!(f, "  Filepath: {}", self.filepath)?;
         writeln!(f, "  Comments: {:?}", self.comments)?;
+        writeln!(f, "  Attributes: {:?}", self.attributes)?;
+        match &self.doc_comment {
+            None => {
+                writeln!(f, "  DocComment: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "  DocComment: {:?}", x)?;
+            }
+        }
 
         for port in &self.ports {
             write!(f, "{}", port)?;
@@ -513,21 +1844,74 @@ impl fmt::Display for SvModuleDeclaration {
             write!(f, "{}", instance)?;
         }
 
+        for typedef in &self.typedefs {
+            write!(f, "{}", typedef)?;
+        }
+
+        for function in &self.functions {
+            write!(f, "{}", function)?;
+        }
+
+        for task in &self.tasks {
+            write!(f, "{}", task)?;
+        }
+
+        for signal in &self.signals {
+            write!(f, "{}", signal)?;
+        }
+
+        for block in &self.generate_blocks {
+            write!(f, "{}", block)?;
+        }
+
         writeln!(f, "")
     }
 }
 
+impl fmt::Display for SvGenerateBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  GenerateBlock: ")?;
+        writeln!(f, "    Kind: {:?}", self.kind)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
+        writeln!(f, "    Label: {:?}", self.label)?;
+        writeln!(f, "    Genvar: {:?}", self.genvar)?;
+        writeln!(f, "    Init: {:?}", self.init)?;
+        writeln!(f, "    Condition: {:?}", self.condition)?;
+        writeln!(f, "    Step: {:?}", self.step)?;
+        writeln!(f, "    Count: {:?}", self.count)?;
+        writeln!(f, "    Indices: {:?}", self.indices)?;
+
+        for instance in &self.instances {
+            write!(f, "{}", instance)?;
+        }
+
+        for signal in &self.signals {
+            write!(f, "{}", signal)?;
+        }
+
+        for block in &self.blocks {
+            write!(f, "{}", block)?;
+        }
+
+        write!(f, "")
+    }
+}
+
 impl fmt::Display for SvInstance {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "  Instance: ")?;
         writeln!(f, "    Module identifier: {}", self.module_identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
         writeln!(
             f,
             "    Hierarchical instance: {}",
             self.hierarchical_instance
         )?;
         writeln!(f, "    Hierarchy: {:?}", self.hierarchy)?;
+        writeln!(f, "    Parameter overrides: {:?}", self.parameter_overrides)?;
+        writeln!(f, "    Parameter values: {:?}", self.parameter_values)?;
         writeln!(f, "    Connections: {:?}", self.connections)?;
+        writeln!(f, "    Connection kinds: {:?}", self.connection_kinds)?;
 
         write!(f, "")
     }
@@ -537,20 +1921,213 @@ impl fmt::Display for SvPackageDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "Package:")?;
         writeln!(f, "  Identifier: {}", self.identifier)?;
+        writeln!(f, "  Span: {:?}", self.span)?;
+        writeln!(f, "  Filepath: {}", self.filepath)?;
+
+        for param in &self.parameters {
+            write!(f, "{}", param)?;
+        }
+
+        for typedef in &self.typedefs {
+            write!(f, "{}", typedef)?;
+        }
+
+        for function in &self.functions {
+            write!(f, "{}", function)?;
+        }
+
+        for task in &self.tasks {
+            write!(f, "{}", task)?;
+        }
+
+        writeln!(f, "")
+    }
+}
+
+impl fmt::Display for SvClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Class:")?;
+        writeln!(f, "  Identifier: {}", self.identifier)?;
+        writeln!(f, "  Extends: {:?}", self.extends)?;
+        writeln!(f, "  Virtual: {}", self.is_virtual)?;
+        writeln!(f, "  Span: {:?}", self.span)?;
         writeln!(f, "  Filepath: {}", self.filepath)?;
 
         for param in &self.parameters {
             write!(f, "{}", param)?;
         }
 
+        for function in &self.functions {
+            write!(f, "{}", function)?;
+        }
+
+        for task in &self.tasks {
+            write!(f, "{}", task)?;
+        }
+
+        writeln!(f, "")
+    }
+}
+
+impl fmt::Display for SvInterface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Interface:")?;
+        writeln!(f, "  Identifier: {}", self.identifier)?;
+        writeln!(f, "  Span: {:?}", self.span)?;
+        writeln!(f, "  Filepath: {}", self.filepath)?;
+
+        for port in &self.ports {
+            write!(f, "{}", port)?;
+        }
+
+        for modport in &self.modports {
+            write!(f, "{}", modport)?;
+        }
+
         writeln!(f, "")
     }
 }
 
+impl fmt::Display for SvModport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  Modport: ")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Ports: {:?}", self.ports)?;
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvTypedef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  Typedef: ")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
+        writeln!(f, "    Kind: {:?}", self.kind)?;
+        match &self.datatype {
+            None => {
+                writeln!(f, "    DataType: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    DataType: {}", x)?;
+            }
+        }
+        writeln!(f, "    PackedDimensions: {:?}", self.packed_dimensions)?;
+        writeln!(f, "    UnpackedDimensions: {:?}", self.unpacked_dimensions)?;
+
+        for member in &self.members {
+            write!(f, "{}", member)?;
+        }
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvTypedefMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "    Member: ")?;
+        writeln!(f, "      Identifier: {}", self.identifier)?;
+        match &self.datatype {
+            None => {
+                writeln!(f, "      DataType: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "      DataType: {}", x)?;
+            }
+        }
+        writeln!(f, "      PackedDimensions: {:?}", self.packed_dimensions)?;
+        writeln!(
+            f,
+            "      UnpackedDimensions: {:?}",
+            self.unpacked_dimensions
+        )?;
+        match &self.expression {
+            None => {
+                writeln!(f, "      Expression: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "      Expression: {}", x)?;
+            }
+        }
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  Function: ")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
+        match &self.return_type {
+            None => {
+                writeln!(f, "    ReturnType: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    ReturnType: {}", x)?;
+            }
+        }
+
+        for argument in &self.arguments {
+            write!(f, "{}", argument)?;
+        }
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvTask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  Task: ")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
+
+        for argument in &self.arguments {
+            write!(f, "{}", argument)?;
+        }
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "    Argument: ")?;
+        writeln!(f, "      Identifier: {}", self.identifier)?;
+        writeln!(f, "      Direction: {:?}", self.direction)?;
+        writeln!(f, "      DataType: {}", self.datatype)?;
+
+        write!(f, "")
+    }
+}
+
+impl fmt::Display for SvSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "  Signal: ")?;
+        writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
+        writeln!(f, "    DataKind: {:?}", self.datakind)?;
+        match &self.datatype {
+            None => {
+                writeln!(f, "    DataType: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    DataType: {}", x)?;
+            }
+        }
+        writeln!(f, "    PackedDimensions: {:?}", self.packed_dimensions)?;
+        writeln!(f, "    UnpackedDimensions: {:?}", self.unpacked_dimensions)?;
+        writeln!(f, "    Signed: {}", self.signed)?;
+
+        write!(f, "")
+    }
+}
+
 impl fmt::Display for SvPort {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "  Port: ")?;
         writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
         writeln!(f, "    Direction: {:?}", self.direction)?;
         writeln!(f, "    DataKind: {:?}", self.datakind)?;
         writeln!(f, "    DataType: {:?}", self.datatype)?;
@@ -589,6 +2166,30 @@ impl fmt::Display for SvPort {
             }
         }
         writeln!(f, "    UnpackedDimensions: {:?}", unpackeddim_display)?;
+        match &self.width {
+            None => {
+                writeln!(f, "    Width: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    Width: {}", x)?;
+            }
+        }
+        match &self.interface_name {
+            None => {
+                writeln!(f, "    InterfaceName: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    InterfaceName: {}", x)?;
+            }
+        }
+        match &self.modport_name {
+            None => {
+                writeln!(f, "    ModportName: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    ModportName: {}", x)?;
+            }
+        }
         match &self.comment {
             None => {
                 writeln!(f, "    Comment: None")?;
@@ -597,6 +2198,15 @@ impl fmt::Display for SvPort {
                 writeln!(f, "    Comment: {:?}", x)?;
             }
         }
+        writeln!(f, "    Attributes: {:?}", self.attributes)?;
+        match &self.doc_comment {
+            None => {
+                writeln!(f, "    DocComment: None")?;
+            }
+            Some(x) => {
+                writeln!(f, "    DocComment: {:?}", x)?;
+            }
+        }
 
         write!(f, "")
     }
@@ -606,6 +2216,7 @@ impl fmt::Display for SvParameter {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "  Parameter: ")?;
         writeln!(f, "    Identifier: {}", self.identifier)?;
+        writeln!(f, "    Span: {:?}", self.span)?;
         match &self.expression {
             None => {
                 writeln!(f, "    Expression: None")?;
@@ -680,3 +2291,32 @@ impl fmt::Display for SvParameter {
         write!(f, "")
     }
 }
+
+// Converts a serialized value into the equivalent Python object, i.e. an object into a dict and an
+// array into a list
+fn json_to_py(py: Python, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(x) => x.into_py(py),
+        Value::Number(x) => match (x.as_u64(), x.as_i64()) {
+            (Some(y), _) => y.into_py(py),
+            (None, Some(y)) => y.into_py(py),
+            (None, None) => x.as_f64().into_py(py),
+        },
+        Value::String(x) => x.into_py(py),
+        Value::Array(x) => {
+            let items = x
+                .iter()
+                .map(|y| json_to_py(py, y))
+                .collect::<PyResult<Vec<PyObject>>>()?;
+            PyList::new(py, items).into_py(py)
+        }
+        Value::Object(x) => {
+            let dict = PyDict::new(py);
+            for (key, y) in x {
+                dict.set_item(key, json_to_py(py, y)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
@@ -0,0 +1,77 @@
+use crate::structures::SvClass;
+use crate::sv_error::SvError;
+use crate::sv_misc::{identifier, span};
+use crate::sv_port::parameter_port_list;
+use crate::sv_subroutine::{function_declaration, task_declaration};
+use sv_parser::{unwrap_node, NodeEvent, RefNode, SyntaxTree};
+
+/// Extracts a class, e.g. `class fifo_driver #(int DEPTH = 4) extends base_driver; ... endclass`.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// let svdata = parse_sv_file("tests/systemverilog/classes.sv", None, None, false).unwrap();
+/// let class = &svdata.classes[1];
+///
+/// assert_eq!(class.identifier, "fifo_driver");
+/// assert_eq!(class.extends.as_deref(), Some("base_driver"));
+/// assert_eq!(class.parameters[0].identifier, "DEPTH");
+/// assert_eq!(class.functions[1].return_type.as_deref(), Some("int unsigned"));
+/// assert_eq!(class.tasks[0].identifier, "flush");
+///
+/// assert!(svdata.classes[0].is_virtual);
+/// ```
+pub fn class_declaration(
+    p: &sv_parser::ClassDeclaration,
+    syntax_tree: &SyntaxTree,
+    filepath: &str,
+) -> Result<SvClass, SvError> {
+    let (virtual_keyword, _, _, id, parameters, extends, ..) = &p.nodes;
+
+    let mut ret = SvClass {
+        identifier: identifier(RefNode::ClassIdentifier(id), syntax_tree).unwrap(),
+        extends: extends
+            .as_ref()
+            .and_then(|x| class_type_identifier(&x.1, syntax_tree)),
+        is_virtual: virtual_keyword.is_some(),
+        parameters: Vec::new(),
+        functions: Vec::new(),
+        tasks: Vec::new(),
+        filepath: String::from(filepath),
+        span: span(RefNode::ClassDeclaration(p), syntax_tree),
+    };
+
+    if let Some(x) = parameters {
+        parameter_port_list(x, syntax_tree, &mut ret.parameters)?;
+    }
+
+    // The methods of a nested class are not methods of this class
+    let mut depth: usize = 0;
+
+    for event in RefNode::ClassDeclaration(p).into_iter().event() {
+        match event {
+            NodeEvent::Enter(RefNode::ClassDeclaration(_)) => depth += 1,
+            NodeEvent::Leave(RefNode::ClassDeclaration(_)) => depth -= 1,
+
+            NodeEvent::Enter(RefNode::FunctionDeclaration(x)) if depth == 1 => {
+                ret.functions.push(function_declaration(x, syntax_tree));
+            }
+
+            NodeEvent::Enter(RefNode::TaskDeclaration(x)) if depth == 1 => {
+                ret.tasks.push(task_declaration(x, syntax_tree));
+            }
+
+            _ => (),
+        }
+    }
+
+    Ok(ret)
+}
+
+// The identifier of the class, without its package or parameters, e.g. base_driver for
+// pkg::base_driver #(T)
+fn class_type_identifier(p: &sv_parser::ClassType, syntax_tree: &SyntaxTree) -> Option<String> {
+    let id = unwrap_node!(p, ClassIdentifier)?;
+    identifier(id, syntax_tree)
+}
@@ -0,0 +1,442 @@
+use crate::structures::SvParameter;
+use crate::sv_misc::{get_string, symbol};
+use crate::sv_primlit_integral::SvPrimaryLiteralIntegral;
+use std::path::{Path, PathBuf};
+use sv_parser::{parse_sv_str, unwrap_node, Defines, RefNode, SyntaxTree};
+
+/// Folds the default expression of a parameter into an integral value.
+///
+/// Identifiers are resolved against the parameters of the same scope which were declared before,
+/// e.g. the parameters of the same module. The value is `None` if any part of the expression
+/// cannot be resolved, e.g. a function call, a reference to a package or a bit select.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// let svdata = parse_sv_file("tests/systemverilog/const_eval.sv", None, None, false).unwrap();
+/// let parameters = &svdata.modules[0].parameters;
+/// let value = |x: usize| u64::try_from(parameters[x].value.clone().unwrap()).unwrap();
+///
+/// assert_eq!(value(1), 15);
+/// assert_eq!(value(2), 16);
+/// assert_eq!(value(3), 31);
+/// assert_eq!(value(4), 3);
+/// assert_eq!(i64::try_from(parameters[5].value.clone().unwrap()), Ok(-5));
+/// assert_eq!(parameters[6].value, None);
+/// assert_eq!(parameters[7].value, None);
+/// assert_eq!(parameters[8].value, None);
+/// assert_eq!(value(9), 1);
+///
+/// // The values are assigned to the declared types, and shifted out bits are dropped
+/// assert_eq!(parameters[10].value.as_ref().unwrap().size, 4);
+/// assert_eq!(value(10), 15);
+/// assert_eq!(i64::try_from(parameters[11].value.clone().unwrap()), Ok(-8));
+/// assert_eq!(parameters[12].value.as_ref().unwrap().size, 8);
+/// assert_eq!(value(12), 255);
+/// assert_eq!(value(13), 1);
+/// ```
+pub fn const_param_expression(
+    p: &sv_parser::ConstantParamExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::ConstantParamExpression::ConstantMintypmaxExpression(x) => {
+            const_mintypmax_expression(x, syntax_tree, scope)
+        }
+        _ => None,
+    }
+}
+
+/// Folds a constant expression given as source text, e.g. the `expression` of a parameter or a
+/// parameter override, into an integral value, the same as `const_param_expression` does.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_const_eval::const_expression_str;
+/// # use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+/// let svdata = parse_sv_file("tests/systemverilog/const_eval.sv", None, None, false).unwrap();
+/// let parameters = &svdata.modules[0].parameters;
+///
+/// let int = |x: Option<SvPrimaryLiteralIntegral>| x.map(|x| i64::try_from(x).unwrap());
+///
+/// // The value is not assigned to the type of the parameter, so only the integers are the same
+/// for parameter in &parameters[..10] {
+///     let expression = parameter.expression.as_deref().unwrap();
+///     assert_eq!(
+///         int(const_expression_str(expression, parameters)),
+///         int(parameter.value.clone())
+///     );
+/// }
+///
+/// assert_eq!(
+///     const_expression_str("8'hff", parameters),
+///     SvPrimaryLiteralIntegral::from_sv_literal("8'hff").ok()
+/// );
+///
+/// assert_eq!(const_expression_str("(", parameters), None);
+/// ```
+pub fn const_expression_str(
+    expression: &str,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    let source = format!(
+        "package const_eval; localparam VALUE = {}; endpackage",
+        expression
+    );
+    let (syntax_tree, _) = parse_sv_str(
+        &source,
+        Path::new(""),
+        &Defines::new(),
+        &[] as &[PathBuf],
+        true,
+        false,
+    )
+    .ok()?;
+
+    match unwrap_node!(&syntax_tree, ConstantParamExpression) {
+        Some(RefNode::ConstantParamExpression(x)) => const_param_expression(x, &syntax_tree, scope),
+        _ => None,
+    }
+}
+
+fn const_mintypmax_expression(
+    p: &sv_parser::ConstantMintypmaxExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::ConstantMintypmaxExpression::Unary(x) => const_expression(x, syntax_tree, scope),
+        _ => None,
+    }
+}
+
+// The binary expressions are flattened into their operands and operators, which are then folded
+// by precedence, so the value does not depend on how the parser nested the binary expressions.
+pub fn const_expression(
+    p: &sv_parser::ConstantExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    let mut operands: Vec<SvPrimaryLiteralIntegral> = Vec::new();
+    let mut operators: Vec<String> = Vec::new();
+
+    const_expression_flatten(p, syntax_tree, scope, &mut operands, &mut operators)?;
+    const_evaluate(operands, operators)
+}
+
+/// Folds the expression of a parameter override, e.g. the `4 * 2` of `.DEPTH(4 * 2)`, into an
+/// integral value, the same as `const_param_expression` does.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_const_eval::const_expression_str;
+/// let svdata = parse_sv_file("tests/systemverilog/instances.sv", None, None, false).unwrap();
+/// let instance = &svdata.modules[1].instances[0];
+///
+/// assert_eq!(
+///     instance.parameter_values[1],
+///     const_expression_str(&instance.parameter_overrides[1][1], &[])
+/// );
+/// ```
+pub fn const_param_override(
+    p: &sv_parser::ParamExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::ParamExpression::MintypmaxExpression(x) => {
+            const_override_mintypmax_expression(x, syntax_tree, scope)
+        }
+        _ => None,
+    }
+}
+
+fn const_override_mintypmax_expression(
+    p: &sv_parser::MintypmaxExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::MintypmaxExpression::Expression(x) => {
+            let mut operands: Vec<SvPrimaryLiteralIntegral> = Vec::new();
+            let mut operators: Vec<String> = Vec::new();
+
+            const_override_flatten(x, syntax_tree, scope, &mut operands, &mut operators)?;
+            const_evaluate(operands, operators)
+        }
+        _ => None,
+    }
+}
+
+// Folds the flattened operands and operators by precedence
+fn const_evaluate(
+    operands: Vec<SvPrimaryLiteralIntegral>,
+    operators: Vec<String>,
+) -> Option<SvPrimaryLiteralIntegral> {
+    let mut values: Vec<SvPrimaryLiteralIntegral> = Vec::new();
+    let mut pending: Vec<String> = Vec::new();
+    let mut operands = operands.into_iter();
+
+    values.push(operands.next()?);
+
+    for (operator, operand) in operators.into_iter().zip(operands) {
+        let precedence = const_precedence(&operator)?;
+
+        while let Some(prev) = pending.last() {
+            if const_precedence(prev)? < precedence {
+                break;
+            }
+
+            let prev = pending.pop()?;
+            const_fold(&mut values, &prev)?;
+        }
+
+        pending.push(operator);
+        values.push(operand);
+    }
+
+    while let Some(operator) = pending.pop() {
+        const_fold(&mut values, &operator)?;
+    }
+
+    values.pop()
+}
+
+fn const_expression_flatten(
+    p: &sv_parser::ConstantExpression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+    operands: &mut Vec<SvPrimaryLiteralIntegral>,
+    operators: &mut Vec<String>,
+) -> Option<()> {
+    match p {
+        sv_parser::ConstantExpression::Binary(x) => {
+            let (left, operator, _, right) = &x.nodes;
+
+            const_expression_flatten(left, syntax_tree, scope, operands, operators)?;
+            operators.push(symbol(RefNode::BinaryOperator(operator), syntax_tree)?);
+            const_expression_flatten(right, syntax_tree, scope, operands, operators)?;
+        }
+
+        sv_parser::ConstantExpression::Unary(x) => {
+            let (operator, _, primary) = &x.nodes;
+            let value = const_primary(primary, syntax_tree, scope)?;
+
+            operands.push(const_unary(
+                &symbol(RefNode::UnaryOperator(operator), syntax_tree)?,
+                value,
+            )?);
+        }
+
+        sv_parser::ConstantExpression::ConstantPrimary(x) => {
+            operands.push(const_primary(x, syntax_tree, scope)?);
+        }
+
+        _ => return None,
+    }
+
+    Some(())
+}
+
+// The same as const_expression_flatten, for an expression which is not a constant expression in
+// the grammar, e.g. a parameter override
+fn const_override_flatten(
+    p: &sv_parser::Expression,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+    operands: &mut Vec<SvPrimaryLiteralIntegral>,
+    operators: &mut Vec<String>,
+) -> Option<()> {
+    match p {
+        sv_parser::Expression::Binary(x) => {
+            let (left, operator, _, right) = &x.nodes;
+
+            const_override_flatten(left, syntax_tree, scope, operands, operators)?;
+            operators.push(symbol(RefNode::BinaryOperator(operator), syntax_tree)?);
+            const_override_flatten(right, syntax_tree, scope, operands, operators)?;
+        }
+
+        sv_parser::Expression::Unary(x) => {
+            let (operator, _, primary) = &x.nodes;
+            let value = const_override_primary(primary, syntax_tree, scope)?;
+
+            operands.push(const_unary(
+                &symbol(RefNode::UnaryOperator(operator), syntax_tree)?,
+                value,
+            )?);
+        }
+
+        sv_parser::Expression::Primary(x) => {
+            operands.push(const_override_primary(x, syntax_tree, scope)?);
+        }
+
+        _ => return None,
+    }
+
+    Some(())
+}
+
+fn const_unary(
+    operator: &str,
+    value: SvPrimaryLiteralIntegral,
+) -> Option<SvPrimaryLiteralIntegral> {
+    match operator {
+        "+" => Some(value),
+        "-" if value.signed => Some(-value),
+        // The two's complement at the width of the operand, e.g. -8'd1 is 8'd255
+        "-" => const_cast(value.inv() + 1, value.size, false),
+        "~" => Some(value.inv()),
+        _ => None,
+    }
+}
+
+fn const_primary(
+    p: &sv_parser::ConstantPrimary,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::ConstantPrimary::PrimaryLiteral(_) => {
+            let literal = get_string(RefNode::ConstantPrimary(p), syntax_tree)?;
+            SvPrimaryLiteralIntegral::from_sv_literal(&literal).ok()
+        }
+
+        sv_parser::ConstantPrimary::MintypmaxExpression(x) => {
+            match unwrap_node!(x.as_ref(), ConstantMintypmaxExpression) {
+                Some(RefNode::ConstantMintypmaxExpression(y)) => {
+                    const_mintypmax_expression(y, syntax_tree, scope)
+                }
+                _ => None,
+            }
+        }
+
+        // Any other primary is only resolved if it is the identifier of a parameter in scope
+        _ => {
+            let id = get_string(RefNode::ConstantPrimary(p), syntax_tree)?;
+            scope.iter().find(|x| x.identifier == id)?.value.clone()
+        }
+    }
+}
+
+fn const_override_primary(
+    p: &sv_parser::Primary,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match p {
+        sv_parser::Primary::PrimaryLiteral(_) => {
+            let literal = get_string(RefNode::Primary(p), syntax_tree)?;
+            SvPrimaryLiteralIntegral::from_sv_literal(&literal).ok()
+        }
+
+        sv_parser::Primary::MintypmaxExpression(x) => {
+            match unwrap_node!(x.as_ref(), MintypmaxExpression) {
+                Some(RefNode::MintypmaxExpression(y)) => {
+                    const_override_mintypmax_expression(y, syntax_tree, scope)
+                }
+                _ => None,
+            }
+        }
+
+        // Any other primary is only resolved if it is the identifier of a parameter in scope
+        _ => {
+            let id = get_string(RefNode::Primary(p), syntax_tree)?;
+            scope.iter().find(|x| x.identifier == id)?.value.clone()
+        }
+    }
+}
+
+// The precedence of the binary operators, as in 1800-2017 | Table 11-2
+fn const_precedence(operator: &str) -> Option<usize> {
+    match operator {
+        "**" => Some(8),
+        "*" | "/" | "%" => Some(7),
+        "+" | "-" => Some(6),
+        "<<" | ">>" => Some(5),
+        "<" | "<=" | ">" | ">=" => Some(4),
+        "==" | "!=" => Some(3),
+        "&" => Some(2),
+        "^" => Some(1),
+        "|" => Some(0),
+        _ => None,
+    }
+}
+
+/// Assigns a value to a declared integral type, i.e. truncates or extends it to the width of the
+/// type, sign extending a signed value, and gives it the signedness of the type. The value is
+/// `None` if the width is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_const_eval::const_cast;
+/// # use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+/// let a = SvPrimaryLiteralIntegral::from_sv_literal("8'hff").unwrap();
+/// let b = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1000").unwrap();
+///
+/// assert_eq!(
+///     const_cast(a.clone(), 4, false),
+///     Some(SvPrimaryLiteralIntegral::from_sv_literal("4'hf").unwrap())
+/// );
+/// assert_eq!(
+///     const_cast(b, 8, false),
+///     Some(SvPrimaryLiteralIntegral::from_sv_literal("8'hf8").unwrap())
+/// );
+/// assert_eq!(const_cast(a, 0, false), None);
+/// ```
+pub fn const_cast(
+    mut value: SvPrimaryLiteralIntegral,
+    width: usize,
+    signed: bool,
+) -> Option<SvPrimaryLiteralIntegral> {
+    if value.size > width {
+        value.try_truncate(width).ok()?;
+    } else if value.signed {
+        value = value.sign_extend_to(width).ok()?;
+    } else {
+        value = value.zero_extend_to(width).ok()?;
+    }
+
+    value.signed = signed;
+    Some(value)
+}
+
+// Replaces the two topmost values with the result of the operator
+fn const_fold(values: &mut Vec<SvPrimaryLiteralIntegral>, operator: &str) -> Option<()> {
+    let right = values.pop()?;
+    let left = values.pop()?;
+
+    values.push(match operator {
+        "**" => left.pow_primlit(right),
+        "*" => left.mult(right),
+        "/" => left.div_primlit(right),
+        "%" => left.mod_primlit(right),
+        "+" => left.add_primlit(right),
+        "-" => left.sub_primlit(right),
+        "<<" => {
+            // The shifted out bits are dropped, so the result keeps the width of the left operand
+            let n = usize::try_from(u64::try_from(right).ok()?).ok()?;
+            let size = left.size;
+            const_cast(left.lsl(std::cmp::min(n, size)), size, left.signed)?
+        }
+        ">>" => left.lsr(usize::try_from(u64::try_from(right).ok()?).ok()?),
+        "<" => left.lt(right),
+        "<=" => left.le(right),
+        ">" => left.gt(right),
+        ">=" => left.ge(right),
+        "==" => left.logical_eq(right),
+        "!=" => left.logical_eq(right).logical_not(),
+        "&" => left.and_primlit(right),
+        "^" => left.xor_primlit(right),
+        "|" => left.or_primlit(right),
+        _ => return None,
+    });
+
+    Some(())
+}
@@ -0,0 +1,176 @@
+use crate::structures::{SvData, SvDiff, SvModuleDeclaration, SvModuleDiff, SvParameter, SvPort};
+
+/// Compares the modules of two `SvData` objects, from the old to the new one.
+///
+/// Modules, ports and parameters are matched by identifier. Ports are compared by their
+/// direction, kind, type, signedness and width, falling back to the packed dimensions when the
+/// width of either port is not known. Parameters are compared by their type, signedness, number
+/// of bits and value, falling back to the expression when neither value is known. Comments,
+/// attributes and spans are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_diff::sv_diff;
+/// let old = parse_sv_file("tests/systemverilog/diff_old.sv", None, None, false).unwrap();
+/// let new = parse_sv_file("tests/systemverilog/diff_new.sv", None, None, false).unwrap();
+/// let diff = sv_diff(&old, &new);
+///
+/// assert_eq!(diff.added_modules, vec!["diff_added"]);
+/// assert_eq!(diff.removed_modules, vec!["diff_removed"]);
+/// assert_eq!(diff.changed_modules.len(), 1);
+///
+/// let module = &diff.changed_modules[0];
+/// assert_eq!(module.identifier, "diff_kept");
+/// assert_eq!(module.added_ports, vec!["ready"]);
+/// assert_eq!(module.removed_ports, Vec::<String>::new());
+/// assert_eq!(
+///     module.renamed_ports,
+///     vec![(String::from("clk"), String::from("clock"))]
+/// );
+/// assert_eq!(module.retyped_ports, vec!["valid"]);
+/// assert_eq!(module.added_parameters, vec!["COUNT"]);
+/// assert_eq!(module.removed_parameters, vec!["DEPTH"]);
+/// assert_eq!(module.changed_parameters, vec!["WIDTH"]);
+///
+/// assert!(sv_diff(&new, &new).is_empty());
+/// ```
+pub fn sv_diff(old: &SvData, new: &SvData) -> SvDiff {
+    let mut ret = SvDiff {
+        added_modules: Vec::new(),
+        removed_modules: Vec::new(),
+        changed_modules: Vec::new(),
+    };
+
+    for module in &old.modules {
+        match new
+            .modules
+            .iter()
+            .find(|x| x.identifier == module.identifier)
+        {
+            Some(x) => {
+                let module_diff = diff_module(module, x);
+
+                if !module_diff.is_empty() {
+                    ret.changed_modules.push(module_diff);
+                }
+            }
+            None => ret.removed_modules.push(module.identifier.clone()),
+        }
+    }
+
+    for module in &new.modules {
+        if !old
+            .modules
+            .iter()
+            .any(|x| x.identifier == module.identifier)
+        {
+            ret.added_modules.push(module.identifier.clone());
+        }
+    }
+
+    ret
+}
+
+fn diff_module(old: &SvModuleDeclaration, new: &SvModuleDeclaration) -> SvModuleDiff {
+    let mut ret = SvModuleDiff {
+        identifier: old.identifier.clone(),
+        added_ports: Vec::new(),
+        removed_ports: Vec::new(),
+        renamed_ports: Vec::new(),
+        retyped_ports: Vec::new(),
+        added_parameters: Vec::new(),
+        removed_parameters: Vec::new(),
+        changed_parameters: Vec::new(),
+    };
+
+    for (index, port) in old.ports.iter().enumerate() {
+        match new.ports.iter().find(|x| x.identifier == port.identifier) {
+            Some(x) => {
+                if !same_port_type(port, x) {
+                    ret.retyped_ports.push(port.identifier.clone());
+                }
+            }
+
+            // Replaced by a new port with the same type at the same position
+            None => match new.ports.get(index) {
+                Some(x)
+                    if same_port_type(port, x)
+                        && !old.ports.iter().any(|y| y.identifier == x.identifier) =>
+                {
+                    ret.renamed_ports
+                        .push((port.identifier.clone(), x.identifier.clone()));
+                }
+                _ => ret.removed_ports.push(port.identifier.clone()),
+            },
+        }
+    }
+
+    for port in &new.ports {
+        let renamed = ret.renamed_ports.iter().any(|(_, x)| *x == port.identifier);
+
+        if !renamed && !old.ports.iter().any(|x| x.identifier == port.identifier) {
+            ret.added_ports.push(port.identifier.clone());
+        }
+    }
+
+    for param in &old.parameters {
+        match new
+            .parameters
+            .iter()
+            .find(|x| x.identifier == param.identifier)
+        {
+            Some(x) => {
+                if !same_parameter(param, x) {
+                    ret.changed_parameters.push(param.identifier.clone());
+                }
+            }
+            None => ret.removed_parameters.push(param.identifier.clone()),
+        }
+    }
+
+    for param in &new.parameters {
+        if !old
+            .parameters
+            .iter()
+            .any(|x| x.identifier == param.identifier)
+        {
+            ret.added_parameters.push(param.identifier.clone());
+        }
+    }
+
+    ret
+}
+
+fn same_port_type(old: &SvPort, new: &SvPort) -> bool {
+    let same_width = match (old.width, new.width) {
+        (Some(x), Some(y)) => x == y,
+        _ => old.packed_dimensions == new.packed_dimensions,
+    };
+
+    same_width
+        && old.direction == new.direction
+        && old.datakind == new.datakind
+        && old.datatype == new.datatype
+        && old.classid == new.classid
+        && old.nettype == new.nettype
+        && old.signedness == new.signedness
+        && old.interface_name == new.interface_name
+        && old.modport_name == new.modport_name
+        && old.unpacked_dimensions == new.unpacked_dimensions
+}
+
+fn same_parameter(old: &SvParameter, new: &SvParameter) -> bool {
+    let same_value = match (&old.value, &new.value) {
+        (None, None) => old.expression == new.expression,
+        (x, y) => x == y,
+    };
+
+    same_value
+        && old.paramtype == new.paramtype
+        && old.datatype == new.datatype
+        && old.classid == new.classid
+        && old.signedness == new.signedness
+        && old.num_bits == new.num_bits
+}
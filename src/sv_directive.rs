@@ -0,0 +1,67 @@
+use crate::structures::SvDirective;
+use crate::sv_misc::{get_string, span};
+use sv_parser::{RefNode, SyntaxTree};
+
+/// Extracts a compiler directive which is kept by the preprocessor, i.e. `` `timescale ``,
+/// `` `default_nettype ``, `` `celldefine ``, `` `endcelldefine `` or `` `resetall ``.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// let svdata = parse_sv_file("tests/systemverilog/directives.sv", None, None, false).unwrap();
+/// let timescale = &svdata.directives[0];
+///
+/// assert_eq!(timescale.name, "timescale");
+/// assert_eq!(timescale.arguments, vec!["1ns", "1ps"]);
+/// assert_eq!(timescale.location, "tests/systemverilog/directives.sv at line 1, column 1");
+/// ```
+pub fn compiler_directive(
+    node: RefNode,
+    syntax_tree: &SyntaxTree,
+    location: String,
+) -> SvDirective {
+    let name = match node {
+        RefNode::TimescaleCompilerDirective(_) => "timescale",
+        RefNode::DefaultNettypeCompilerDirective(_) => "default_nettype",
+        RefNode::CelldefineDriveCompilerDirective(_) => "celldefine",
+        RefNode::EndcelldefineDriveCompilerDirective(_) => "endcelldefine",
+        RefNode::ResetallCompilerDirective(_) => "resetall",
+        _ => unreachable!(),
+    };
+
+    let span = span(node.clone(), syntax_tree);
+    let mut arguments: Vec<String> = Vec::new();
+
+    // The time unit and precision of a timescale, or the net type of a default_nettype
+    match node {
+        RefNode::TimescaleCompilerDirective(x) => {
+            let (_, _, unit, unit_suffix, _, precision, precision_suffix) = &x.nodes;
+            let time = |number, suffix| {
+                Some(
+                    get_string(RefNode::UnsignedNumber(number), syntax_tree)?
+                        + &get_string(RefNode::TimeUnit(suffix), syntax_tree)?,
+                )
+            };
+
+            arguments.extend(time(unit, unit_suffix));
+            arguments.extend(time(precision, precision_suffix));
+        }
+
+        RefNode::DefaultNettypeCompilerDirective(x) => {
+            arguments.extend(get_string(
+                RefNode::DefaultNettypeValue(&x.nodes.2),
+                syntax_tree,
+            ));
+        }
+
+        _ => (),
+    }
+
+    SvDirective {
+        name: String::from(name),
+        arguments,
+        location,
+        span,
+    }
+}
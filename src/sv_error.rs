@@ -0,0 +1,367 @@
+use pyo3::create_exception;
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::PyErr;
+use std::{error::Error, fmt, fs, io, path::Path};
+
+create_exception!(
+    python_svdata,
+    ParseError,
+    PyValueError,
+    "Raised when a systemverilog file is not valid systemverilog."
+);
+
+/// Errors that can occur while reading a systemverilog file and extracting its data.
+///
+/// # Examples
+///
+/// A file that does not exist
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/does_not_exist.sv", None, None, false) {
+///     Err(SvError::Io { .. }) => (),
+///     _ => panic!("Expected an Io error!"),
+/// }
+/// ```
+/// A file that is not valid systemverilog
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/error_parse.sv", None, None, false) {
+///     Err(SvError::Parse {
+///         location: Some(_), ..
+///     }) => (),
+///     _ => panic!("Expected a Parse error!"),
+/// }
+/// ```
+/// An included file that cannot be found in any of the include directories
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/error_include.sv", None, None, false) {
+///     Err(SvError::Include { .. }) => (),
+///     _ => panic!("Expected an Include error!"),
+/// }
+/// ```
+/// A localparam without a default value
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/error_syntax.sv", None, None, false) {
+///     Err(SvError::Syntax(_)) => (),
+///     _ => panic!("Expected a Syntax error!"),
+/// }
+/// ```
+/// A literal size that does not fit in 64 bits
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/error_literal.sv", None, None, false) {
+///     Err(SvError::Literal(_)) => (),
+///     _ => panic!("Expected a Literal error!"),
+/// }
+/// ```
+/// Constructs that are recognized but not supported, e.g. a program, in strict mode
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// let file_path = "tests/systemverilog/warnings.sv";
+/// let warnings = parse_sv_file(file_path, None, None, false).unwrap().warnings;
+///
+/// match parse_sv_file(file_path, None, None, true) {
+///     Err(SvError::Unsupported(x)) => assert_eq!(x, warnings),
+///     _ => panic!("Expected an Unsupported error!"),
+/// }
+/// ```
+/// Packed dimensions whose number of bits overflows
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// match parse_sv_file("tests/systemverilog/error_arithmetic.sv", None, None, false) {
+///     Err(SvError::Arithmetic(_)) => (),
+///     _ => panic!("Expected an Arithmetic error!"),
+/// }
+/// ```
+/// A package declared in two files, in strict mode
+/// ```
+/// # use python_svdata::parse_sv_files;
+/// # use python_svdata::sv_error::SvError;
+/// let file_paths = vec![
+///     String::from("tests/systemverilog/duplicate_package_a.sv"),
+///     String::from("tests/systemverilog/duplicate_package_b.sv"),
+/// ];
+///
+/// match parse_sv_files(&file_paths, None, None, false, true) {
+///     Err(x @ SvError::DuplicatePackage { .. }) => assert_eq!(
+///         x.to_string(),
+///         "Package shared_pkg is declared in both tests/systemverilog/duplicate_package_a.sv \
+///          and tests/systemverilog/duplicate_package_b.sv."
+///     ),
+///     _ => panic!("Expected a DuplicatePackage error!"),
+/// }
+/// ```
+/// Saved data in a format that is not supported
+/// ```
+/// # use python_svdata::load_sv_data;
+/// # use python_svdata::sv_error::SvError;
+/// match load_sv_data("tests/systemverilog/ansi_module.sv") {
+///     Err(SvError::Format(_)) => (),
+///     _ => panic!("Expected a Format error!"),
+/// }
+/// ```
+#[derive(Debug)]
+pub enum SvError {
+    /// The file could not be read.
+    Io { path: String, source: io::Error },
+    /// The file is not valid systemverilog.
+    Parse {
+        path: String,
+        location: Option<SvErrorLocation>,
+    },
+    /// A file included by the file could not be found.
+    Include { path: String, include: String },
+    /// The same module is declared in two files.
+    DuplicateModule {
+        identifier: String,
+        first_path: String,
+        second_path: String,
+    },
+    /// The same module is declared twice in one file.
+    ModuleRedefinition {
+        identifier: String,
+        first_location: String,
+        second_location: String,
+    },
+    /// The same package is declared in two files.
+    DuplicatePackage {
+        identifier: String,
+        first_path: String,
+        second_path: String,
+    },
+    /// The file was parsed but contains an illegal construct.
+    Syntax(String),
+    /// An integral literal could not be parsed.
+    Literal(String),
+    /// An arithmetic operation could not be carried out.
+    Arithmetic(String),
+    /// The module is not declared.
+    UnknownModule(String),
+    /// Modules instantiate themselves, given as the path from a module back to itself.
+    ModuleCycle(Vec<String>),
+    /// The file contains constructs that are recognized but not supported, each described along
+    /// with its location.
+    Unsupported(Vec<String>),
+    /// Saved data could not be converted to or from JSON.
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+    /// Data cannot be saved to or loaded from a file with this extension.
+    Format(String),
+}
+
+/// The location of an error within a systemverilog file.
+///
+/// Both the line and the column start at 1. The snippet is the rest of the offending line from
+/// the column onwards, shortened to at most 40 characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvErrorLocation {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+const SNIPPET_LENGTH: usize = 40;
+
+impl SvErrorLocation {
+    /// Finds the location of the byte offset `pos` within the file at `path`.
+    /// Returns `None` if the file cannot be read or `pos` lies outside of it.
+    pub fn from_file(path: &Path, pos: usize) -> Option<SvErrorLocation> {
+        let text = fs::read_to_string(path).ok()?;
+        SvErrorLocation::from_text(&text, pos)
+    }
+
+    /// Finds the location of the byte offset `pos` within `text`, the contents of a file.
+    /// Returns `None` if `pos` lies outside of it.
+    pub fn from_text(text: &str, pos: usize) -> Option<SvErrorLocation> {
+        let before = text.get(..pos)?;
+
+        let line_start = before.rfind('\n').map_or(0, |x| x + 1);
+        let line_end = text[pos..].find('\n').map_or(text.len(), |x| pos + x);
+
+        Some(SvErrorLocation {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: text[pos..line_end]
+                .trim_end()
+                .chars()
+                .take(SNIPPET_LENGTH)
+                .collect(),
+        })
+    }
+}
+
+impl fmt::Display for SvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SvError::Io { path, source } => write!(f, "Could not read {}: {}", path, source),
+            SvError::Parse {
+                path,
+                location: None,
+            } => write!(f, "Could not parse {}.", path),
+            SvError::Parse {
+                path,
+                location: Some(x),
+            } => write!(
+                f,
+                "Could not parse {} at line {}, column {}: {}",
+                path, x.line, x.column, x.snippet
+            ),
+            SvError::Include { path, include } => {
+                write!(f, "Could not find {} included by {}.", include, path)
+            }
+            SvError::DuplicateModule {
+                identifier,
+                first_path,
+                second_path,
+            } => write!(
+                f,
+                "Module {} is declared in both {} and {}.",
+                identifier, first_path, second_path
+            ),
+            SvError::ModuleRedefinition {
+                identifier,
+                first_location,
+                second_location,
+            } => write!(
+                f,
+                "Module {} is declared twice, in {} and in {}.",
+                identifier, first_location, second_location
+            ),
+            SvError::DuplicatePackage {
+                identifier,
+                first_path,
+                second_path,
+            } => write!(
+                f,
+                "Package {} is declared in both {} and {}.",
+                identifier, first_path, second_path
+            ),
+            SvError::Syntax(x) => write!(f, "Syntax error: {}", x),
+            SvError::Literal(x) => write!(f, "Literal error: {}", x),
+            SvError::Arithmetic(x) => write!(f, "Arithmetic error: {}", x),
+            SvError::UnknownModule(x) => write!(f, "Module {} is not declared.", x),
+            SvError::ModuleCycle(x) => {
+                write!(f, "Modules instantiate themselves: {}.", x.join(" -> "))
+            }
+            SvError::Unsupported(x) => write!(f, "Unsupported constructs: {}", x.join(" ")),
+            SvError::Json { path, source } => {
+                write!(
+                    f,
+                    "Could not convert the data in {} to or from JSON: {}",
+                    path, source
+                )
+            }
+            SvError::Format(x) => write!(
+                f,
+                "The format of {} is not supported, expected a .json file.",
+                x
+            ),
+        }
+    }
+}
+
+impl Error for SvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SvError::Io { source, .. } => Some(source),
+            SvError::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur when the preconditions of an operation on an integral primary literal
+/// are not met.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_error::PrimlitError;
+/// # use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![3],
+///     data_xz: None,
+///     size: 2,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a.try_negate(), Err(PrimlitError::ExpectedSigned));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrimlitError {
+    /// The operation expects signed values.
+    ExpectedSigned,
+    /// The operation expects unsigned values.
+    ExpectedUnsigned,
+    /// The value cannot be truncated to zero bits.
+    ZeroWidth,
+    /// The value has fewer bits than the requested width.
+    TooNarrow { size: usize, requested: usize },
+    /// The value has more bits than the requested width.
+    TooWide { size: usize, requested: usize },
+    /// The index lies outside of the bits of the value.
+    IndexOutOfRange { index: usize, size: usize },
+    /// The LSB of the range is above its MSB.
+    ReversedRange { msb: usize, lsb: usize },
+}
+
+impl fmt::Display for PrimlitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PrimlitError::ExpectedSigned => {
+                write!(f, "Expected a signed value but found unsigned.")
+            }
+            PrimlitError::ExpectedUnsigned => {
+                write!(f, "Expected an unsigned value but found signed.")
+            }
+            PrimlitError::ZeroWidth => write!(f, "Cannot truncate the value to zero bits."),
+            PrimlitError::TooNarrow { size, requested } => write!(
+                f,
+                "Cannot truncate a value of {} bits to {} bits.",
+                size, requested
+            ),
+            PrimlitError::TooWide { size, requested } => write!(
+                f,
+                "Cannot extend a value of {} bits to {} bits, truncate it instead.",
+                size, requested
+            ),
+            PrimlitError::IndexOutOfRange { index, size } => write!(
+                f,
+                "The index {} is outside of the {} bits of the value.",
+                index, size
+            ),
+            PrimlitError::ReversedRange { msb, lsb } => {
+                write!(f, "The LSB {} of the range is above its MSB {}.", lsb, msb)
+            }
+        }
+    }
+}
+
+impl Error for PrimlitError {}
+
+impl From<PrimlitError> for PyErr {
+    fn from(err: PrimlitError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+impl From<SvError> for PyErr {
+    fn from(err: SvError) -> PyErr {
+        match err {
+            SvError::Io { .. } => PyIOError::new_err(err.to_string()),
+            SvError::Parse { .. } => ParseError::new_err(err.to_string()),
+            _ => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::structures::{SvGenerateBlock, SvGenerateKind, SvParamType, SvParameter, SvSpan};
+use crate::sv_const_eval::const_expression;
+use crate::sv_instance::module_instance;
+use crate::sv_misc::{get_string, identifier, span, symbol};
+use crate::sv_primlit_integral::SvPrimaryLiteralIntegral;
+use crate::sv_signal::{net_declaration, variable_declaration};
+use sv_parser::{NodeEvent, RefNode, SyntaxTree};
+
+// A loop which does not end within this many iterations is not counted
+const LOOP_LIMIT: usize = 1 << 16;
+
+/// Extracts a generate loop, e.g. `for (genvar i = 0; i < N; i++) begin : gen_lane ... end`.
+///
+/// The iterations are counted, along with the value of the genvar in each of them, when the
+/// initial value, the condition and the step of the loop can be resolved against the parameters in
+/// `scope`.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// let svdata = parse_sv_file("tests/systemverilog/generate.sv", None, None, false).unwrap();
+/// let blocks = &svdata.modules[1].generate_blocks;
+///
+/// assert_eq!(blocks[0].label.as_deref(), Some("gen_lane"));
+/// assert_eq!(blocks[0].genvar.as_deref(), Some("i"));
+/// assert_eq!(blocks[0].condition.as_deref(), Some("i<LANES"));
+/// assert_eq!(blocks[0].count, Some(4));
+/// assert_eq!(blocks[0].instances[0].hierarchical_instance, "u_lane");
+///
+/// assert_eq!(blocks[1].count, Some(2));
+/// assert_eq!(blocks[1].indices, Some(vec![3, 1]));
+/// assert_eq!(blocks[2].count, None);
+/// ```
+pub fn loop_generate_construct(
+    p: &sv_parser::LoopGenerateConstruct,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> SvGenerateBlock {
+    let (_, header, block) = &p.nodes;
+    let (init, _, condition, _, iteration) = &header.nodes.1;
+    let (_, genvar, _, init_expression) = &init.nodes;
+
+    let mut ret = generate_block(
+        SvGenerateKind::For,
+        block,
+        span(RefNode::LoopGenerateConstruct(p), syntax_tree),
+        syntax_tree,
+        scope,
+    );
+
+    ret.genvar = identifier(RefNode::GenvarIdentifier(genvar), syntax_tree);
+    ret.init = get_string(RefNode::ConstantExpression(init_expression), syntax_tree);
+    ret.condition = get_string(RefNode::GenvarExpression(condition), syntax_tree);
+    ret.step = get_string(RefNode::GenvarIteration(iteration), syntax_tree);
+    ret.indices = generate_indices(init_expression, condition, iteration, syntax_tree, scope);
+    ret.count = ret.indices.as_ref().map(|x| x.len());
+
+    ret
+}
+
+/// Extracts the branches of a generate conditional, e.g. `if (EXTRA) begin ... end else ...`, the
+/// branch that is taken when the condition holds followed by the `else` branch, if any.
+pub fn if_generate_construct(
+    p: &sv_parser::IfGenerateConstruct,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Vec<SvGenerateBlock> {
+    let (_, condition, block, otherwise) = &p.nodes;
+
+    let mut then = generate_block(
+        SvGenerateKind::If,
+        block,
+        span(RefNode::GenerateBlock(block), syntax_tree),
+        syntax_tree,
+        scope,
+    );
+    then.condition = get_string(RefNode::ConstantExpression(&condition.nodes.1), syntax_tree);
+
+    let mut ret = vec![then];
+
+    if let Some((_, block)) = otherwise {
+        ret.push(generate_block(
+            SvGenerateKind::Else,
+            block,
+            span(RefNode::GenerateBlock(block), syntax_tree),
+            syntax_tree,
+            scope,
+        ));
+    }
+
+    ret
+}
+
+fn generate_block(
+    kind: SvGenerateKind,
+    block: &sv_parser::GenerateBlock,
+    span: SvSpan,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> SvGenerateBlock {
+    let mut ret = SvGenerateBlock {
+        kind,
+        label: generate_label(block, syntax_tree),
+        genvar: None,
+        init: None,
+        condition: None,
+        step: None,
+        count: None,
+        indices: None,
+        instances: Vec::new(),
+        signals: Vec::new(),
+        blocks: Vec::new(),
+        span,
+    };
+
+    // The items of nested generate blocks, subroutines and classes are not items of the block
+    let mut depth: usize = 0;
+
+    for event in RefNode::GenerateBlock(block).into_iter().event() {
+        match event {
+            NodeEvent::Enter(RefNode::LoopGenerateConstruct(x)) => {
+                if depth == 0 {
+                    ret.blocks
+                        .push(loop_generate_construct(x, syntax_tree, scope));
+                }
+                depth += 1;
+            }
+
+            NodeEvent::Enter(RefNode::IfGenerateConstruct(x)) => {
+                if depth == 0 {
+                    ret.blocks
+                        .extend(if_generate_construct(x, syntax_tree, scope));
+                }
+                depth += 1;
+            }
+
+            NodeEvent::Enter(RefNode::BlockItemDeclaration(_))
+            | NodeEvent::Enter(RefNode::ClassDeclaration(_)) => depth += 1,
+
+            NodeEvent::Leave(RefNode::LoopGenerateConstruct(_))
+            | NodeEvent::Leave(RefNode::IfGenerateConstruct(_))
+            | NodeEvent::Leave(RefNode::BlockItemDeclaration(_))
+            | NodeEvent::Leave(RefNode::ClassDeclaration(_)) => depth -= 1,
+
+            NodeEvent::Enter(RefNode::ModuleInstantiation(x)) if depth == 0 => {
+                ret.instances.push(module_instance(x, syntax_tree, scope));
+            }
+
+            NodeEvent::Enter(RefNode::NetDeclaration(sv_parser::NetDeclaration::NetType(x)))
+                if depth == 0 =>
+            {
+                ret.signals.extend(net_declaration(x, syntax_tree));
+            }
+
+            NodeEvent::Enter(RefNode::DataDeclaration(sv_parser::DataDeclaration::Variable(x)))
+                if depth == 0 =>
+            {
+                ret.signals.extend(variable_declaration(x, syntax_tree));
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+// The label either precedes the block, `gen_lane : begin`, or follows its begin keyword,
+// `begin : gen_lane`
+pub fn generate_label(
+    block: &sv_parser::GenerateBlock,
+    syntax_tree: &SyntaxTree,
+) -> Option<String> {
+    match block {
+        sv_parser::GenerateBlock::Multiple(x) => {
+            let (prefix, _, suffix, _, _, _) = &x.nodes;
+
+            let id = match (prefix, suffix) {
+                (Some((id, _)), _) | (None, Some((_, id))) => id,
+                _ => return None,
+            };
+
+            identifier(RefNode::GenerateBlockIdentifier(id), syntax_tree)
+        }
+
+        _ => None,
+    }
+}
+
+// Runs the loop with the genvar in scope, which shadows any parameter of the same name, and
+// returns the value of the genvar in each iteration
+fn generate_indices(
+    init: &sv_parser::ConstantExpression,
+    condition: &sv_parser::GenvarExpression,
+    iteration: &sv_parser::GenvarIteration,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<Vec<i64>> {
+    let genvar = match iteration {
+        sv_parser::GenvarIteration::Assignment(x) => &x.nodes.0,
+        sv_parser::GenvarIteration::Prefix(x) => &x.nodes.1,
+        sv_parser::GenvarIteration::Suffix(x) => &x.nodes.0,
+    };
+
+    let genvar = generate_genvar(
+        identifier(RefNode::GenvarIdentifier(genvar), syntax_tree)?,
+        const_expression(init, syntax_tree, scope)?,
+    );
+
+    let mut scope: Vec<SvParameter> = scope.to_vec();
+    scope.insert(0, genvar);
+
+    let mut ret: Vec<i64> = Vec::new();
+
+    loop {
+        let value = const_expression(&condition.nodes.0, syntax_tree, &scope)?;
+
+        if value.contains_xz() {
+            return None;
+        } else if value.is_zero() {
+            return Some(ret);
+        }
+
+        ret.push(i64::try_from(scope[0].value.clone()?).ok()?);
+        if ret.len() > LOOP_LIMIT {
+            return None;
+        }
+
+        scope[0].value = Some(generate_step(iteration, syntax_tree, &scope)?);
+    }
+}
+
+// The next value of the genvar, which is the first parameter in scope
+fn generate_step(
+    iteration: &sv_parser::GenvarIteration,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    let value = i64::try_from(scope[0].value.clone()?).ok()?;
+
+    let operator = match iteration {
+        sv_parser::GenvarIteration::Assignment(x) => {
+            let (_, operator, expression) = &x.nodes;
+            let right = const_expression(&expression.nodes.0, syntax_tree, scope)?;
+            let right = i64::try_from(right).ok()?;
+
+            return match symbol(RefNode::AssignmentOperator(operator), syntax_tree)?.as_str() {
+                "=" => Some(SvPrimaryLiteralIntegral::from(right)),
+                "+=" => value.checked_add(right).map(SvPrimaryLiteralIntegral::from),
+                "-=" => value.checked_sub(right).map(SvPrimaryLiteralIntegral::from),
+                "*=" => value.checked_mul(right).map(SvPrimaryLiteralIntegral::from),
+                _ => None,
+            };
+        }
+        sv_parser::GenvarIteration::Prefix(x) => &x.nodes.0,
+        sv_parser::GenvarIteration::Suffix(x) => &x.nodes.1,
+    };
+
+    match symbol(RefNode::IncOrDecOperator(operator), syntax_tree)?.as_str() {
+        "++" => value.checked_add(1),
+        "--" => value.checked_sub(1),
+        _ => None,
+    }
+    .map(SvPrimaryLiteralIntegral::from)
+}
+
+fn generate_genvar(identifier: String, value: SvPrimaryLiteralIntegral) -> SvParameter {
+    SvParameter {
+        identifier,
+        expression: None,
+        paramtype: SvParamType::LocalParam,
+        datatype: None,
+        datatype_overridable: false,
+        classid: None,
+        signedness: None,
+        signedness_overridable: false,
+        num_bits: None,
+        packed_dimensions: Vec::new(),
+        unpacked_dimensions: Vec::new(),
+        comment: None,
+        value: Some(value),
+        span: (0, 0),
+    }
+}
@@ -0,0 +1,313 @@
+use crate::structures::{
+    SvData, SvElaboratedDesign, SvElaboratedInstance, SvGenerateBlock, SvGenerateKind, SvHierarchy,
+    SvInstance, SvModuleDeclaration,
+};
+use crate::sv_error::SvError;
+use std::collections::{BTreeMap, HashSet};
+
+/// Builds the module hierarchy of the modules in an `SvData` object.
+///
+/// An instance of a module or interface which is not declared in the same `SvData` object is a
+/// black box. A module which instantiates itself, directly or through other modules, is reported
+/// as a cycle.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_hierarchy::sv_hierarchy;
+/// let svdata = parse_sv_file("tests/systemverilog/hierarchy.sv", None, None, false).unwrap();
+/// let hierarchy = sv_hierarchy(&svdata);
+///
+/// assert_eq!(
+///     hierarchy.children["hierarchy_top"],
+///     vec!["hierarchy_mid", "black_box"]
+/// );
+/// assert_eq!(hierarchy.children["hierarchy_leaf"], Vec::<String>::new());
+/// assert_eq!(hierarchy.black_boxes["hierarchy_top"], vec!["black_box"]);
+/// assert_eq!(
+///     hierarchy.cycles,
+///     vec![vec!["hierarchy_ping", "hierarchy_pong", "hierarchy_ping"]]
+/// );
+/// ```
+pub fn sv_hierarchy(svdata: &SvData) -> SvHierarchy {
+    let declared: HashSet<&str> = svdata
+        .modules
+        .iter()
+        .map(|x| x.identifier.as_str())
+        .chain(svdata.interfaces.iter().map(|x| x.identifier.as_str()))
+        .collect();
+
+    let mut ret = SvHierarchy {
+        children: BTreeMap::new(),
+        black_boxes: BTreeMap::new(),
+        cycles: Vec::new(),
+    };
+
+    for module in &svdata.modules {
+        let mut children: Vec<String> = Vec::new();
+
+        for instance in module_instances(module) {
+            if !children.contains(&instance.module_identifier) {
+                children.push(instance.module_identifier.clone());
+            }
+        }
+
+        let black_boxes: Vec<String> = children
+            .iter()
+            .filter(|x| !declared.contains(x.as_str()))
+            .cloned()
+            .collect();
+
+        if !black_boxes.is_empty() {
+            ret.black_boxes
+                .insert(module.identifier.clone(), black_boxes);
+        }
+
+        ret.children.insert(module.identifier.clone(), children);
+    }
+
+    let mut visited: HashSet<String> = HashSet::new();
+
+    for module in ret.children.keys() {
+        hierarchy_cycles(
+            module,
+            &ret.children,
+            &mut Vec::new(),
+            &mut visited,
+            &mut ret.cycles,
+        );
+    }
+
+    ret
+}
+
+// Depth first search in which the path holds the modules that are being visited, so an instance
+// of a module on the path closes a cycle.
+fn hierarchy_cycles(
+    module: &str,
+    children: &BTreeMap<String, Vec<String>>,
+    path: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    if let Some(x) = path.iter().position(|x| x == module) {
+        let mut cycle: Vec<String> = path[x..].to_vec();
+        cycle.push(module.to_string());
+        cycles.push(cycle);
+        return;
+    }
+
+    if !visited.insert(module.to_string()) {
+        return;
+    }
+
+    path.push(module.to_string());
+    for child in children.get(module).into_iter().flatten() {
+        hierarchy_cycles(child, children, path, visited, cycles);
+    }
+    path.pop();
+}
+
+/// Elaborates the instances below the module `top`, giving each instance its full instance path,
+/// e.g. `top.u_sub.u_leaf`, and the module that it instantiates.
+///
+/// The path of an instance in a generate block includes the label of the block, and the value of
+/// the genvar for each iteration of a loop, e.g. `top.gen_lane[0].u_lane`. An unlabeled block is
+/// named `genblk<n>`, as in 1800-2017 | 27.6. The conditions of generate conditionals are not
+/// evaluated, so the instances of all their branches are elaborated, and the instances of a loop
+/// whose iterations cannot be counted are elaborated once, indexed by the genvar itself.
+///
+/// The instances are resolved against the modules and interfaces of the `SvData` object. An
+/// instance of a module which is not declared is a black box. Fails if `top` is not declared as a
+/// module, or if a module below it instantiates itself, directly or through other modules.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_error::SvError;
+/// # use python_svdata::sv_hierarchy::sv_elaborate;
+/// let svdata = parse_sv_file("tests/systemverilog/hierarchy.sv", None, None, false).unwrap();
+/// let design = sv_elaborate(&svdata, "hierarchy_top").unwrap();
+/// let paths: Vec<&str> = design.instances.iter().map(|x| x.path.as_str()).collect();
+///
+/// assert_eq!(
+///     paths,
+///     vec![
+///         "hierarchy_top",
+///         "hierarchy_top.u_mid_0",
+///         "hierarchy_top.u_mid_0.u_leaf",
+///         "hierarchy_top.u_mid_1",
+///         "hierarchy_top.u_mid_1.u_leaf",
+///         "hierarchy_top.u_black_box",
+///     ]
+/// );
+/// assert_eq!(design.instances[2].module_identifier, "hierarchy_leaf");
+/// assert!(design.instances[2].leaf);
+/// assert!(design.instances[5].black_box);
+///
+/// match sv_elaborate(&svdata, "hierarchy_ping") {
+///     Err(SvError::ModuleCycle(x)) => {
+///         assert_eq!(x, vec!["hierarchy_ping", "hierarchy_pong", "hierarchy_ping"])
+///     }
+///     _ => panic!("Expected a ModuleCycle error!"),
+/// }
+/// ```
+pub fn sv_elaborate(svdata: &SvData, top: &str) -> Result<SvElaboratedDesign, SvError> {
+    let module = match svdata.modules.iter().find(|x| x.identifier == top) {
+        Some(x) => x,
+        None => return Err(SvError::UnknownModule(top.to_string())),
+    };
+
+    let mut ret = SvElaboratedDesign {
+        top: top.to_string(),
+        instances: vec![SvElaboratedInstance {
+            path: top.to_string(),
+            module_identifier: top.to_string(),
+            leaf: module_instances(module).is_empty(),
+            black_box: false,
+        }],
+    };
+
+    elaborate_instances(svdata, module, top, &mut Vec::new(), &mut ret.instances)?;
+
+    Ok(ret)
+}
+
+// Depth first, like hierarchy_cycles, except that a module is elaborated again for every instance
+// of it, so the path only serves to find cycles.
+fn elaborate_instances(
+    svdata: &SvData,
+    module: &SvModuleDeclaration,
+    instance_path: &str,
+    path: &mut Vec<String>,
+    instances: &mut Vec<SvElaboratedInstance>,
+) -> Result<(), SvError> {
+    path.push(module.identifier.clone());
+
+    for (child_path, instance) in instance_paths(module, instance_path) {
+        let child = svdata
+            .modules
+            .iter()
+            .find(|x| x.identifier == instance.module_identifier);
+
+        match child {
+            Some(x) => {
+                if let Some(y) = path.iter().position(|y| *y == x.identifier) {
+                    let mut cycle: Vec<String> = path[y..].to_vec();
+                    cycle.push(x.identifier.clone());
+                    return Err(SvError::ModuleCycle(cycle));
+                }
+
+                instances.push(SvElaboratedInstance {
+                    path: child_path.clone(),
+                    module_identifier: x.identifier.clone(),
+                    leaf: module_instances(x).is_empty(),
+                    black_box: false,
+                });
+
+                elaborate_instances(svdata, x, &child_path, path, instances)?;
+            }
+            None => instances.push(SvElaboratedInstance {
+                path: child_path,
+                module_identifier: instance.module_identifier.clone(),
+                leaf: true,
+                black_box: !svdata
+                    .interfaces
+                    .iter()
+                    .any(|x| x.identifier == instance.module_identifier),
+            }),
+        }
+    }
+
+    path.pop();
+
+    Ok(())
+}
+
+// The instances of a module, followed by those in its generate blocks
+fn module_instances(module: &SvModuleDeclaration) -> Vec<&SvInstance> {
+    let mut ret: Vec<&SvInstance> = module.instances.iter().collect();
+
+    for block in &module.generate_blocks {
+        generate_instances(block, &mut ret);
+    }
+
+    ret
+}
+
+fn generate_instances<'a>(block: &'a SvGenerateBlock, instances: &mut Vec<&'a SvInstance>) {
+    instances.extend(&block.instances);
+
+    for x in &block.blocks {
+        generate_instances(x, instances);
+    }
+}
+
+// The instances of a module with their instance paths, followed by those in its generate blocks.
+// The labels of the generate case blocks around an instance are in its hierarchy.
+fn instance_paths<'a>(
+    module: &'a SvModuleDeclaration,
+    instance_path: &str,
+) -> Vec<(String, &'a SvInstance)> {
+    let mut ret: Vec<(String, &SvInstance)> = Vec::new();
+
+    for instance in &module.instances {
+        let mut path: Vec<&str> = vec![instance_path];
+        path.extend(instance.hierarchy.iter().map(|x| x.as_str()));
+        path.push(&instance.hierarchical_instance);
+
+        ret.push((path.join("."), instance));
+    }
+
+    generate_paths(&module.generate_blocks, instance_path, &mut ret);
+
+    ret
+}
+
+// An unlabeled block is numbered after its generate construct in the enclosing scope, and both
+// branches of a conditional belong to the same construct
+fn generate_paths<'a>(
+    blocks: &'a [SvGenerateBlock],
+    scope_path: &str,
+    paths: &mut Vec<(String, &'a SvInstance)>,
+) {
+    let mut number: usize = 0;
+
+    for block in blocks {
+        if block.kind != SvGenerateKind::Else {
+            number += 1;
+        }
+
+        let name: String = match &block.label {
+            Some(x) => x.clone(),
+            None => format!("genblk{}", number),
+        };
+
+        let scopes: Vec<String> = match (&block.kind, &block.indices) {
+            (SvGenerateKind::For, Some(x)) => x
+                .iter()
+                .map(|index| format!("{}.{}[{}]", scope_path, name, index))
+                .collect(),
+            (SvGenerateKind::For, None) => vec![format!(
+                "{}.{}[{}]",
+                scope_path,
+                name,
+                block.genvar.as_deref().unwrap_or_default()
+            )],
+            _ => vec![format!("{}.{}", scope_path, name)],
+        };
+
+        for scope in scopes {
+            for instance in &block.instances {
+                paths.push((
+                    format!("{}.{}", scope, instance.hierarchical_instance),
+                    instance,
+                ));
+            }
+
+            generate_paths(&block.blocks, &scope, paths);
+        }
+    }
+}
@@ -1,18 +1,110 @@
-use crate::structures::SvInstance;
-use crate::sv_misc::{get_string, identifier};
+use crate::structures::{SvInstance, SvParamType, SvParameter, SvPortConnectionKind};
+use crate::sv_const_eval::{const_expression_str, const_param_override};
+use crate::sv_generate::generate_label;
+use crate::sv_misc::{get_string, identifier, span};
+use crate::sv_primlit_integral::SvPrimaryLiteralIntegral;
 use sv_parser::{unwrap_node, RefNode, SyntaxTree};
 
-pub fn module_instance(p: &sv_parser::ModuleInstantiation, syntax_tree: &SyntaxTree) -> SvInstance {
+// The parameter overrides are folded against the parameters of the parent module in scope
+pub fn module_instance(
+    p: &sv_parser::ModuleInstantiation,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> SvInstance {
+    let (connections, connection_kinds) = inst_connections(p, syntax_tree);
+
     let ret = SvInstance {
         module_identifier: inst_module_identifier(p, syntax_tree),
         hierarchical_instance: inst_hierarchical_instance(p, syntax_tree),
         hierarchy: inst_hierarchy(p, syntax_tree),
-        connections: inst_connections(p, syntax_tree),
+        parameter_overrides: inst_parameter_overrides(p, syntax_tree),
+        parameter_values: inst_parameter_values(p, syntax_tree, scope),
+        connections,
+        connection_kinds,
+        span: span(RefNode::ModuleInstantiation(p), syntax_tree),
     };
 
     ret
 }
 
+/// Resolves the parameters of the module that an instance instantiates, given the parameters
+/// declared by that module, by layering the parameter overrides of the instance on the defaults.
+///
+/// Overrides by name replace the parameter of the same name, and overrides by order replace the
+/// parameters that are not localparams, in order of declaration. An empty override by name, e.g.
+/// `.DEPTH()`, keeps the default. The defaults of the parameters
+/// that are not overridden are folded again, so that they follow the overrides of the parameters
+/// that they depend on.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_instance::inst_resolve_parameters;
+/// let svdata = parse_sv_file("tests/systemverilog/instances.sv", None, None, false).unwrap();
+/// let child = &svdata.modules[0];
+/// let instances = &svdata.modules[1].instances;
+///
+/// let values = |x: usize| -> Vec<u64> {
+///     inst_resolve_parameters(&instances[x], &child.parameters)
+///         .into_iter()
+///         .map(|x| u64::try_from(x.value.unwrap()).unwrap())
+///         .collect()
+/// };
+///
+/// assert_eq!(values(0), vec![8, 8, 64]);
+/// assert_eq!(values(1), vec![8, 2, 16]);
+/// assert_eq!(values(3), vec![8, 1, 8]);
+/// ```
+pub fn inst_resolve_parameters(
+    instance: &SvInstance,
+    parameters: &[SvParameter],
+) -> Vec<SvParameter> {
+    let mut ret: Vec<SvParameter> = Vec::new();
+    let mut position: usize = 0;
+
+    for parameter in parameters {
+        let mut parameter = parameter.clone();
+
+        let overridden = match parameter.paramtype {
+            SvParamType::LocalParam => None,
+            SvParamType::Parameter => {
+                position += 1;
+
+                instance
+                    .parameter_overrides
+                    .iter()
+                    .zip(&instance.parameter_values)
+                    .enumerate()
+                    .find(|(index, (x, _))| match x.as_slice() {
+                        [identifier, expression] => {
+                            *identifier == parameter.identifier && !expression.is_empty()
+                        }
+                        [_] => *index == position - 1,
+                        _ => false,
+                    })
+                    .map(|(_, x)| x)
+            }
+        };
+
+        match overridden {
+            Some((x, value)) => {
+                parameter.expression = x.last().cloned();
+                parameter.value = value.clone();
+            }
+            None => {
+                if let Some(expression) = &parameter.expression {
+                    parameter.value = const_expression_str(expression, &ret);
+                }
+            }
+        }
+
+        ret.push(parameter);
+    }
+
+    ret
+}
+
 // Find module identifier for the instantiation (child module)
 fn inst_module_identifier(p: &sv_parser::ModuleInstantiation, syntax_tree: &SyntaxTree) -> String {
     if let Some(id) = unwrap_node!(p, ModuleIdentifier) {
@@ -44,15 +136,9 @@ fn inst_hierarchy(p: &sv_parser::ModuleInstantiation, syntax_tree: &SyntaxTree)
                 for instance in x {
                     match instance {
                         RefNode::ModuleInstantiation(y) => {
+                            // An unlabeled block has no label in the hierarchy
                             if y == p {
-                                if let Some(label) =
-                                    unwrap_node!(node.clone(), GenerateBlockIdentifier)
-                                {
-                                    let label = identifier(label, syntax_tree).unwrap();
-                                    ret.push(label);
-                                } else {
-                                    unreachable!()
-                                }
+                                ret.extend(generate_label(x, syntax_tree));
                             }
                         }
                         _ => (),
@@ -66,8 +152,8 @@ fn inst_hierarchy(p: &sv_parser::ModuleInstantiation, syntax_tree: &SyntaxTree)
     ret
 }
 
-// Finding connections for the instantiation
-fn inst_connections(
+// Finding parameter overrides for the instantiation
+fn inst_parameter_overrides(
     p: &sv_parser::ModuleInstantiation,
     syntax_tree: &SyntaxTree,
 ) -> Vec<Vec<String>> {
@@ -75,116 +161,96 @@ fn inst_connections(
 
     for node in p {
         match node {
-            // Port connection by name
-            RefNode::NamedPortConnection(x) => {
-                // Connection in child module
-                let left = unwrap_node!(node.clone(), PortIdentifier).unwrap();
+            // Parameter override by name
+            RefNode::NamedParameterAssignment(x) => {
+                let left = unwrap_node!(x, ParameterIdentifier).unwrap();
                 let left = identifier(left, &syntax_tree).unwrap();
-                // Connection in parent module
-                if let Some(right_node) = unwrap_node!(node.clone(), HierarchicalIdentifier) {
-                    let right_name = identifier(right_node, &syntax_tree).unwrap();
-                    let mut right_index = String::new();
-                    for select_node in x {
-                        match select_node {
-                            RefNode::Select(y) => {
-                                for expression_node in y {
-                                    match expression_node {
-                                        // Indexing a variable
-                                        RefNode::HierarchicalIdentifier(_) => {
-                                            if let Some(right_node) =
-                                                unwrap_node!(expression_node.clone(), Identifier)
-                                            {
-                                                right_index =
-                                                    identifier(right_node, &syntax_tree).unwrap();
-                                            } else {
-                                                unreachable!()
-                                            }
-                                        }
-                                        // Indexing a number
-                                        RefNode::IntegralNumber(_) => {
-                                            if let Some(right_node) =
-                                                unwrap_node!(expression_node.clone(), DecimalNumber)
-                                            {
-                                                right_index =
-                                                    get_string(right_node, &syntax_tree).unwrap();
-                                            } else {
-                                                unreachable!()
-                                            }
-                                        }
-                                        _ => (),
-                                    }
-                                }
-                            }
-                            _ => (),
-                        }
-                    }
-                    // Push connection to ret
-                    if right_index == "" {
-                        // If no indexing
-                        ret.push([left, right_name].to_vec());
-                    } else {
-                        // If there is indexing
-                        let right = format!("{}[{}]", right_name, right_index);
-                        ret.push([left, right].to_vec());
-                    }
-                } else {
-                    ret.push([left, String::from("")].to_vec());
-                }
+                let right = match unwrap_node!(x, ParamExpression) {
+                    Some(right_node) => get_string(right_node, &syntax_tree).unwrap(),
+                    None => String::new(),
+                };
+                ret.push([left, right].to_vec());
+            }
+            // Parameter override by order
+            RefNode::OrderedParameterAssignment(x) => {
+                let right = unwrap_node!(x, ParamExpression).unwrap();
+                ret.push([get_string(right, &syntax_tree).unwrap()].to_vec());
             }
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+// Folding the parameter overrides for the instantiation, in the same order as the overrides
+fn inst_parameter_values(
+    p: &sv_parser::ModuleInstantiation,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Vec<Option<SvPrimaryLiteralIntegral>> {
+    let mut ret: Vec<Option<SvPrimaryLiteralIntegral>> = Vec::new();
+
+    for node in p {
+        let expression = match node {
+            RefNode::NamedParameterAssignment(x) => unwrap_node!(x, ParamExpression),
+            RefNode::OrderedParameterAssignment(x) => unwrap_node!(x, ParamExpression),
+            _ => continue,
+        };
+
+        ret.push(match expression {
+            Some(RefNode::ParamExpression(x)) => const_param_override(x, syntax_tree, scope),
+            _ => None,
+        });
+    }
+
+    ret
+}
+
+// Finding connections for the instantiation, along with the kind of each connection
+fn inst_connections(
+    p: &sv_parser::ModuleInstantiation,
+    syntax_tree: &SyntaxTree,
+) -> (Vec<Vec<String>>, Vec<SvPortConnectionKind>) {
+    let mut ret: Vec<Vec<String>> = Vec::new();
+    let mut kinds: Vec<SvPortConnectionKind> = Vec::new();
+
+    for node in p {
+        match node {
+            // Port connection by name
+            RefNode::NamedPortConnection(x) => match x {
+                sv_parser::NamedPortConnection::Identifier(y) => {
+                    let (_, _, left, paren) = &y.nodes;
+                    // Connection in child module
+                    let left = identifier(RefNode::PortIdentifier(left), &syntax_tree).unwrap();
+                    // Connection in parent module, which is implicitly a signal of the same
+                    // name when there are no parentheses
+                    let right = match unwrap_node!(x, Expression) {
+                        Some(right_node) => get_string(right_node, &syntax_tree).unwrap(),
+                        None => String::new(),
+                    };
+                    ret.push([left, right].to_vec());
+                    kinds.push(match paren {
+                        Some(_) => SvPortConnectionKind::Explicit,
+                        None => SvPortConnectionKind::Implicit,
+                    });
+                }
+                // A wildcard connection has neither a port nor an expression
+                sv_parser::NamedPortConnection::Asterisk(_) => {
+                    ret.push(Vec::new());
+                    kinds.push(SvPortConnectionKind::Wildcard);
+                }
+            },
             // Port connection by order
             RefNode::OrderedPortConnection(x) => {
-                if let Some(right_node) = unwrap_node!(node.clone(), HierarchicalIdentifier) {
-                    let right_name = identifier(right_node, &syntax_tree).unwrap();
-                    // TODO: Mutating a string is a bit dodgy here.
-                    let mut right_index = String::new();
-                    for select_node in x {
-                        match select_node {
-                            RefNode::Select(y) => {
-                                for expression_node in y {
-                                    match expression_node {
-                                        // Indexing a variable
-                                        RefNode::HierarchicalIdentifier(_) => {
-                                            if let Some(right_node) =
-                                                unwrap_node!(expression_node.clone(), Identifier)
-                                            {
-                                                right_index =
-                                                    identifier(right_node, &syntax_tree).unwrap();
-                                            } else {
-                                                unreachable!()
-                                            }
-                                        }
-                                        // Indexing a number
-                                        RefNode::IntegralNumber(_) => {
-                                            if let Some(right_node) =
-                                                unwrap_node!(expression_node.clone(), DecimalNumber)
-                                            {
-                                                right_index =
-                                                    get_string(right_node, &syntax_tree).unwrap();
-                                            } else {
-                                                unreachable!()
-                                            }
-                                        }
-                                        _ => (),
-                                    }
-                                }
-                            }
-                            _ => (),
-                        }
-                    }
-                    // Push connection to ret
-                    if right_index == "" {
-                        // If no indexing
-                        ret.push([right_name].to_vec());
-                    } else {
-                        // If there is indexing
-                        let right = format!("{}[{}]", right_name, right_index);
-                        ret.push([right].to_vec());
-                    }
+                if let Some(right_node) = unwrap_node!(x, Expression) {
+                    ret.push([get_string(right_node, &syntax_tree).unwrap()].to_vec());
+                    kinds.push(SvPortConnectionKind::Explicit);
                 }
             }
             _ => (),
         }
     }
 
-    ret
+    (ret, kinds)
 }
@@ -0,0 +1,84 @@
+use crate::structures::{SvAttribute, SvInterface, SvModport, SvPort, SvPortDirection};
+use crate::sv_misc::{identifier, span};
+use crate::sv_port::{port_attributes_ansi, port_declaration_ansi};
+use sv_parser::{unwrap_node, RefNode, SyntaxTree};
+
+pub fn interface_declaration(m: RefNode, syntax_tree: &SyntaxTree, filepath: &str) -> SvInterface {
+    let mut ret = SvInterface {
+        identifier: interface_identifier(m.clone(), syntax_tree).unwrap(),
+        ports: Vec::new(),
+        modports: Vec::new(),
+        filepath: String::from(filepath),
+        span: span(m.clone(), syntax_tree),
+    };
+
+    let mut prev_port: Option<SvPort> = None;
+    let mut port_attrs: Vec<Vec<SvAttribute>> = Vec::new();
+
+    for node in m {
+        match node {
+            RefNode::ListOfPortDeclarations(p) => {
+                port_attrs = port_attributes_ansi(p, syntax_tree);
+            }
+
+            RefNode::AnsiPortDeclaration(p) => {
+                let mut parsed_port: SvPort = port_declaration_ansi(p, syntax_tree, &prev_port);
+                parsed_port.attributes =
+                    port_attrs.get(ret.ports.len()).cloned().unwrap_or_default();
+                ret.ports.push(parsed_port.clone());
+                prev_port = Some(parsed_port);
+            }
+
+            RefNode::ModportItem(p) => {
+                ret.modports.push(modport_item(p, syntax_tree));
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+fn modport_item(node: &sv_parser::ModportItem, syntax_tree: &SyntaxTree) -> SvModport {
+    let mut ret = SvModport {
+        identifier: modport_identifier(node, syntax_tree),
+        ports: Vec::new(),
+    };
+
+    // The direction applies to every simple port that follows it, until the next direction.
+    let mut direction = SvPortDirection::IMPLICIT;
+
+    for sub_node in node {
+        match sub_node {
+            RefNode::PortDirection(x) => {
+                direction = match x {
+                    sv_parser::PortDirection::Inout(_) => SvPortDirection::Inout,
+                    sv_parser::PortDirection::Input(_) => SvPortDirection::Input,
+                    sv_parser::PortDirection::Output(_) => SvPortDirection::Output,
+                    sv_parser::PortDirection::Ref(_) => SvPortDirection::Ref,
+                };
+            }
+
+            RefNode::ModportSimplePort(x) => {
+                let id = unwrap_node!(x, PortIdentifier).unwrap();
+                ret.ports
+                    .push((identifier(id, syntax_tree).unwrap(), direction.clone()));
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+fn interface_identifier(node: RefNode, syntax_tree: &SyntaxTree) -> Option<String> {
+    let id = unwrap_node!(node, InterfaceIdentifier).unwrap();
+    identifier(id, &syntax_tree)
+}
+
+fn modport_identifier(node: &sv_parser::ModportItem, syntax_tree: &SyntaxTree) -> String {
+    let id = unwrap_node!(node, ModportIdentifier).unwrap();
+    identifier(id, syntax_tree).unwrap()
+}
@@ -1,4 +1,6 @@
-use sv_parser::{unwrap_node, NodeEvent, RefNode, SyntaxTree};
+use crate::structures::{SvAttribute, SvSpan};
+use crate::sv_primlit_integral::SvPrimaryLiteralIntegral;
+use sv_parser::{unwrap_locate, unwrap_node, Locate, NodeEvent, RefNode, SyntaxTree};
 
 pub fn identifier(parent: RefNode, syntax_tree: &SyntaxTree) -> Option<String> {
     let id = match unwrap_node!(parent, SimpleIdentifier, EscapedIdentifier) {
@@ -88,3 +90,167 @@ pub fn get_comment(parent: RefNode, syntax_tree: &SyntaxTree) -> Option<Vec<Stri
         Some(ret)
     }
 }
+
+// Every attribute of the attribute instances, e.g. ("keep", Some("\"true\"")) for (* keep = "true" *)
+pub fn attributes(
+    attrs: &[sv_parser::AttributeInstance],
+    syntax_tree: &SyntaxTree,
+) -> Vec<SvAttribute> {
+    let mut ret: Vec<SvAttribute> = Vec::new();
+
+    for attr in attrs {
+        for node in attr {
+            match node {
+                RefNode::AttrSpec(x) => {
+                    let (name, value) = &x.nodes;
+
+                    ret.push((
+                        identifier(RefNode::Identifier(name), syntax_tree).unwrap(),
+                        match value {
+                            Some((_, y)) => get_string(RefNode::ConstantExpression(y), syntax_tree),
+                            None => None,
+                        },
+                    ));
+                }
+
+                _ => (),
+            }
+        }
+    }
+
+    ret
+}
+
+// The comment on the lines immediately above a node, skipping any attributes in between. This is a
+// best effort, which only looks at the source text: a comment that follows code on the same line
+// belongs to that code, and so does not count.
+pub fn doc_comment(node: RefNode, syntax_tree: &SyntaxTree) -> Option<String> {
+    let start = unwrap_locate!(node)?;
+    let before = Locate {
+        offset: 0,
+        line: 1,
+        len: start.offset,
+    };
+    let mut text: &str = syntax_tree.get_str(&before).unwrap_or_default().trim_end();
+
+    while text.ends_with("*)") {
+        match text.rfind("(*") {
+            Some(x) => text = text[..x].trim_end(),
+            None => break,
+        }
+    }
+
+    let mut lines: Vec<&str> = Vec::new();
+    let mut in_block: bool = false;
+
+    for line in text.lines().rev().map(|x| x.trim()) {
+        if in_block {
+            lines.push(line);
+            in_block = !line.starts_with("/*");
+        } else if line.starts_with("//") {
+            lines.push(line);
+        } else if line.ends_with("*/") && line.starts_with("/*") {
+            lines.push(line);
+        } else if line.ends_with("*/") && !line.contains("/*") {
+            lines.push(line);
+            in_block = true;
+        } else {
+            break;
+        }
+    }
+
+    let comment: Vec<&str> = lines
+        .iter()
+        .rev()
+        .map(|x| match x.strip_prefix("//") {
+            Some(y) => y.trim_start_matches('/').trim(),
+            None => x
+                .trim_start_matches("/*")
+                .trim_start_matches('*')
+                .trim_end_matches("*/")
+                .trim(),
+        })
+        .collect();
+    let comment: String = comment.join("\n").trim().to_string();
+
+    if comment.is_empty() {
+        None
+    } else {
+        Some(comment)
+    }
+}
+
+// The byte offsets of a node within its file, from the start of its first token to the end of its
+// last token. The offsets within the preprocessed text are used for text without an origin.
+pub fn span(node: RefNode, syntax_tree: &SyntaxTree) -> SvSpan {
+    let mut first: Option<&Locate> = None;
+    let mut last: Option<&Locate> = None;
+    let mut skip_whitespace: bool = false;
+
+    for event in node.into_iter().event() {
+        match event {
+            NodeEvent::Enter(RefNode::WhiteSpace(_)) => skip_whitespace = true,
+            NodeEvent::Leave(RefNode::WhiteSpace(_)) => skip_whitespace = false,
+            NodeEvent::Enter(RefNode::Locate(x)) => {
+                if !skip_whitespace {
+                    first.get_or_insert(x);
+                    last = Some(x);
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    let origin = |x: &Locate| match syntax_tree.get_origin(x) {
+        Some((_, pos)) => pos,
+        None => x.offset,
+    };
+
+    match (first, last) {
+        (Some(first), Some(last)) => (origin(first), origin(last) + last.len),
+        _ => (0, 0),
+    }
+}
+
+// The source text of a node, from its first token up to the end of the whitespace and comments
+// that follow its last token
+pub fn node_text<'a>(node: RefNode, syntax_tree: &'a SyntaxTree) -> Option<&'a str> {
+    let mut start: Option<usize> = None;
+    let mut end: usize = 0;
+
+    for x in node {
+        if let RefNode::Locate(y) = x {
+            start.get_or_insert(y.offset);
+            end = y.offset + y.len;
+        }
+    }
+
+    let start = start?;
+    syntax_tree.get_str(&Locate {
+        offset: start,
+        line: 1,
+        len: end - start,
+    })
+}
+
+// Text as written in the source, without surrounding whitespace, e.g. from `SyntaxTree::get_str`
+pub fn source_text(source: Option<&str>) -> Option<String> {
+    match source.map(|x| x.trim()) {
+        Some(x) if !x.is_empty() => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+// Only unsized decimal numbers are resolved, which are 32bit signed values. A number that does not
+// fit, e.g. 4294967295, is not resolved rather than wrapping around to a negative value.
+pub fn unsized_decimal(expression: &str) -> Option<SvPrimaryLiteralIntegral> {
+    let value: i32 = expression.replace('_', "").parse().ok()?;
+
+    Some(SvPrimaryLiteralIntegral {
+        data_01: vec![value as u32 as usize],
+        data_xz: None,
+        size: 32,
+        signed: true,
+    })
+}
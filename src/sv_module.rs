@@ -1,27 +1,57 @@
-use crate::structures::{SvInstance, SvModuleDeclaration, SvParamType, SvPort};
+use crate::structures::{
+    SvAttribute, SvInstance, SvModuleDeclaration, SvParamType, SvPort, SvSignal,
+};
+use crate::sv_error::SvError;
+use crate::sv_generate::{if_generate_construct, loop_generate_construct};
 use crate::sv_instance::module_instance;
-use crate::sv_misc::identifier;
-use crate::sv_port::{port_declaration_ansi, port_parameter_declaration_ansi};
+use crate::sv_misc::{attributes, doc_comment, identifier, span};
+use crate::sv_port::{
+    parameter_port_list, port_attributes_ansi, port_declaration_ansi,
+    port_parameter_declaration_ansi,
+};
+use crate::sv_signal::{net_declaration, variable_declaration};
+use crate::sv_subroutine::{function_declaration, task_declaration};
+use crate::sv_typedef::typedef_declaration;
 use sv_parser::{unwrap_node, NodeEvent, RefNode, SyntaxTree};
 
 pub fn module_declaration_ansi(
     m: RefNode,
     syntax_tree: &SyntaxTree,
     filepath: &str,
-) -> SvModuleDeclaration {
+) -> Result<SvModuleDeclaration, SvError> {
     let mut ret = SvModuleDeclaration {
         identifier: module_identifier(m.clone(), syntax_tree).unwrap(),
         parameters: Vec::new(),
         ports: Vec::new(),
         instances: Vec::new(),
+        typedefs: Vec::new(),
+        functions: Vec::new(),
+        tasks: Vec::new(),
+        signals: Vec::new(),
+        generate_blocks: Vec::new(),
         filepath: String::from(filepath),
         comments: Vec::new(),
+        attributes: Vec::new(),
+        doc_comment: doc_comment(m.clone(), syntax_tree),
+        span: span(m.clone(), syntax_tree),
+        is_ansi: true,
     };
 
+    if let Some(RefNode::ModuleAnsiHeader(x)) = unwrap_node!(m.clone(), ModuleAnsiHeader) {
+        ret.attributes = attributes(&x.nodes.0, syntax_tree);
+    }
+
     let mut prev_port: Option<SvPort> = None;
+    let mut port_attrs: Vec<Vec<SvAttribute>> = Vec::new();
     let mut parent_stack = Vec::new();
     let mut _entering = true;
 
+    // A parameter declared in the body of a module with a parameter port list is a localparam.
+    let body_param_type = match unwrap_node!(m.clone(), ParameterPortList) {
+        Some(_) => SvParamType::LocalParam,
+        None => SvParamType::Parameter,
+    };
+
     for event in m.into_iter().event() {
         let node = match event {
             NodeEvent::Enter(x) => {
@@ -38,103 +68,132 @@ pub fn module_declaration_ansi(
 
         match node {
             RefNode::ParameterPortList(p) => {
-                let mut common_scope_found: bool = false;
-                let mut param_type: RefNode = node;
-
-                for sub_node in p.into_iter().event() {
-                    if _entering {
-                        match sub_node {
-                            NodeEvent::Enter(RefNode::ParameterDeclarationParam(x)) => {
-                                common_scope_found = true;
-                                param_type = RefNode::ParameterDeclarationParam(x);
-                            }
-
-                            NodeEvent::Enter(RefNode::LocalParameterDeclarationParam(x)) => {
-                                common_scope_found = true;
-                                param_type = RefNode::LocalParameterDeclarationParam(x);
-                            }
-
-                            NodeEvent::Enter(RefNode::ParameterPortDeclarationParamList(x)) => {
-                                common_scope_found = true;
-                                param_type = RefNode::ParameterPortDeclarationParamList(x);
-                            }
+                if _entering && !parent_stack.iter().any(|x| x == "ClassDeclaration") {
+                    parameter_port_list(p, syntax_tree, &mut ret.parameters)?;
+                }
+            }
 
-                            NodeEvent::Leave(RefNode::LocalParameterDeclarationParam(_))
-                            | NodeEvent::Leave(RefNode::ParameterDeclarationParam(_))
-                            | NodeEvent::Leave(RefNode::ParameterPortDeclarationParamList(_)) => {
-                                common_scope_found = false;
-                            }
+            RefNode::ParameterDeclaration(_) | RefNode::LocalParameterDeclaration(_) => {
+                if _entering
+                    && !parent_stack
+                        .iter()
+                        .any(|x| x == "ParameterPortList" || x == "ClassDeclaration")
+                {
+                    let common_data = unwrap_node!(node.clone(), DataType, DataTypeOrImplicit);
+                    let param_type = match node {
+                        RefNode::LocalParameterDeclaration(_) => SvParamType::LocalParam,
+                        _ => body_param_type.clone(),
+                    };
 
-                            NodeEvent::Enter(RefNode::ListOfParamAssignments(a)) => {
-                                if !common_scope_found {
-                                    let param_type = SvParamType::Parameter;
-
-                                    for param in a {
-                                        match param {
-                                            RefNode::ParamAssignment(x) => {
-                                                ret.parameters.push(
-                                                    port_parameter_declaration_ansi(
-                                                        x,
-                                                        syntax_tree,
-                                                        None,
-                                                        &param_type,
-                                                    ),
-                                                );
-                                            }
-                                            _ => (),
-                                        }
-                                    }
-                                } else {
-                                    let common_data = unwrap_node!(
-                                        param_type.clone(),
-                                        DataType,
-                                        DataTypeOrImplicit
-                                    );
-
-                                    let param_type = match param_type {
-                                        RefNode::LocalParameterDeclarationParam(_) => {
-                                            SvParamType::LocalParam
-                                        }
-                                        RefNode::ParameterDeclarationParam(_)
-                                        | RefNode::ParameterPortDeclarationParamList(_) => {
-                                            SvParamType::Parameter
-                                        }
-                                        _ => unreachable!(),
-                                    };
-
-                                    for param in a {
-                                        match param {
-                                            RefNode::ParamAssignment(x) => ret.parameters.push(
-                                                port_parameter_declaration_ansi(
-                                                    x,
-                                                    syntax_tree,
-                                                    common_data.clone(),
-                                                    &param_type,
-                                                ),
-                                            ),
-                                            _ => (),
-                                        }
-                                    }
+                    if let Some(a) = unwrap_node!(node.clone(), ListOfParamAssignments) {
+                        for param in a {
+                            match param {
+                                RefNode::ParamAssignment(x) => {
+                                    ret.parameters.push(port_parameter_declaration_ansi(
+                                        x,
+                                        syntax_tree,
+                                        common_data.clone(),
+                                        &param_type,
+                                        &ret.parameters,
+                                    )?);
                                 }
+                                _ => (),
                             }
-
-                            _ => (),
                         }
                     }
                 }
             }
 
+            RefNode::ListOfPortDeclarations(p) => {
+                if _entering {
+                    port_attrs = port_attributes_ansi(p, syntax_tree);
+                }
+            }
+
             RefNode::AnsiPortDeclaration(p) => {
                 if _entering {
-                    let parsed_port: SvPort = port_declaration_ansi(p, syntax_tree, &prev_port);
+                    let mut parsed_port: SvPort = port_declaration_ansi(p, syntax_tree, &prev_port);
+                    parsed_port.attributes =
+                        port_attrs.get(ret.ports.len()).cloned().unwrap_or_default();
                     ret.ports.push(parsed_port.clone());
                     prev_port = Some(parsed_port);
                 }
             }
 
-            RefNode::ModuleInstantiation(p) => {
+            RefNode::TypeDeclaration(sv_parser::TypeDeclaration::DataType(p)) => {
                 if _entering {
-                    let parsed_instance: SvInstance = module_instance(p, syntax_tree);
+                    ret.typedefs
+                        .push(typedef_declaration(p, syntax_tree, &ret.parameters));
+                }
+            }
+
+            // Methods of a class are not functions or tasks of the module
+            RefNode::FunctionDeclaration(p) => {
+                if _entering && !parent_stack.iter().any(|x| x == "ClassDeclaration") {
+                    ret.functions.push(function_declaration(p, syntax_tree));
+                }
+            }
+
+            RefNode::TaskDeclaration(p) => {
+                if _entering && !parent_stack.iter().any(|x| x == "ClassDeclaration") {
+                    ret.tasks.push(task_declaration(p, syntax_tree));
+                }
+            }
+
+            // Variables local to a subroutine, a block or a class are not signals of the module, and
+            // those of generate blocks are listed in the blocks
+            RefNode::NetDeclaration(sv_parser::NetDeclaration::NetType(_))
+            | RefNode::DataDeclaration(sv_parser::DataDeclaration::Variable(_)) => {
+                if _entering
+                    && !parent_stack
+                        .iter()
+                        .any(|x| x == "BlockItemDeclaration" || x == "ClassDeclaration")
+                    && !generate_nested(&parent_stack)
+                {
+                    let signals: Vec<SvSignal> = match node {
+                        RefNode::NetDeclaration(sv_parser::NetDeclaration::NetType(p)) => {
+                            net_declaration(p, syntax_tree)
+                        }
+                        RefNode::DataDeclaration(sv_parser::DataDeclaration::Variable(p)) => {
+                            variable_declaration(p, syntax_tree)
+                        }
+                        _ => unreachable!(),
+                    };
+
+                    // A port which is declared again in the body is not a separate signal
+                    for signal in signals {
+                        if ret.port_by_name(&signal.identifier).is_none() {
+                            ret.signals.push(signal);
+                        }
+                    }
+                }
+            }
+
+            // Nested generate blocks are extracted along with the outermost one
+            RefNode::LoopGenerateConstruct(p) => {
+                if _entering && generate_outermost(&parent_stack) {
+                    ret.generate_blocks.push(loop_generate_construct(
+                        p,
+                        syntax_tree,
+                        &ret.parameters,
+                    ));
+                }
+            }
+
+            RefNode::IfGenerateConstruct(p) => {
+                if _entering && generate_outermost(&parent_stack) {
+                    ret.generate_blocks.extend(if_generate_construct(
+                        p,
+                        syntax_tree,
+                        &ret.parameters,
+                    ));
+                }
+            }
+
+            RefNode::ModuleInstantiation(p) => {
+                if _entering && !generate_nested(&parent_stack) {
+                    let parsed_instance: SvInstance =
+                        module_instance(p, syntax_tree, &ret.parameters);
                     ret.instances.push(parsed_instance);
                 }
             }
@@ -148,21 +207,62 @@ pub fn module_declaration_ansi(
             _ => (),
         }
     }
-    ret
+    Ok(ret)
 }
 
+/// Extracts a non-ANSI module, e.g. `module top (clk); input logic clk; ... endmodule`, whose
+/// ports are declared in its body. Only the identifier and the span are extracted so far, which is
+/// why `parse_sv_file` still reports non-ANSI modules as unsupported.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_module::{module_declaration_ansi, module_declaration_nonansi};
+/// # use std::collections::HashMap;
+/// # use std::path::PathBuf;
+/// let path = "tests/systemverilog/module_styles.sv";
+/// let (syntax_tree, _) =
+///     sv_parser::parse_sv(path, &HashMap::new(), &[] as &[PathBuf], false, false).unwrap();
+/// let mut modules = Vec::new();
+///
+/// for node in &syntax_tree {
+///     match node {
+///         sv_parser::RefNode::ModuleDeclarationAnsi(_) => {
+///             modules.push(module_declaration_ansi(node, &syntax_tree, path).unwrap());
+///         }
+///         sv_parser::RefNode::ModuleDeclarationNonansi(_) => {
+///             modules.push(module_declaration_nonansi(node, &syntax_tree, path));
+///         }
+///         _ => (),
+///     }
+/// }
+///
+/// assert_eq!(modules[0].identifier, "styles_ansi");
+/// assert!(modules[0].is_ansi);
+/// assert_eq!(modules[1].identifier, "styles_nonansi");
+/// assert!(!modules[1].is_ansi);
+/// ```
 pub fn module_declaration_nonansi(
     _m: RefNode,
     _syntax_tree: &SyntaxTree,
     _filepath: &str,
 ) -> SvModuleDeclaration {
     let ret = SvModuleDeclaration {
-        identifier: module_identifier(_m, _syntax_tree).unwrap(),
+        identifier: module_identifier(_m.clone(), _syntax_tree).unwrap(),
         parameters: Vec::new(),
         ports: Vec::new(),
         instances: Vec::new(),
+        typedefs: Vec::new(),
+        functions: Vec::new(),
+        tasks: Vec::new(),
+        signals: Vec::new(),
+        generate_blocks: Vec::new(),
         filepath: String::from(_filepath),
         comments: Vec::new(),
+        attributes: Vec::new(),
+        doc_comment: None,
+        span: span(_m, _syntax_tree),
+        is_ansi: false,
     };
     // TODO
     ret
@@ -176,6 +276,22 @@ fn module_identifier(node: RefNode, syntax_tree: &SyntaxTree) -> Option<String>
     }
 }
 
+// Whether a node is within a generate loop or conditional, which extracts its own items
+fn generate_nested(parent_nodes: &[String]) -> bool {
+    parent_nodes
+        .iter()
+        .any(|x| x == "LoopGenerateConstruct" || x == "IfGenerateConstruct")
+}
+
+// The last node on the stack is the generate construct itself
+fn generate_outermost(parent_nodes: &[String]) -> bool {
+    !parent_nodes
+        .iter()
+        .rev()
+        .skip(1)
+        .any(|x| x == "LoopGenerateConstruct" || x == "IfGenerateConstruct")
+}
+
 fn if_module_comment(parent_nodes: Vec<String>) -> bool {
     parent_nodes
         .iter()
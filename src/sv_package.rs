@@ -1,22 +1,44 @@
 use crate::structures::{SvPackageDeclaration, SvParamType};
-use crate::sv_misc::identifier;
+use crate::sv_error::SvError;
+use crate::sv_misc::{identifier, span};
 use crate::sv_port::port_parameter_declaration_ansi;
-use sv_parser::{unwrap_node, RefNode, SyntaxTree};
+use crate::sv_subroutine::{function_declaration, task_declaration};
+use crate::sv_typedef::typedef_declaration;
+use sv_parser::{unwrap_node, NodeEvent, RefNode, SyntaxTree};
 
 pub fn package_declaration(
     m: RefNode,
     syntax_tree: &SyntaxTree,
     filepath: &str,
-) -> SvPackageDeclaration {
+) -> Result<SvPackageDeclaration, SvError> {
     let mut ret = SvPackageDeclaration {
         identifier: package_identifier(m.clone(), syntax_tree).unwrap(),
         parameters: Vec::new(),
+        typedefs: Vec::new(),
+        functions: Vec::new(),
+        tasks: Vec::new(),
         filepath: String::from(filepath),
+        span: span(m.clone(), syntax_tree),
     };
 
-    for node in m {
+    let mut parent_stack = Vec::new();
+
+    for event in m.into_iter().event() {
+        let node = match event {
+            NodeEvent::Enter(x) => {
+                parent_stack.push(x.to_string());
+                x
+            }
+            NodeEvent::Leave(_) => {
+                parent_stack.pop();
+                continue;
+            }
+        };
+
         match node {
-            RefNode::ParameterDeclarationParam(_) | RefNode::LocalParameterDeclarationParam(_) => {
+            RefNode::ParameterDeclarationParam(_) | RefNode::LocalParameterDeclarationParam(_)
+                if !parent_stack.iter().any(|x| x == "ClassDeclaration") =>
+            {
                 let common_data = unwrap_node!(node.clone(), DataType, DataTypeOrImplicit);
                 let a = unwrap_node!(node.clone(), ListOfParamAssignments);
 
@@ -28,18 +50,37 @@ pub fn package_declaration(
                                 syntax_tree,
                                 common_data.clone(),
                                 &SvParamType::LocalParam,
-                            ));
+                                &ret.parameters,
+                            )?);
                         }
                         _ => (),
                     }
                 }
             }
 
+            RefNode::TypeDeclaration(sv_parser::TypeDeclaration::DataType(x)) => {
+                ret.typedefs
+                    .push(typedef_declaration(x, syntax_tree, &ret.parameters));
+            }
+
+            // Methods of a class are not functions or tasks of the package
+            RefNode::FunctionDeclaration(x) => {
+                if !parent_stack.iter().any(|x| x == "ClassDeclaration") {
+                    ret.functions.push(function_declaration(x, syntax_tree));
+                }
+            }
+
+            RefNode::TaskDeclaration(x) => {
+                if !parent_stack.iter().any(|x| x == "ClassDeclaration") {
+                    ret.tasks.push(task_declaration(x, syntax_tree));
+                }
+            }
+
             _ => (),
         }
     }
 
-    ret
+    Ok(ret)
 }
 
 fn package_identifier(node: RefNode, syntax_tree: &SyntaxTree) -> Option<String> {
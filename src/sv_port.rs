@@ -1,9 +1,15 @@
 use crate::structures::{
-    SvDataKind, SvDataType, SvNetType, SvPackedDimension, SvParamType, SvParameter, SvPort,
-    SvPortDirection, SvSignedness, SvUnpackedDimension,
+    SvAttribute, SvDataKind, SvDataType, SvNetType, SvPackedDimension, SvParamType, SvParameter,
+    SvPort, SvPortDirection, SvSignedness, SvUnpackedDimension,
 };
-use crate::sv_misc::{get_comment, get_string, identifier, keyword, symbol};
-use sv_parser::{unwrap_node, RefNode, SyntaxTree};
+use crate::sv_const_eval::{const_cast, const_param_expression};
+use crate::sv_error::SvError;
+use crate::sv_misc::{
+    attributes, doc_comment, get_comment, get_string, identifier, keyword, span, symbol,
+    unsized_decimal,
+};
+use crate::sv_primlit_integral::{usize_to_primlit, SvPrimaryLiteralIntegral};
+use sv_parser::{unwrap_node, NodeEvent, RefNode, SyntaxTree};
 
 pub fn port_declaration_ansi(
     p: &sv_parser::AnsiPortDeclaration,
@@ -13,7 +19,9 @@ pub fn port_declaration_ansi(
     let inherit = port_check_inheritance_ansi(p, prev_port);
     let ret: SvPort;
 
-    if inherit == false {
+    if let Some(RefNode::InterfacePortHeader(x)) = unwrap_node!(p, InterfacePortHeader) {
+        ret = port_interface_ansi(p, x, syntax_tree);
+    } else if inherit == false {
         ret = SvPort {
             identifier: port_identifier(p, syntax_tree),
             direction: port_direction_ansi(p, prev_port),
@@ -27,7 +35,16 @@ pub fn port_declaration_ansi(
                 RefNode::AnsiPortDeclaration(p),
                 syntax_tree,
             ),
+            width: port_width_ansi(
+                &port_datatype_ansi(p, syntax_tree),
+                &port_packeddim_ansi(RefNode::AnsiPortDeclaration(p), syntax_tree),
+            ),
+            interface_name: None,
+            modport_name: None,
             comment: get_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+            attributes: Vec::new(),
+            doc_comment: doc_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+            span: span(RefNode::AnsiPortDeclaration(p), syntax_tree),
         }
     } else {
         let prev_port = prev_port.clone().unwrap();
@@ -44,19 +61,174 @@ pub fn port_declaration_ansi(
                 RefNode::AnsiPortDeclaration(p),
                 syntax_tree,
             ),
+            width: prev_port.width,
+            interface_name: prev_port.interface_name,
+            modport_name: prev_port.modport_name,
             comment: get_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+            attributes: Vec::new(),
+            doc_comment: doc_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+            span: span(RefNode::AnsiPortDeclaration(p), syntax_tree),
         };
     }
 
     return ret;
 }
 
+// An interface port, e.g. `bus_if.master bus`, has neither a direction nor a data type, only the
+// interface and the modport through which the module accesses it. The interface is None for a
+// generic interface port, i.e. `interface.master bus`.
+fn port_interface_ansi(
+    p: &sv_parser::AnsiPortDeclaration,
+    header: &sv_parser::InterfacePortHeader,
+    syntax_tree: &SyntaxTree,
+) -> SvPort {
+    let (interface_name, modport) = match header {
+        sv_parser::InterfacePortHeader::Identifier(x) => {
+            let (id, modport) = &x.nodes;
+            (
+                identifier(RefNode::InterfaceIdentifier(id), syntax_tree),
+                modport,
+            )
+        }
+        sv_parser::InterfacePortHeader::Interface(x) => (None, &x.nodes.1),
+    };
+
+    SvPort {
+        identifier: port_identifier(p, syntax_tree),
+        direction: SvPortDirection::IMPLICIT,
+        datakind: SvDataKind::Interface,
+        datatype: SvDataType::IMPLICIT,
+        classid: None,
+        nettype: None,
+        signedness: None,
+        packed_dimensions: Vec::new(),
+        unpacked_dimensions: port_unpackeddim_ansi(RefNode::AnsiPortDeclaration(p), syntax_tree),
+        width: None,
+        interface_name,
+        modport_name: match modport {
+            Some((_, x)) => identifier(RefNode::ModportIdentifier(x), syntax_tree),
+            None => None,
+        },
+        comment: get_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+        attributes: Vec::new(),
+        doc_comment: doc_comment(RefNode::AnsiPortDeclaration(p), syntax_tree),
+        span: span(RefNode::AnsiPortDeclaration(p), syntax_tree),
+    }
+}
+
+// The attributes of each port, in order of declaration. They precede the port declarations, so
+// they are not part of them.
+pub fn port_attributes_ansi(
+    p: &sv_parser::ListOfPortDeclarations,
+    syntax_tree: &SyntaxTree,
+) -> Vec<Vec<SvAttribute>> {
+    let (_, ports, _) = &p.nodes.0.nodes;
+
+    match ports {
+        Some(x) => x
+            .contents()
+            .into_iter()
+            .map(|(attrs, _)| attributes(attrs, syntax_tree))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Extracts the parameters of a parameter port list, e.g. `#(parameter int WIDTH = 8)`, of a
+/// module or a class, appending them to `parameters`. Type parameters are not extracted.
+pub fn parameter_port_list(
+    p: &sv_parser::ParameterPortList,
+    syntax_tree: &SyntaxTree,
+    parameters: &mut Vec<SvParameter>,
+) -> Result<(), SvError> {
+    let mut common_scope_found: bool = false;
+    let mut param_type: RefNode = RefNode::ParameterPortList(p);
+
+    for sub_node in p.into_iter().event() {
+        match sub_node {
+            NodeEvent::Enter(RefNode::ParameterDeclarationParam(x)) => {
+                common_scope_found = true;
+                param_type = RefNode::ParameterDeclarationParam(x);
+            }
+
+            NodeEvent::Enter(RefNode::LocalParameterDeclarationParam(x)) => {
+                common_scope_found = true;
+                param_type = RefNode::LocalParameterDeclarationParam(x);
+            }
+
+            NodeEvent::Enter(RefNode::ParameterPortDeclarationParamList(x)) => {
+                common_scope_found = true;
+                param_type = RefNode::ParameterPortDeclarationParamList(x);
+            }
+
+            NodeEvent::Leave(RefNode::LocalParameterDeclarationParam(_))
+            | NodeEvent::Leave(RefNode::ParameterDeclarationParam(_))
+            | NodeEvent::Leave(RefNode::ParameterPortDeclarationParamList(_)) => {
+                common_scope_found = false;
+            }
+
+            NodeEvent::Enter(RefNode::ListOfParamAssignments(a)) => {
+                if !common_scope_found {
+                    let param_type = SvParamType::Parameter;
+
+                    for param in a {
+                        match param {
+                            RefNode::ParamAssignment(x) => {
+                                let parameter = port_parameter_declaration_ansi(
+                                    x,
+                                    syntax_tree,
+                                    None,
+                                    &param_type,
+                                    parameters,
+                                )?;
+                                parameters.push(parameter);
+                            }
+                            _ => (),
+                        }
+                    }
+                } else {
+                    let common_data =
+                        unwrap_node!(param_type.clone(), DataType, DataTypeOrImplicit);
+
+                    let param_type = match param_type {
+                        RefNode::LocalParameterDeclarationParam(_) => SvParamType::LocalParam,
+                        RefNode::ParameterDeclarationParam(_)
+                        | RefNode::ParameterPortDeclarationParamList(_) => SvParamType::Parameter,
+                        _ => unreachable!(),
+                    };
+
+                    for param in a {
+                        match param {
+                            RefNode::ParamAssignment(x) => {
+                                let parameter = port_parameter_declaration_ansi(
+                                    x,
+                                    syntax_tree,
+                                    common_data.clone(),
+                                    &param_type,
+                                    parameters,
+                                )?;
+                                parameters.push(parameter);
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
 pub fn port_parameter_declaration_ansi(
     p: &sv_parser::ParamAssignment,
     syntax_tree: &SyntaxTree,
     common_data: Option<RefNode>,
     param_type: &SvParamType,
-) -> SvParameter {
+    scope: &[SvParameter],
+) -> Result<SvParameter, SvError> {
     let found_assignment = port_parameter_check_default_ansi(p);
     let (param_datatype, param_explicit_datatype) = port_parameter_datatype_ansi(
         common_data.clone(),
@@ -105,8 +277,19 @@ pub fn port_parameter_declaration_ansi(
             found_assignment,
             &port_parameter_value_ansi(p, syntax_tree, found_assignment),
             syntax_tree,
-        ),
+        )?,
         comment: get_comment(RefNode::ParamAssignment(p), syntax_tree),
+        value: port_parameter_resolve_ansi(&param_datatype, p, syntax_tree, scope).and_then(|x| {
+            port_parameter_cast_ansi(
+                x,
+                &param_datatype,
+                param_explicit_datatype,
+                &param_signedness,
+                param_explicit_signedness,
+                &param_packeddim,
+            )
+        }),
+        span: span(RefNode::ParamAssignment(p), syntax_tree),
     };
 
     port_parameter_syntax_ansi(
@@ -115,9 +298,61 @@ pub fn port_parameter_declaration_ansi(
         &ret.packed_dimensions,
         param_type,
         found_assignment,
-    );
+    )?;
 
-    ret
+    Ok(ret)
+}
+
+// Parameters of the same scope that were declared before can be referenced by the expression
+fn port_parameter_resolve_ansi(
+    datatype: &Option<SvDataType>,
+    p: &sv_parser::ParamAssignment,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> Option<SvPrimaryLiteralIntegral> {
+    match datatype {
+        Some(SvDataType::Real)
+        | Some(SvDataType::Shortreal)
+        | Some(SvDataType::Realtime)
+        | Some(SvDataType::String)
+        | Some(SvDataType::Class)
+        | Some(SvDataType::TypeRef)
+        | Some(SvDataType::Unsupported) => None,
+
+        _ => match unwrap_node!(p, ConstantParamExpression) {
+            Some(RefNode::ConstantParamExpression(x)) => {
+                const_param_expression(x, syntax_tree, scope)
+            }
+            _ => None,
+        },
+    }
+}
+
+// The value is assigned to the declared type, e.g. a logic [3:0] parameter keeps the 4 LSBs of
+// 8'hff, while a parameter without a datatype or packed dimensions keeps the width of the value
+// and only takes an explicit signing. The flags are true for a datatype or signedness which is
+// implied by the value.
+fn port_parameter_cast_ansi(
+    value: SvPrimaryLiteralIntegral,
+    datatype: &Option<SvDataType>,
+    datatype_implicit: bool,
+    signedness: &Option<SvSignedness>,
+    signedness_implicit: bool,
+    packed_dimensions: &Vec<SvPackedDimension>,
+) -> Option<SvPrimaryLiteralIntegral> {
+    let width = if datatype_implicit && packed_dimensions.is_empty() {
+        value.size
+    } else {
+        port_width_ansi(datatype.as_ref()?, packed_dimensions)?
+    };
+
+    let signed = if datatype_implicit && signedness_implicit {
+        value.signed
+    } else {
+        signedness == &Some(SvSignedness::Signed)
+    };
+
+    const_cast(value, width, signed)
 }
 
 fn port_parameter_check_default_ansi(node: &sv_parser::ParamAssignment) -> bool {
@@ -134,23 +369,33 @@ fn port_parameter_syntax_ansi(
     packed_dimensions: &Vec<SvPackedDimension>,
     param_type: &SvParamType,
     found_assignment: bool,
-) {
+) -> Result<(), SvError> {
     if !packed_dimensions.is_empty() {
         match datatype {
-            Some(SvDataType::Integer) => {
-                panic!("Cannot combine packed dimensions with an integer!")
-            }
-            Some(SvDataType::Real) => panic!("Cannot combine packed dimensions with a real!"),
-            Some(SvDataType::String) => panic!("Cannot combine packed dimensions with a string!"),
-            Some(SvDataType::Time) => panic!("Cannot combine packed dimensions with time!"),
+            Some(SvDataType::Integer) => Err(SvError::Syntax(String::from(
+                "Cannot combine packed dimensions with an integer!",
+            )))?,
+            Some(SvDataType::Real) => Err(SvError::Syntax(String::from(
+                "Cannot combine packed dimensions with a real!",
+            )))?,
+            Some(SvDataType::String) => Err(SvError::Syntax(String::from(
+                "Cannot combine packed dimensions with a string!",
+            )))?,
+            Some(SvDataType::Time) => Err(SvError::Syntax(String::from(
+                "Cannot combine packed dimensions with time!",
+            )))?,
             _ => (),
         }
     }
 
     match signedness {
         Some(SvSignedness::Signed) | Some(SvSignedness::Unsigned) => match datatype {
-            Some(SvDataType::Real) => panic!("Reals cannot have signedness!"),
-            Some(SvDataType::String) => panic!("Strings cannot have signedness!"),
+            Some(SvDataType::Real) => Err(SvError::Syntax(String::from(
+                "Reals cannot have signedness!",
+            )))?,
+            Some(SvDataType::String) => Err(SvError::Syntax(String::from(
+                "Strings cannot have signedness!",
+            )))?,
             _ => (),
         },
 
@@ -158,9 +403,13 @@ fn port_parameter_syntax_ansi(
     }
 
     match (param_type, found_assignment) {
-        (SvParamType::LocalParam, false) => panic!("Localparams must have a default value!"),
+        (SvParamType::LocalParam, false) => Err(SvError::Syntax(String::from(
+            "Localparams must have a default value!",
+        )))?,
         _ => (),
     }
+
+    Ok(())
 }
 
 fn parameter_resolver_needed_ansi(node: &sv_parser::ParamAssignment) -> bool {
@@ -634,7 +883,7 @@ fn port_parameter_bits_ansi(
     found_assignment: bool,
     expression: &Option<String>,
     syntax_tree: &SyntaxTree,
-) -> Option<u64> {
+) -> Result<Option<u64>, SvError> {
     if !packed_dimensions.is_empty() {
         let mut nu_bits: u64 = 0;
         packed_dimensions.reverse();
@@ -646,21 +895,30 @@ fn port_parameter_bits_ansi(
 
             match (left_num, right_num) {
                 (Ok(left_num), Ok(right_num)) => {
-                    let res: i64 = left_num - right_num;
+                    let res: u64 = left_num.abs_diff(right_num);
+                    let dim_bits = res.checked_add(1).ok_or(SvError::Arithmetic(format!(
+                        "The number of bits of [{}:{}] does not fit in 64 bits!",
+                        left, right
+                    )))?;
+
                     if nu_bits == 0 {
-                        nu_bits = res.abs() as u64 + 1;
+                        nu_bits = dim_bits;
                     } else {
-                        nu_bits = nu_bits * (res.abs() as u64 + 1);
+                        nu_bits = nu_bits
+                            .checked_mul(dim_bits)
+                            .ok_or(SvError::Arithmetic(String::from(
+                            "The number of bits of the packed dimensions does not fit in 64 bits!",
+                        )))?;
                     }
                 }
 
-                _ => return Some(404), // TODO
+                _ => return Ok(Some(404)), // TODO
             }
         }
 
-        Some(nu_bits)
+        Ok(Some(nu_bits))
     } else {
-        match datatype {
+        Ok(match datatype {
             Some(SvDataType::Class) => None,
 
             Some(SvDataType::Bit) => Some(1),
@@ -703,12 +961,14 @@ fn port_parameter_bits_ansi(
 
                         match fixed_size {
                             Some(RefNode::Size(_)) => {
-                                let ret: u64;
-                                ret = get_string(fixed_size.clone().unwrap(), syntax_tree)
-                                    .unwrap()
-                                    .as_str()
-                                    .parse()
-                                    .unwrap();
+                                let size =
+                                    get_string(fixed_size.clone().unwrap(), syntax_tree).unwrap();
+                                let ret: u64 = size.as_str().parse().map_err(|_| {
+                                    SvError::Literal(format!(
+                                        "The size {} does not fit in 64 bits!",
+                                        size
+                                    ))
+                                })?;
                                 Some(ret)
                             }
                             Some(RefNode::UnbasedUnsizedLiteral(_)) => Some(1),
@@ -727,7 +987,7 @@ fn port_parameter_bits_ansi(
             None => None,
 
             _ => unreachable!(),
-        }
+        })
     }
 }
 
@@ -752,8 +1012,9 @@ fn port_direction_ansi(
         }
         Some(RefNode::PortDirection(sv_parser::PortDirection::Ref(_))) => SvPortDirection::Ref,
         _ => match prev_port {
-            Some(_) => prev_port.clone().unwrap().direction,
-            None => SvPortDirection::Inout,
+            // An interface port has no direction to pass on
+            Some(x) if x.datakind != SvDataKind::Interface => x.direction.clone(),
+            _ => SvPortDirection::Inout,
         },
     }
 }
@@ -902,7 +1163,43 @@ fn port_signedness_ansi(
     }
 }
 
-fn port_packeddim_ansi(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvPackedDimension> {
+// The width is None unless every bound of the packed dimensions is an integer literal
+pub fn port_width_ansi(
+    datatype: &SvDataType,
+    packed_dimensions: &Vec<SvPackedDimension>,
+) -> Option<usize> {
+    let base: usize = match datatype {
+        SvDataType::Logic | SvDataType::Reg | SvDataType::Bit | SvDataType::IMPLICIT => 1,
+        SvDataType::Byte => 8,
+        SvDataType::Shortint => 16,
+        SvDataType::Int | SvDataType::Integer => 32,
+        SvDataType::Longint | SvDataType::Time => 64,
+        _ => return None,
+    };
+
+    let mut width: SvPrimaryLiteralIntegral = usize_to_primlit(base);
+
+    for (left, right) in packed_dimensions {
+        let left = unsized_decimal(left)?;
+        let right = unsized_decimal(right)?;
+
+        let mut range = left.sub_primlit(right.clone());
+        if range.is_negative() {
+            range = right.sub_primlit(left);
+        }
+
+        width = width.mult(range + 1);
+    }
+
+    // Widths which do not fit in a single element are not representable as a usize
+    if width.data_01[1..].iter().all(|x| *x == 0) {
+        Some(width.data_01[0])
+    } else {
+        None
+    }
+}
+
+pub fn port_packeddim_ansi(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvPackedDimension> {
     let mut ret: Vec<SvPackedDimension> = Vec::new();
 
     for node in m {
@@ -931,7 +1228,7 @@ fn port_packeddim_ansi(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvPackedDime
     ret
 }
 
-fn port_unpackeddim_ansi(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvUnpackedDimension> {
+pub fn port_unpackeddim_ansi(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvUnpackedDimension> {
     let mut ret: Vec<SvUnpackedDimension> = Vec::new();
 
     for node in m {
@@ -995,7 +1292,8 @@ fn port_check_inheritance_ansi(
         NetType,
         VarDataType,
         PortDirection,
-        PackedDimension
+        PackedDimension,
+        InterfacePortHeader
     );
 
     match prev_port {
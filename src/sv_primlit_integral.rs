@@ -1,13 +1,249 @@
+use crate::sv_error::PrimlitError;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{IntoPyDict, PyBytes, PyLong};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
-use std::ops::{Add, Mul, Neg, Shl, Shr};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// The number of bits of unsized integer literals, i.e. the width of an integer.
+pub const DEFAULT_UNSIZED_WIDTH: usize = 32;
+
+/// An integral value, e.g. the value of a parameter.
+///
+/// Args:
+///    data_01 (list[int]): The 0/1 bits of the value, least significant element first.
+///    data_xz (list[int] | None): The bits of the value that are X or Z, or `None` for a 2-state value.
+///    size (int): The number of bits of the value.
+///    signed (bool): Whether the value is signed.
+///
+/// `SvPrimaryLiteralIntegral(size, signed)` creates a 2-state value of `size` bits that are all 0.
+/// `SvPrimaryLiteralIntegral.from_binary_str("10xz")` creates a value from its bits, and the
+/// functions `logic_0()`, `logic_1()` and `logic_x()` return 4-state 1-bit values, while `bit_0()`
+/// and `bit_1()` return 2-state 1-bit values.
+///
+/// The arithmetic, bitwise and shift operators follow systemverilog. The comparison operators
+/// return `None` instead of a bool when the systemverilog result is X, e.g. when an operand
+/// contains X or Z bits. Like an `if` in systemverilog, such a result counts as false.
+///
+/// A value is serialized as its `size`, `signed`, `data_01` and `data_xz`, in that order, and
+/// deserializing a value that does not pass `validate` fails.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    into = "SerializedPrimaryLiteralIntegral",
+    try_from = "SerializedPrimaryLiteralIntegral"
+)]
+#[pyclass]
 pub struct SvPrimaryLiteralIntegral {
+    #[pyo3(get, set)]
     pub data_01: Vec<usize>,
+    #[pyo3(get, set)]
     pub data_xz: Option<Vec<usize>>,
+    #[pyo3(get, set)]
     pub size: usize,
+    #[pyo3(get, set)]
     pub signed: bool,
 }
+// The serialized form of an integral primary literal, which is validated before it becomes one
+#[derive(Serialize, Deserialize)]
+struct SerializedPrimaryLiteralIntegral {
+    size: usize,
+    signed: bool,
+    data_01: Vec<usize>,
+    #[serde(default)]
+    data_xz: Option<Vec<usize>>,
+}
+
+impl From<SvPrimaryLiteralIntegral> for SerializedPrimaryLiteralIntegral {
+    fn from(value: SvPrimaryLiteralIntegral) -> Self {
+        SerializedPrimaryLiteralIntegral {
+            size: value.size,
+            signed: value.signed,
+            data_01: value.data_01,
+            data_xz: value.data_xz,
+        }
+    }
+}
+
+impl TryFrom<SerializedPrimaryLiteralIntegral> for SvPrimaryLiteralIntegral {
+    type Error = String;
+
+    fn try_from(value: SerializedPrimaryLiteralIntegral) -> Result<Self, Self::Error> {
+        let ret = SvPrimaryLiteralIntegral {
+            data_01: value.data_01,
+            data_xz: value.data_xz,
+            size: value.size,
+            signed: value.signed,
+        };
+
+        ret.validate()?;
+
+        Ok(ret)
+    }
+}
+
+#[pymethods]
+impl SvPrimaryLiteralIntegral {
+    #[new]
+    #[pyo3(signature = (size = 1, signed = false))]
+    fn py_new(size: usize, signed: bool) -> Self {
+        SvPrimaryLiteralIntegral::new(size, signed)
+    }
+    /// Creates a value from one character of 0, 1, x/X or z/Z for every bit, e.g. `"10xz"`, from
+    /// the MSB to the LSB. The value is only a 4-state value if it contains X/Z(s).
+    ///
+    /// Raises a `ValueError` if the string is empty or contains any other character.
+    #[staticmethod]
+    #[pyo3(signature = (bits, signed = false))]
+    fn from_binary_str(bits: &str, signed: bool) -> PyResult<SvPrimaryLiteralIntegral> {
+        let bits: Vec<char> = bits.chars().collect();
+
+        SvPrimaryLiteralIntegral::from_bit_vector(&bits, signed).map_err(PyValueError::new_err)
+    }
+    /// Creates a 2-state value of `width` bits from a Python int of any magnitude. A negative int
+    /// is stored as its two's complement, which requires `signed`.
+    ///
+    /// Raises a `ValueError` if the int does not fit in `width` bits.
+    #[staticmethod]
+    #[pyo3(signature = (value, width, signed = false))]
+    fn from_int(value: &PyLong, width: usize, signed: bool) -> PyResult<SvPrimaryLiteralIntegral> {
+        let py = value.py();
+        let negative: bool = value.compare(0)? == Ordering::Less;
+
+        // The bits of the magnitude, or of the one's complement of a negative value, without its sign
+        let magnitude: &PyAny = if negative {
+            value.call_method0("__invert__")?
+        } else {
+            value
+        };
+        let bits: usize = magnitude.call_method0("bit_length")?.extract()?;
+
+        let fits: bool = if signed {
+            bits < width
+        } else {
+            !negative && bits <= width
+        };
+
+        if !fits {
+            return Err(PyValueError::new_err(format!(
+                "{} does not fit in {} {} bit(s).",
+                value,
+                width,
+                if signed { "signed" } else { "unsigned" }
+            )));
+        }
+
+        let bytes: &PyBytes = value
+            .call_method(
+                "to_bytes",
+                ((width + 7) / 8, "little"),
+                Some([("signed", negative)].into_py_dict(py)),
+            )?
+            .downcast()?;
+
+        let mut ret = SvPrimaryLiteralIntegral::new(width, signed);
+        let elmnt_bytes: usize = std::mem::size_of::<usize>();
+
+        for (x, byte) in bytes.as_bytes().iter().enumerate() {
+            ret.data_01[x / elmnt_bytes] |= (*byte as usize) << (8 * (x % elmnt_bytes));
+        }
+
+        // The two's complement of a negative value also sets the bits beyond the width
+        let used_bits: usize = width % usize::BITS as usize;
+        if used_bits != 0 {
+            let last = ret.data_01.len() - 1;
+            ret.data_01[last] &= (1 << used_bits) - 1;
+        }
+
+        Ok(ret)
+    }
+    /// Returns the value as a Python int, which is negative for a negative signed value.
+    ///
+    /// Raises a `ValueError` if the value contains X or Z bits.
+    fn to_int(&self, py: Python) -> PyResult<PyObject> {
+        if self.contains_xz() {
+            return Err(PyValueError::new_err(format!(
+                "Cannot convert {} with X/Z(s) to an int.",
+                self
+            )));
+        }
+
+        let num_bytes: usize = std::cmp::max(1, (self.size + 7) / 8);
+        let mut bytes: Vec<u8> = self.data_01.iter().flat_map(|x| x.to_le_bytes()).collect();
+        bytes.resize(num_bytes, 0);
+
+        // The MSB is extended to the end of its byte, so that Python reads the same two's complement
+        let used_bits: usize = self.size % 8;
+        if self.signed && self.is_set_msb_01() && used_bits != 0 {
+            bytes[num_bytes - 1] |= 0xff << used_bits;
+        }
+
+        let ret = py.get_type::<PyLong>().call_method(
+            "from_bytes",
+            (PyBytes::new(py, &bytes), "little"),
+            Some([("signed", self.signed)].into_py_dict(py)),
+        )?;
+
+        Ok(ret.into())
+    }
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+    fn __add__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.add_primlit(other)
+    }
+    fn __sub__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.sub_primlit(other)
+    }
+    fn __mul__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.mult(other)
+    }
+    fn __lshift__(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        self.lsl(n)
+    }
+    fn __rshift__(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        self.lsr(n)
+    }
+    fn __and__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.and_primlit(other)
+    }
+    fn __or__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.or_primlit(other)
+    }
+    fn __xor__(&self, other: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.xor_primlit(other)
+    }
+    fn __invert__(&self) -> SvPrimaryLiteralIntegral {
+        self.inv()
+    }
+    fn __neg__(&self) -> PyResult<SvPrimaryLiteralIntegral> {
+        if !self.signed {
+            return Err(PrimlitError::ExpectedSigned.into());
+        }
+
+        Ok(-self.clone())
+    }
+    fn __richcmp__(&self, other: SvPrimaryLiteralIntegral, op: CompareOp) -> Option<bool> {
+        let ret = match op {
+            CompareOp::Lt => self.lt(other),
+            CompareOp::Le => self.le(other),
+            CompareOp::Gt => self.gt(other),
+            CompareOp::Ge => self.ge(other),
+            CompareOp::Eq => self.logical_eq(other),
+            CompareOp::Ne => self.logical_eq(other).inv(),
+        };
+
+        if ret.contains_xz() {
+            None
+        } else {
+            Some(ret.data_01[0] == 1)
+        }
+    }
+}
 
 /// The following functions should be replaced by the build in methods once they become stable.
 /// All the test cases were created with usize::BITS = 64 although all the methods support any usize::BITS
@@ -41,28 +277,142 @@ impl SvPrimaryLiteralIntegral {
         }
     }
 
-    /// Accepts two integral primary literals and ensures that both data_01 vector dimensions (i.e nu of elements) are matched.
-    pub fn _primlit_vec_elmnt_match(&mut self, right_nu: &mut SvPrimaryLiteralIntegral) {
-        let left_size = self.data_01.len();
-        let right_size = right_nu.data_01.len();
+    /** Unsigned subtraction between two integral primary literals.
+    Both data_01 vector dimensions (i.e nu of elements) are matched and the result wraps around within them.
+    It can be used for "signed" and "unsigned" values, and therefore the final number of bits is not derived within the function.
+    Instead it must be explicitly implemented according the context that the function is used. */
+    pub fn _unsigned_primlit_sub(&mut self, mut right_nu: SvPrimaryLiteralIntegral) {
+        self._primlit_vec_elmnt_match(&mut right_nu);
 
-        if left_size > right_size {
-            let diff: usize = left_size - right_size;
+        let mut borrow_flag: bool = false;
 
-            for _x in 0..diff {
-                right_nu.data_01.push(0);
-                if right_nu.is_4state() {
-                    right_nu.data_xz.as_mut().unwrap().push(0);
-                }
+        for x in 0..self.data_01.len() {
+            let (diff, borrow_a) = self.data_01[x].overflowing_sub(right_nu.data_01[x]);
+            let (diff, borrow_b) = diff.overflowing_sub(borrow_flag as usize);
+
+            self.data_01[x] = diff;
+            borrow_flag = borrow_a || borrow_b;
+        }
+    }
+
+    /** Unsigned long division between two 2-state integral primary literals, using a shift-subtract loop.
+    Returns the quotient, which has the number of bits of the dividend, and the remainder, which has the number of bits of the divisor.
+    Both arguments are treated as unsigned and the divisor must not be zero. */
+    pub fn _unsigned_primlit_divmod(
+        &self,
+        right_nu: SvPrimaryLiteralIntegral,
+    ) -> (SvPrimaryLiteralIntegral, SvPrimaryLiteralIntegral) {
+        let bits = usize::BITS as usize;
+
+        let mut divisor = right_nu.clone();
+        divisor.data_xz = None;
+        divisor.signed = false;
+
+        let mut quotient = SvPrimaryLiteralIntegral {
+            data_01: vec![0; self.data_01.len()],
+            data_xz: None,
+            size: self.size,
+            signed: false,
+        };
+
+        // The remainder is always less than the divisor, so one extra bit is sufficient for the shift.
+        let remainder_num_bits = divisor.size + 1;
+        let mut remainder = SvPrimaryLiteralIntegral {
+            data_01: vec![0; (remainder_num_bits + bits - 1) / bits],
+            data_xz: None,
+            size: remainder_num_bits,
+            signed: false,
+        };
+
+        for x in (0..self.size).rev() {
+            let mut carry: usize = (self.data_01[x / bits] >> (x % bits)) & 1;
+
+            for y in 0..remainder.data_01.len() {
+                let msb: usize = remainder.data_01[y] >> (bits - 1);
+                remainder.data_01[y] = (remainder.data_01[y] << 1) | carry;
+                carry = msb;
             }
-        } else if left_size < right_size {
-            let diff: usize = right_size - left_size;
 
-            for _x in 0..diff {
-                self.data_01.push(0);
-                if self.is_4state() {
-                    self.data_xz.as_mut().unwrap().push(0);
-                }
+            // The MSB of the difference is only set if the divisor is greater than the remainder.
+            let mut diff = remainder.sub_primlit(divisor.clone());
+            if !diff.is_set_msb_01() {
+                diff._truncate(remainder_num_bits);
+                remainder = diff;
+                quotient.data_01[x / bits] |= 1 << (x % bits);
+            }
+        }
+
+        remainder._truncate(divisor.size);
+
+        (quotient, remainder)
+    }
+
+    /** Accepts two integral primary literals and ensures that both data_01 vector dimensions (i.e nu of elements) are matched.
+    If only one of them is a 4-state value, the other one is promoted to a 4-state value without X/Z(s),
+    so that the data_xz vectors of both exist and are matched as well. */
+    /// # Examples
+    ///
+    /// 2-state value with width < usize::BITS and 4-state value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// let mut b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: Some(vec![2, 0]),
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// a._primlit_vec_elmnt_match(&mut b);
+    ///
+    /// assert_eq!(a.data_01, vec![5, 0]);
+    /// assert_eq!(a.data_xz, Some(vec![0, 0]));
+    /// assert_eq!(b.data_01, vec![0, 1]);
+    /// assert_eq!(b.data_xz, Some(vec![2, 0]));
+    /// assert_eq!(a.size, 3);
+    /// ```
+    /// 4-state value with width < usize::BITS and 2-state value with width > 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("4'b1x0z").unwrap();
+    /// let mut b = SvPrimaryLiteralIntegral::new(130, true);
+    ///
+    /// a._primlit_vec_elmnt_match(&mut b);
+    ///
+    /// assert_eq!(a.data_01, vec![9, 0, 0]);
+    /// assert_eq!(a.data_xz, Some(vec![5, 0, 0]));
+    /// assert_eq!(b.data_xz, Some(vec![0, 0, 0]));
+    /// ```
+    /// Two 2-state values remain 2-state values
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::new(70, false);
+    /// let mut b = SvPrimaryLiteralIntegral::new(3, false);
+    ///
+    /// a._primlit_vec_elmnt_match(&mut b);
+    ///
+    /// assert_eq!(b.data_01, vec![0, 0]);
+    /// assert_eq!(b.data_xz, None);
+    /// ```
+    pub fn _primlit_vec_elmnt_match(&mut self, right_nu: &mut SvPrimaryLiteralIntegral) {
+        if self.is_4state() && !right_nu.is_4state() {
+            right_nu.data_xz = Some(vec![0; right_nu.data_01.len()]);
+        } else if !self.is_4state() && right_nu.is_4state() {
+            self.data_xz = Some(vec![0; self.data_01.len()]);
+        }
+
+        let elmnts: usize = std::cmp::max(self.data_01.len(), right_nu.data_01.len());
+
+        for x in [self, right_nu] {
+            x.data_01.resize(elmnts, 0);
+            if let Some(data_xz) = x.data_xz.as_mut() {
+                data_xz.resize(elmnts, 0);
             }
         }
     }
@@ -73,7 +423,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -85,7 +435,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -97,7 +447,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with width > usize::BITS
     ///  ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
@@ -109,7 +459,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Positive value with width > usize::BITS
     ///  ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
@@ -123,16 +473,17 @@ impl SvPrimaryLiteralIntegral {
         let mut zero = bit1b_0();
         zero.signed = true;
 
-        self.lt(zero) == logic1b_1()
+        self._lt(zero) == logic1b_1()
     }
 
     /// Receives an integral primary literal as an argument and deduces whether the stored value is zero or not.
+    /// A value containing X/Z(s) is never zero. Only the bits within the width are considered.
     ///
     /// # Examples
     ///
     /// Zero with width = 1 bit
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0],
     ///     data_xz: None,
@@ -144,7 +495,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Zero with width > usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
     ///     data_xz: None,
@@ -156,7 +507,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Non-Zero with width > usize::BITS
     ///  ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 1],
     ///     data_xz: None,
@@ -166,221 +517,491 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// assert_eq!(a.is_zero(), false);
     /// ```
+    /// 4-state values with and without X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 70,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_zero(), true);
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 32]),
+    ///     size: 70,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(b.is_zero(), false);
+    /// ```
     pub fn is_zero(&self) -> bool {
-        let mut zero = bit1b_0();
-        zero.signed = true;
-
-        self.case_eq(zero) == bit1b_1()
-    }
-
-    /// Deduces whether the primary literal is 4-state or not.
-    pub fn is_4state(&self) -> bool {
-        match self.data_xz.clone() {
-            None => false,
-            Some(_) => true,
-        }
-    }
-
-    /// Receives an integral primary literal as an argument and deduces whether it contains X(s) or Z(s).
-    pub fn contains_xz(&self) -> bool {
-        if !self.is_4state() {
+        if self.contains_xz() {
             return false;
-        } else {
-            for x in self.data_xz.as_ref().unwrap() {
-                if x.leading_zeros() != usize::BITS {
-                    return true;
-                }
-            }
-        }
-
-        false
-    }
-
-    /// Receives an integral primary literal and returns its contents in a 4-state integral primary literal.
-    pub fn to_4state(&self) -> SvPrimaryLiteralIntegral {
-        let mut ret = SvPrimaryLiteralIntegral {
-            data_01: self.data_01.clone(),
-            data_xz: Some(vec![0]),
-            size: self.size,
-            signed: self.signed,
-        };
-
-        if ret.data_01.len() != ret.data_xz.as_ref().unwrap().len() {
-            for _x in 0..(ret.data_01.len() - ret.data_xz.as_ref().unwrap().len()) {
-                let mut new_vec = ret.data_xz.clone().unwrap();
-                new_vec.push(0);
-                ret.data_xz = Some(new_vec);
-            }
-        }
-
-        ret
-    }
-
-    /// Returns whether the MSB of data_01 is high. The size must be correctly specified.
-    pub fn is_set_msb_01(&self) -> bool {
-        let left_leading_zeros: usize =
-            usize::BITS as usize - (self.size - (self.data_01.len() - 1) * usize::BITS as usize);
-
-        if self.data_01[self.data_01.len() - 1].leading_zeros() as usize == left_leading_zeros {
-            true
-        } else {
-            false
         }
-    }
-
-    /// Returns whether the MSB of data_xz is high. The size must be correctly specified.
-    pub fn is_set_msb_xz(&self) -> bool {
-        if self.is_4state() {
-            let left_leading_zeros: usize = usize::BITS as usize
-                - (self.size - (self.data_xz.as_ref().unwrap().len() - 1) * usize::BITS as usize);
 
-            if self.data_xz.as_ref().unwrap()[self.data_xz.as_ref().unwrap().len() - 1]
-                .leading_zeros() as usize
-                == left_leading_zeros
-            {
-                true
-            } else {
-                false
-            }
-        } else {
-            false
-        }
+        let bits = usize::BITS as usize;
+        let elmnts: usize = self.size.div_ceil(bits);
+        let used_bits: usize = self.size % bits;
+
+        self.data_01
+            .iter()
+            .take(elmnts)
+            .enumerate()
+            .all(|(x, data)| {
+                if x == elmnts - 1 && used_bits != 0 {
+                    data & (usize::MAX >> (bits - used_bits)) == 0
+                } else {
+                    *data == 0
+                }
+            })
     }
 
-    /** Accepts two signed integral primary literals and ensures that both are properly sign extended and matched to their data_01 dimensions.
-    The correct final number of bits is set to both arguments. */
+    /** Returns a 2-state integral primary literal of the given width in which all the bits are 0.
+    The number of elements in data_01 always matches the width, so the value passes validate. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Negative value with width = usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
+    /// Value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::new(8, true);
     ///
-    /// let mut b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// a._matched_sign_extend(&mut b);
+    /// assert_eq!(a, exp);
+    /// assert_eq!(a.validate(), Ok(()));
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::new(70, false);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 18446744073709551615],
+    ///     data_01: vec![0, 0],
     ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     size: 70,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(a, exp);
+    /// assert_eq!(a.validate(), Ok(()));
     /// ```
-    /// Negative value with width = 2 * usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
+    pub fn new(size: usize, signed: bool) -> SvPrimaryLiteralIntegral {
+        SvPrimaryLiteralIntegral {
+            data_01: vec![0; SvPrimaryLiteralIntegral::_elmnts(size)],
+            data_xz: None,
+            size,
+            signed,
+        }
+    }
+
+    /** Checks that the integral primary literal is consistent with its width.
+    The number of elements in data_01 (and data_xz if present) must be ceil(size / usize::BITS), with at least one element,
+    and no bits may be set beyond the width.
+    The arithmetic, bitwise, shift, concatenation, comparison and negation operations assert this of their operands in debug builds. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Consistent value
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 3],
     ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
-    /// let mut b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    /// assert_eq!(a.validate(), Ok(()));
+    /// ```
+    /// Too many elements in data_01
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
     ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// a._matched_sign_extend(&mut b);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    /// assert_eq!(
+    ///     a.validate(),
+    ///     Err(String::from("Expected 1 element(s) in data_01 for 64 bit(s) but found 2!"))
+    /// );
+    /// ```
+    /// Bits set beyond the width
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775809, 3],
     ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     a.validate(),
+    ///     Err(String::from("Bits are set beyond the width of 65 bit(s) in data_01!"))
+    /// );
+    /// ```
+    ///
+    /// ## 4-State Primary Literals
+    ///
+    /// Consistent value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.validate(), Ok(()));
+    /// ```
+    /// Too few elements in data_xz
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![1]),
     ///     size: 128,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// assert_eq!(
+    ///     a.validate(),
+    ///     Err(String::from("Expected 2 element(s) in data_xz for 128 bit(s) but found 1!"))
+    /// );
     /// ```
-    /// Negative value with usize::BITS < width < 2 * usize::BITS and positive value with width = usize::BITS
+    /// Bits set beyond the width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![16]),
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// let mut b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    /// assert_eq!(
+    ///     a.validate(),
+    ///     Err(String::from("Bits are set beyond the width of 4 bit(s) in data_xz!"))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        SvPrimaryLiteralIntegral::_validate_data(&self.data_01, self.size, "data_01")?;
+
+        if let Some(data_xz) = &self.data_xz {
+            SvPrimaryLiteralIntegral::_validate_data(data_xz, self.size, "data_xz")?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of usize elements needed to hold the given number of bits, with at least one element.
+    fn _elmnts(size: usize) -> usize {
+        std::cmp::max(1, (size + usize::BITS as usize - 1) / usize::BITS as usize)
+    }
+
+    fn _validate_data(data: &[usize], size: usize, name: &str) -> Result<(), String> {
+        let elmnts = SvPrimaryLiteralIntegral::_elmnts(size);
+
+        if data.len() != elmnts {
+            return Err(format!(
+                "Expected {} element(s) in {} for {} bit(s) but found {}!",
+                elmnts,
+                name,
+                size,
+                data.len()
+            ));
+        }
+
+        let used_bits = size % usize::BITS as usize;
+        let top = data[elmnts - 1];
+
+        if (size == 0 && top != 0) || (used_bits != 0 && top >> used_bits != 0) {
+            return Err(format!(
+                "Bits are set beyond the width of {} bit(s) in {}!",
+                size, name
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Deduces whether the primary literal is 4-state or not.
+    pub fn is_4state(&self) -> bool {
+        match self.data_xz.clone() {
+            None => false,
+            Some(_) => true,
+        }
+    }
+
+    /** Receives an integral primary literal as an argument and deduces whether it contains X(s) or Z(s).
+    Only the bits within the width are considered, any bits set beyond it are ignored. */
+    /// # Examples
+    ///
+    /// Value with X(s)/Z(s) within the width
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 2]),
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
-    /// a._matched_sign_extend(&mut b);
+    /// assert_eq!(a.contains_xz(), true);
+    /// ```
+    /// Value with X(s)/Z(s) only beyond the width
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 9223372036854775808]),
+    ///     size: 66,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.contains_xz(), false);
+    /// ```
+    pub fn contains_xz(&self) -> bool {
+        if !self.is_4state() {
+            return false;
+        }
+
+        let bits = usize::BITS as usize;
+        let elmnts: usize = (self.size + bits - 1) / bits;
+
+        for (x, data) in self
+            .data_xz
+            .as_ref()
+            .unwrap()
+            .iter()
+            .take(elmnts)
+            .enumerate()
+        {
+            let mut data: usize = *data;
+
+            if x == elmnts - 1 && self.size % bits != 0 {
+                data = data & (usize::MAX >> (bits - self.size % bits));
+            }
+
+            if data != 0 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Receives an integral primary literal and returns its contents in a 4-state integral primary literal.
+    pub fn to_4state(&self) -> SvPrimaryLiteralIntegral {
+        let mut ret = SvPrimaryLiteralIntegral {
+            data_01: self.data_01.clone(),
+            data_xz: Some(vec![0]),
+            size: self.size,
+            signed: self.signed,
+        };
+
+        if ret.data_01.len() != ret.data_xz.as_ref().unwrap().len() {
+            for _x in 0..(ret.data_01.len() - ret.data_xz.as_ref().unwrap().len()) {
+                let mut new_vec = ret.data_xz.clone().unwrap();
+                new_vec.push(0);
+                ret.data_xz = Some(new_vec);
+            }
+        }
+
+        ret
+    }
+
+    /** Receives an integral primary literal and returns its contents in a 2-state integral primary literal.
+    The bits that are X/Z are resolved to 0, while the size and the sign are preserved. */
+    /// # Examples
+    ///
+    /// Value containing an X and a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13, 1],
+    ///     data_xz: Some(vec![6, 1]),
+    ///     size: 66,
+    ///     signed: true,
+    /// };
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 18446744073709551615],
+    ///     data_01: vec![9, 0],
     ///     data_xz: None,
-    ///     size: 128,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// assert_eq!(a.to_2state(), exp);
     /// ```
-    /// Positive value with width = usize::BITS and negative value with width = usize::BITS
+    /// Unlike to_4state, the conversion loses the X/Z(s)
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 3,
+    ///     signed: false,
     /// };
     ///
-    /// let mut b = SvPrimaryLiteralIntegral {
+    /// assert_eq!(a.to_4state().to_2state(), a);
+    /// assert_eq!(b.to_2state().to_4state(), a.to_4state());
+    /// ```
+    pub fn to_2state(&self) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+
+        if let Some(data_xz) = ret.data_xz.take() {
+            for x in 0..ret.data_01.len() {
+                ret.data_01[x] = ret.data_01[x] & !data_xz.get(x).copied().unwrap_or(0);
+            }
+        }
+
+        ret
+    }
+
+    /// Returns whether the MSB of data_01 is high. The size must be correctly specified.
+    /// # Examples
+    ///
+    /// Value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
     ///     size: 64,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// a._matched_sign_extend(&mut b);
+    /// assert_eq!(a.is_set_msb_01(), true);
+    /// ```
+    /// Value with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
     ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// assert_eq!(a.is_set_msb_01(), false);
+    /// ```
+    /// Value with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 9223372036854775808],
     ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_set_msb_01(), true);
+    /// ```
+    pub fn is_set_msb_01(&self) -> bool {
+        SvPrimaryLiteralIntegral::_is_set_msb(&self.data_01, self.size)
+    }
+
+    /// Returns whether the MSB of data_xz is high. The size must be correctly specified.
+    /// # Examples
+    ///
+    /// Value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![9223372036854775808]),
     ///     size: 64,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// assert_eq!(a.is_set_msb_xz(), true);
+    /// ```
+    /// Value with width = 2 * usize::BITS
     /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: false,
+    /// };
     ///
-    /// ## 4-State Primary Literals (No X/Z(s))
+    /// assert_eq!(a.is_set_msb_xz(), false);
+    /// ```
+    /// Value with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 9223372036854775808]),
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_set_msb_xz(), true);
+    /// ```
+    pub fn is_set_msb_xz(&self) -> bool {
+        match &self.data_xz {
+            Some(data_xz) => SvPrimaryLiteralIntegral::_is_set_msb(data_xz, self.size),
+            None => false,
+        }
+    }
+
+    // The MSB is found from the size alone, so that elements beyond the size are ignored.
+    fn _is_set_msb(data: &[usize], size: usize) -> bool {
+        if size == 0 {
+            return false;
+        }
+
+        let elmnt: usize = (size - 1) / usize::BITS as usize;
+        let shift: usize = (size - 1) % usize::BITS as usize;
+
+        match data.get(elmnt) {
+            Some(x) => (x >> shift) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /** Accepts two signed integral primary literals and ensures that both are properly sign extended and matched to their data_01 dimensions.
+    The correct final number of bits is set to both arguments. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
     ///
     /// Negative value with width = usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let mut b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
@@ -389,7 +1010,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 18446744073709551615],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
@@ -398,17 +1019,17 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with width = 2 * usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
     ///
     /// let mut b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
@@ -417,7 +1038,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
@@ -426,17 +1047,17 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with usize::BITS < width < 2 * usize::BITS and positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let mut b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
@@ -445,19 +1066,134 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 18446744073709551615],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(a, exp);
     /// ```
-    ///
+    /// Positive value with width = usize::BITS and negative value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let mut b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._matched_sign_extend(&mut b);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Negative value with width = usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let mut b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._matched_sign_extend(&mut b);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 18446744073709551615],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Negative value with width = 2 * usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// let mut b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._matched_sign_extend(&mut b);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Negative value with usize::BITS < width < 2 * usize::BITS and positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let mut b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._matched_sign_extend(&mut b);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 18446744073709551615],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    ///
     /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
     /// Value with width = usize::BITS and positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -485,7 +1221,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with with usize::BITS < width < 2 * usize::BITS and positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 1]),
@@ -513,7 +1249,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with with usize::BITS < width < 2 * usize::BITS (contains X/Z(s)) and positive value with width = usize::BITS (does not contain X/Z(s))
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: Some(vec![0, 1]),
@@ -540,8 +1276,17 @@ impl SvPrimaryLiteralIntegral {
     /// assert_eq!(a, exp);
     /// ```
     pub fn _matched_sign_extend(&mut self, right_nu: &mut SvPrimaryLiteralIntegral) {
+        self.try_matched_sign_extend(right_nu)
+            .expect("Could not sign extend the values!")
+    }
+
+    /// Same as _matched_sign_extend, but returns an error instead of panicking if any of the values is unsigned.
+    pub fn try_matched_sign_extend(
+        &mut self,
+        right_nu: &mut SvPrimaryLiteralIntegral,
+    ) -> Result<(), PrimlitError> {
         if self.signed != true || right_nu.signed != true {
-            panic!("Expected signed SvPrimaryLiterals but found unsigned!");
+            return Err(PrimlitError::ExpectedSigned);
         }
         let left_neg: bool = self.is_negative();
         let right_neg: bool = right_nu.is_negative();
@@ -564,9 +1309,7 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    self.data_01[x] = self.data_01[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                self.data_01[x] |= !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -584,10 +1327,8 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    self.data_xz.as_mut().unwrap()[x] =
-                        self.data_xz.as_ref().unwrap()[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                self.data_xz.as_mut().unwrap()[x] |=
+                    !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -605,9 +1346,7 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    right_nu.data_01[x] = right_nu.data_01[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                right_nu.data_01[x] |= !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -625,10 +1364,8 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    right_nu.data_xz.as_mut().unwrap()[x] =
-                        right_nu.data_xz.as_ref().unwrap()[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                right_nu.data_xz.as_mut().unwrap()[x] |=
+                    !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -638,19 +1375,32 @@ impl SvPrimaryLiteralIntegral {
 
         self.size = self.data_01.len() * usize::BITS as usize;
         right_nu.size = right_nu.data_01.len() * usize::BITS as usize;
+
+        Ok(())
     }
 
     /** Accepts two unsigned integral primary literals and ensures that both are properly zero extended and matched to their data_01 dimensions.
     The correct final number of bits is set to both arguments. */
 
     pub fn _matched_zero_extend(&mut self, right_nu: &mut SvPrimaryLiteralIntegral) {
+        self.try_matched_zero_extend(right_nu)
+            .expect("Could not zero extend the values!")
+    }
+
+    /// Same as _matched_zero_extend, but returns an error instead of panicking if any of the values is signed.
+    pub fn try_matched_zero_extend(
+        &mut self,
+        right_nu: &mut SvPrimaryLiteralIntegral,
+    ) -> Result<(), PrimlitError> {
         if self.signed == true || right_nu.signed == true {
-            panic!("Expected unsigned SvPrimaryLiterals but found signed!");
+            return Err(PrimlitError::ExpectedUnsigned);
         }
 
         self._primlit_vec_elmnt_match(right_nu);
         self.size = self.data_01.len() * usize::BITS as usize;
         right_nu.size = right_nu.data_01.len() * usize::BITS as usize;
+
+        Ok(())
     }
 
     /** Receives a signed integral primary literal and sign extends the value in the existing number of data_01 vector elements.
@@ -661,7 +1411,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -682,7 +1432,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: None,
@@ -703,7 +1453,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
@@ -727,7 +1477,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -748,7 +1498,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: Some(vec![0, 0]),
@@ -769,7 +1519,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: Some(vec![0, 0]),
@@ -793,7 +1543,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -814,7 +1564,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: Some(vec![0, 9223372036854775808]),
@@ -835,7 +1585,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 1]),
@@ -855,8 +1605,14 @@ impl SvPrimaryLiteralIntegral {
     /// assert_eq!(a, exp);
     /// ```
     pub fn _sign_extend(&mut self) {
+        self.try_sign_extend()
+            .expect("Could not sign extend the value!")
+    }
+
+    /// Same as _sign_extend, but returns an error instead of panicking if the value is unsigned.
+    pub fn try_sign_extend(&mut self) -> Result<(), PrimlitError> {
         if self.signed != true {
-            panic!("Expected signed SvPrimaryLiteralIntegral but found unsigned!");
+            return Err(PrimlitError::ExpectedSigned);
         }
 
         let left_neg: bool = self.is_negative();
@@ -874,9 +1630,7 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    self.data_01[x] = self.data_01[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                self.data_01[x] |= !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -894,10 +1648,8 @@ impl SvPrimaryLiteralIntegral {
                     last_element = true;
                 }
 
-                for y in 0..left_leading {
-                    self.data_xz.as_mut().unwrap()[x] =
-                        self.data_xz.as_ref().unwrap()[x] + 2usize.pow(usize::BITS - y - 1);
-                }
+                self.data_xz.as_mut().unwrap()[x] |=
+                    !usize::MAX.checked_shr(left_leading).unwrap_or(0);
 
                 if last_element {
                     break;
@@ -906,17 +1658,196 @@ impl SvPrimaryLiteralIntegral {
         }
 
         self.size = self.data_01.len() * usize::BITS as usize;
+
+        Ok(())
+    }
+
+    /** Receives a signed integral primary literal and sign extends it to exactly the given number of bits.
+    The new bits are copies of the MSB, which may also be an X/Z.
+    Returns an error if the value is unsigned or has more bits than requested, in which case _truncate can be used instead. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![137438953469],
+    ///     data_xz: None,
+    ///     size: 37,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.sign_extend_to(37), Ok(exp));
+    /// ```
+    /// Negative value extended beyond usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551614, 31],
+    ///     data_xz: None,
+    ///     size: 69,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.sign_extend_to(69), Ok(exp));
+    /// ```
+    /// Value with more bits than requested
+    /// ```
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     a.sign_extend_to(4),
+    ///     Err(PrimlitError::TooWide {
+    ///         size: 8,
+    ///         requested: 4
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Value with a Z MSB
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![4]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![29],
+    ///     data_xz: Some(vec![28]),
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.sign_extend_to(5), Ok(exp));
+    /// ```
+    pub fn sign_extend_to(&self, width: usize) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        if !self.signed {
+            return Err(PrimlitError::ExpectedSigned);
+        }
+
+        self._extend_to(width, self.is_set_msb_01(), self.is_set_msb_xz())
+    }
+
+    /** Receives an integral primary literal and zero extends it to exactly the given number of bits.
+    Returns an error if the value has more bits than requested, in which case _truncate can be used instead. */
+    /// # Examples
+    ///
+    /// Negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 37,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.zero_extend_to(37), Ok(exp));
+    /// ```
+    /// Value with an X MSB extended beyond usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
+    ///     data_xz: Some(vec![2, 0]),
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.zero_extend_to(67), Ok(exp));
+    /// ```
+    pub fn zero_extend_to(&self, width: usize) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        self._extend_to(width, false, false)
+    }
+
+    // Fills the bits from the current width up to the new width with the given 0/1 and X/Z bits.
+    fn _extend_to(
+        &self,
+        width: usize,
+        fill_01: bool,
+        fill_xz: bool,
+    ) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        if width < self.size {
+            return Err(PrimlitError::TooWide {
+                size: self.size,
+                requested: width,
+            });
+        }
+
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        let elmnts: usize = SvPrimaryLiteralIntegral::_elmnts(width);
+
+        ret.data_01.resize(elmnts, 0);
+        if let Some(data_xz) = ret.data_xz.as_mut() {
+            data_xz.resize(elmnts, 0);
+        }
+
+        for x in self.size..width {
+            if fill_01 {
+                ret.data_01[x / usize::BITS as usize] |= 1 << (x % usize::BITS as usize);
+            }
+            if fill_xz {
+                ret.data_xz.as_mut().unwrap()[x / usize::BITS as usize] |=
+                    1 << (x % usize::BITS as usize);
+            }
+        }
+
+        ret.size = width;
+
+        Ok(ret)
     }
 
     /** Receives a signed integral primary literal and returns its opposite signed primary literal (i.e +ve -> -ve and vice versa).
-    The correct final number of bits is set to the argument. */
+    The correct final number of bits is set to the argument. If the value contains X/Z(s), all the bits of the result are X. */
     /// # Examples
     ///
     /// ## 2-State Primary Literals
     ///
     /// Positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -937,7 +1868,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Negative value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: None,
@@ -958,7 +1889,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -982,7 +1913,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -1003,7 +1934,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: Some(vec![0, 0]),
@@ -1024,7 +1955,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -1043,14 +1974,63 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// assert_eq!(b, exp);
     /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'sbx001").unwrap();
+    ///
+    /// let b: SvPrimaryLiteralIntegral = a.negate();
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// ```
     pub fn negate(&self) -> SvPrimaryLiteralIntegral {
-        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        self.try_negate().expect("Could not negate the value!")
+    }
 
-        if ret.is_zero() {
-            return ret;
-        } else if ret.signed != true {
-            panic!("Expected signed SvPrimaryLiteralIntegral but found unsigned!");
-        }
+    /** Same as negate, but returns an error instead of panicking if a non-zero value is unsigned. */
+    /// # Examples
+    ///
+    /// ```
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: true,
+    /// };
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.try_negate(), Ok(a.negate()));
+    /// assert_eq!(b.try_negate(), Err(PrimlitError::ExpectedSigned));
+    /// ```
+    pub fn try_negate(&self) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        debug_assert_eq!(self.validate(), Ok(()));
+
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+
+        if ret.is_zero() {
+            return Ok(ret);
+        } else if ret.signed != true {
+            return Err(PrimlitError::ExpectedSigned);
+        } else if ret.contains_xz() {
+            return Ok(logic_x(ret.size, ret.signed));
+        }
 
         let from_negative: bool = ret.is_negative();
         ret = ret.inv();
@@ -1076,7 +2056,144 @@ impl SvPrimaryLiteralIntegral {
             ret.data_xz = ret.to_4state().data_xz;
         }
 
-        ret
+        Ok(ret)
+    }
+
+    /** Returns the absolute value of an integral primary literal.
+    Negative values are negated, following the number of bits set by negate, while all the other values are returned unchanged.
+    If the value contains X/Z(s), all the bits of the result are X. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![251],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    /// Signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    /// Unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![251],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![251],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![128],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![128],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 9,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![251],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![255]),
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.abs(), exp);
+    /// ```
+    pub fn abs(&self) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() {
+            logic_x(self.size, self.signed)
+        } else if self.signed && self.is_negative() {
+            self.negate()
+        } else {
+            self.clone()
+        }
     }
 
     /** Receives a signed integral primary literal and returns a primary literal with its inverted value.
@@ -1087,7 +2204,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -1108,7 +2225,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904, 4611686018427387904],
     ///     data_xz: None,
@@ -1132,7 +2249,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -1153,7 +2270,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904, 4611686018427387904],
     ///     data_xz: Some(vec![0, 0]),
@@ -1177,7 +2294,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: Some(vec![0, 1]),
@@ -1198,7 +2315,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904, 4611686018427387904],
     ///     data_xz: Some(vec![1, 0]),
@@ -1217,35 +2334,34 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// assert_eq!(b, exp);
     /// ```
+    /// Value with width = 0
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::new(0, false);
+    ///
+    /// assert_eq!(a.inv(), a);
+    /// assert_eq!(a.inv().validate(), Ok(()));
+    /// ```
     pub fn inv(&self) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+
         let mut ret: SvPrimaryLiteralIntegral = self.clone();
 
-        let first_elmnt_bits: u32;
-        if ret.size % usize::BITS as usize == 0 {
-            first_elmnt_bits = usize::BITS;
-        } else {
-            first_elmnt_bits = ret.size as u32 % usize::BITS;
-        }
-        let remaining_bits = usize::BITS - first_elmnt_bits;
-        let last_index = ret.data_01.len() - 1;
+        // X/Z bits are not inverted, but Z is turned into X by clearing its 01 bit.
+        for x in 0..ret.data_01.len() {
+            ret.data_01[x] = !ret.data_01[x];
 
-        for _x in 0..ret.size {
-            if ret.is_4state()
-                && (ret.data_xz.as_ref().unwrap()[last_index].leading_zeros() == remaining_bits)
-            {
-                if ret.data_01[last_index].leading_zeros() == remaining_bits {
-                    ret.data_01[last_index] =
-                        ret.data_01[last_index] - 2usize.pow(first_elmnt_bits - 1);
-                }
-            } else if ret.data_01[last_index].leading_zeros() == remaining_bits {
-                ret.data_01[last_index] =
-                    ret.data_01[last_index] - 2usize.pow(first_elmnt_bits - 1);
-            } else {
-                ret.data_01[last_index] =
-                    ret.data_01[last_index] + 2usize.pow(first_elmnt_bits - 1);
+            if let Some(data_xz) = &ret.data_xz {
+                ret.data_01[x] &= !data_xz[x];
             }
+        }
 
-            ret = ret.ror(1);
+        let last_index = ret.data_01.len() - 1;
+        let last_elmnt_bits = ret.size % usize::BITS as usize;
+        if ret.size == 0 {
+            ret.data_01[last_index] = 0;
+        } else if last_elmnt_bits != 0 {
+            ret.data_01[last_index] &= (1 << last_elmnt_bits) - 1;
         }
 
         ret
@@ -1260,7 +2376,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -1281,7 +2397,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: None,
@@ -1302,7 +2418,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -1323,7 +2439,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -1342,12 +2458,54 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// assert_eq!(b, exp);
     /// ```
+    /// Value with width = 2 * usize::BITS shifted by more than usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b: SvPrimaryLiteralIntegral = a << 100;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 206158430208, 0, 34359738368],
+    ///     data_xz: None,
+    ///     size: 228,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b: SvPrimaryLiteralIntegral = a << 1;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// ```
     ///
     /// ## 4-State Primary Literals (No X/Z(s))
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -1368,7 +2526,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: Some(vec![0, 0]),
@@ -1389,7 +2547,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -1410,7 +2568,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -1434,7 +2592,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -1455,7 +2613,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -1476,7 +2634,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -1497,7 +2655,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
@@ -1518,7 +2676,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -1539,7 +2697,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -1560,7 +2718,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -1581,7 +2739,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
     ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
@@ -1601,9 +2759,31 @@ impl SvPrimaryLiteralIntegral {
     /// assert_eq!(b, exp);
     /// ```
     pub fn lsl(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+
         let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        let words: usize = n / usize::BITS as usize;
 
-        for _x in 0..n {
+        // Whole elements are moved at once and the vectors are resized to the new width.
+        if words != 0 {
+            ret.size = ret.size + words * usize::BITS as usize;
+
+            let len: usize = std::cmp::max(
+                ret.data_01.len(),
+                (ret.size + usize::BITS as usize - 1) / usize::BITS as usize,
+            );
+
+            ret.data_01.splice(0..0, vec![0; words]);
+            ret.data_01.truncate(len);
+
+            if ret.is_4state() {
+                let data_xz = ret.data_xz.as_mut().unwrap();
+                data_xz.splice(0..0, vec![0; words]);
+                data_xz.truncate(len);
+            }
+        }
+
+        for _x in 0..(n % usize::BITS as usize) {
             let mut leading_one: bool = false;
             let mut leading_one_xz: bool = false;
 
@@ -1651,7 +2831,7 @@ impl SvPrimaryLiteralIntegral {
             } else if leading_one_xz {
                 ret.data_01.push(0);
                 ret.data_xz.as_mut().unwrap().push(1);
-            } else if ret.signed && (ret.size > usize::BITS as usize * ret.data_01.len()) {
+            } else if ret.size > usize::BITS as usize * ret.data_01.len() {
                 ret.data_01.push(0);
 
                 if ret.is_4state() {
@@ -1671,11 +2851,11 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775809, 3],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
@@ -1684,7 +2864,7 @@ impl SvPrimaryLiteralIntegral {
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![16140901064495857664, 0],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
@@ -1692,7 +2872,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775809, 9223372036854775809],
     ///     data_xz: None,
@@ -1713,7 +2893,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -1732,16 +2912,37 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// assert_eq!(b, exp);
     /// ```
+    /// Value with width = 2 * usize::BITS shifted by more than usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b: SvPrimaryLiteralIntegral = a >> 100;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![134217728, 0],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// ```
     ///
     /// ## 4-State Primary Literals (No X/Z(s))
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775809, 3],
     ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
@@ -1750,7 +2951,7 @@ impl SvPrimaryLiteralIntegral {
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![16140901064495857664, 0],
     ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
@@ -1758,7 +2959,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775809, 9223372036854775809],
     ///     data_xz: Some(vec![0, 0]),
@@ -1779,7 +2980,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -1803,7 +3004,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -1824,7 +3025,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -1845,7 +3046,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -1866,7 +3067,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
@@ -1887,7 +3088,7 @@ impl SvPrimaryLiteralIntegral {
     /// ```
     /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 1],
     ///     data_xz: Some(vec![0, 1]),
@@ -1907,9 +3108,24 @@ impl SvPrimaryLiteralIntegral {
     /// assert_eq!(b, exp);
     /// ```
     pub fn lsr(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+
         let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        let words: usize = std::cmp::min(n / usize::BITS as usize, ret.data_01.len());
 
-        for _x in 0..n {
+        // Whole elements are moved at once, filling the top elements with zeros.
+        if words != 0 {
+            ret.data_01.drain(0..words);
+            ret.data_01.extend(vec![0; words]);
+
+            if ret.is_4state() {
+                let data_xz = ret.data_xz.as_mut().unwrap();
+                data_xz.drain(0..words);
+                data_xz.extend(vec![0; words]);
+            }
+        }
+
+        for _x in 0..(n % usize::BITS as usize) {
             let mut trailing_one: bool = false;
             let mut trailing_one_xz: bool = false;
 
@@ -1917,7 +3133,7 @@ impl SvPrimaryLiteralIntegral {
                 let pre_mod = ret.data_01[y];
 
                 if trailing_one {
-                    ret.data_01[y] = (ret.data_01[y] >> 1) + 2usize.pow(usize::BITS - 1);
+                    ret.data_01[y] = (ret.data_01[y] >> 1) | (1 << (usize::BITS - 1));
                     trailing_one = false;
                 } else {
                     ret.data_01[y] = ret.data_01[y] >> 1;
@@ -1932,7 +3148,7 @@ impl SvPrimaryLiteralIntegral {
 
                     if trailing_one_xz {
                         ret.data_xz.as_mut().unwrap()[y] =
-                            (ret.data_xz.as_ref().unwrap()[y] >> 1) + 2usize.pow(usize::BITS - 1);
+                            (ret.data_xz.as_ref().unwrap()[y] >> 1) | (1 << (usize::BITS - 1));
                         trailing_one_xz = false;
                     } else {
                         ret.data_xz.as_mut().unwrap()[y] = ret.data_xz.as_ref().unwrap()[y] >> 1;
@@ -1948,1029 +3164,1423 @@ impl SvPrimaryLiteralIntegral {
         ret
     }
 
-    /** Receives the number of shift positions and shifts the value to the left without changing the number of bits.
-    The dropped bits are shifted in the RHS of the value. */
+    /** Receives an index and returns the selected bit as an unsigned single bit value.
+    An index outside of the width selects an X for 4-state values and a 0 for 2-state values. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// Signed value with a 1 selected
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_01: vec![4],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 3,
     ///     signed: true,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 0],
+    ///     data_01: vec![1],
     ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    ///     size: 1,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.bit_select(2), exp);
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// Value with an X selected
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![2, 2],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 1,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.bit_select(1), exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
-    ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// 4-State value with an index outside of the width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 2,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 1,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.bit_select(2), exp);
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// 2-State value with an index outside of the width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![2, 2],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 1,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.bit_select(5), exp);
+    /// ```
+    pub fn bit_select(&self, index: usize) -> SvPrimaryLiteralIntegral {
+        self.part_select(index, index)
+    }
+
+    /** Receives an index and returns the bit as an unsigned single bit value, like bit_select.
+    Returns an error if the index is outside of the width. */
+    /// # Examples
+    ///
     /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'b1z01").unwrap();
     ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
+    /// assert_eq!(a.get_bit(0), Ok(logic1b_1()));
+    /// assert_eq!(a.get_bit(2), Ok(_logic1b_z()));
+    /// assert_eq!(
+    ///     a.get_bit(4),
+    ///     Err(PrimlitError::IndexOutOfRange { index: 4, size: 4 })
+    /// );
+    /// ```
+    pub fn get_bit(&self, index: usize) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        if index >= self.size {
+            return Err(PrimlitError::IndexOutOfRange {
+                index,
+                size: self.size,
+            });
+        }
+
+        Ok(self.bit_select(index))
+    }
+
+    /** Receives an index and sets the bit to the LSB of the given value, which may be an X/Z.
+    A 2-state value becomes a 4-state value when the bit is set to an X/Z. Returns an error if the index is outside of the width. */
+    /// # Examples
     ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// Bit of a 2-state value with usize::BITS < width < 2 * usize::BITS set to 1 and to X
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![9223372036854775808, 1]),
-    ///     size: 65,
-    ///     signed: true,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    /// a.set_bit(65, bit1b_1()).unwrap();
+    /// a.set_bit(1, logic1b_x()).unwrap();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 0],
-    ///     data_xz: Some(vec![3, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![0, 2],
+    ///     data_xz: Some(vec![2, 0]),
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a, exp);
+    /// assert_eq!(
+    ///     a.set_bit(66, bit1b_1()),
+    ///     Err(PrimlitError::IndexOutOfRange { index: 66, size: 66 })
+    /// );
+    /// ```
+    /// Z bit of a 4-state value set to 1
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("4'b1z01").unwrap();
+    ///
+    /// a.set_bit(2, logic1b_1()).unwrap();
+    ///
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::from_sv_literal("4'b1101").unwrap().to_4state());
+    /// ```
+    pub fn set_bit(
+        &mut self,
+        index: usize,
+        value: SvPrimaryLiteralIntegral,
+    ) -> Result<(), PrimlitError> {
+        if index >= self.size {
+            return Err(PrimlitError::IndexOutOfRange {
+                index,
+                size: self.size,
+            });
+        }
+
+        let elmnt: usize = index / usize::BITS as usize;
+        let bit: usize = 1 << (index % usize::BITS as usize);
+
+        let value_01: bool = value.data_01[0] & 1 == 1;
+        let value_xz: bool = match &value.data_xz {
+            Some(data_xz) => data_xz[0] & 1 == 1,
+            None => false,
+        };
+
+        if value_xz && !self.is_4state() {
+            *self = self.to_4state();
+        }
+
+        if value_01 {
+            self.data_01[elmnt] |= bit;
+        } else {
+            self.data_01[elmnt] &= !bit;
+        }
+
+        if let Some(data_xz) = self.data_xz.as_mut() {
+            if value_xz {
+                data_xz[elmnt] |= bit;
+            } else {
+                data_xz[elmnt] &= !bit;
+            }
+        }
+
+        Ok(())
+    }
+
+    /** Receives an index and sets the bit to 0. Returns an error if the index is outside of the width. */
+    /// # Examples
+    ///
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("4'b1x01").unwrap();
+    ///
+    /// a.clear_bit(2).unwrap();
+    /// a.clear_bit(3).unwrap();
+    ///
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::from_sv_literal("4'b0001").unwrap().to_4state());
+    /// assert_eq!(
+    ///     a.clear_bit(4),
+    ///     Err(PrimlitError::IndexOutOfRange { index: 4, size: 4 })
+    /// );
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 128,
-    ///     signed: true,
-    /// };
+    pub fn clear_bit(&mut self, index: usize) -> Result<(), PrimlitError> {
+        self.set_bit(index, bit1b_0())
+    }
+
+    /** Receives the MSB and LSB indices of a range and writes the low msb - lsb + 1 bits of the given value into it, which may contain X/Z(s).
+    A narrower value is zero extended, unless it is unsigned with an X/Z MSB, in which case it is extended with that X/Z.
+    A wider value is truncated, in which case true is returned.
+    A 2-state value becomes a 4-state value when any of the written bits is an X/Z.
+    Returns an error if the MSB is outside of the width or the LSB is above the MSB. */
+    /// # Examples
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    /// X/Z subfield written into a 2-state value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("8'b1111_0000").unwrap();
     ///
+    /// a.set_range(5, 2, SvPrimaryLiteralIntegral::from_sv_literal("4'bx1z0").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::from_sv_literal("8'b11x1_z000").unwrap());
+    /// assert_eq!(a.data_xz, Some(vec![40]));
+    /// ```
+    /// Narrower unsigned value with an X MSB, which is extended with Xs
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("8'h00").unwrap();
+    ///
+    /// a.set_range(7, 4, SvPrimaryLiteralIntegral::from_sv_literal("2'bx1").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::from_sv_literal("8'bxxx1_0000").unwrap());
+    /// ```
+    /// Narrower value which is zero extended, over the X/Z(s) of a 4-state value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::from_sv_literal("8'bzzzz_zzzz").unwrap();
+    ///
+    /// a.set_range(3, 0, SvPrimaryLiteralIntegral::from_sv_literal("2'b11").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::from_sv_literal("8'bzzzz_0011").unwrap());
+    /// ```
+    /// Wider value which is truncated, in a range across two elements
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral::new(70, false);
+    ///
+    /// let truncated = a
+    ///     .set_range(67, 60, SvPrimaryLiteralIntegral::from_sv_literal("12'h3ff").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!(truncated);
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![2, 2],
-    ///     data_xz: Some(vec![0, 2]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![17293822569102704640, 15],
+    ///     data_xz: None,
+    ///     size: 70,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    pub fn rol(&self, n: usize) -> SvPrimaryLiteralIntegral {
-        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+    /// Range outside of the width, or with the LSB above the MSB
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// let mut a = SvPrimaryLiteralIntegral::new(8, false);
+    ///
+    /// assert_eq!(
+    ///     a.set_range(8, 0, bit1b_1()),
+    ///     Err(PrimlitError::IndexOutOfRange { index: 8, size: 8 })
+    /// );
+    /// assert_eq!(
+    ///     a.set_range(2, 3, bit1b_1()),
+    ///     Err(PrimlitError::ReversedRange { msb: 2, lsb: 3 })
+    /// );
+    /// assert_eq!(a, SvPrimaryLiteralIntegral::new(8, false));
+    /// ```
+    pub fn set_range(
+        &mut self,
+        msb: usize,
+        lsb: usize,
+        mut value: SvPrimaryLiteralIntegral,
+    ) -> Result<bool, PrimlitError> {
+        if msb >= self.size {
+            return Err(PrimlitError::IndexOutOfRange {
+                index: msb,
+                size: self.size,
+            });
+        } else if lsb > msb {
+            return Err(PrimlitError::ReversedRange { msb, lsb });
+        }
 
-        for _x in 0..n {
-            let previous_size = ret.size;
-            let leading_one: bool = ret.is_set_msb_01();
-            let leading_one_xz: bool = ret.is_set_msb_xz();
+        let width: usize = msb - lsb + 1;
+        let truncated: bool = value.size > width;
 
-            ret = ret.lsl(1);
-            ret._truncate(previous_size);
-            if leading_one {
-                ret.data_01[0] = ret.data_01[0] + 1;
+        if truncated {
+            value._truncate(width);
+        } else if value.size < width {
+            let fill_xz: bool = !value.signed && value.is_set_msb_xz();
+            let fill_01: bool = fill_xz && value.is_set_msb_01();
+
+            value = value._extend_to(width, fill_01, fill_xz).unwrap();
+        }
+
+        if value.contains_xz() && !self.is_4state() {
+            *self = self.to_4state();
+        }
+
+        for x in 0..width {
+            let src_elmnt: usize = x / usize::BITS as usize;
+            let src_bit: usize = x % usize::BITS as usize;
+            let dst_elmnt: usize = (lsb + x) / usize::BITS as usize;
+            let dst_bit: usize = 1 << ((lsb + x) % usize::BITS as usize);
+
+            if (value.data_01[src_elmnt] >> src_bit) & 1 == 1 {
+                self.data_01[dst_elmnt] |= dst_bit;
+            } else {
+                self.data_01[dst_elmnt] &= !dst_bit;
             }
 
-            if leading_one_xz {
-                ret.data_xz.as_mut().unwrap()[0] = ret.data_xz.as_ref().unwrap()[0] + 1;
+            if let Some(data_xz) = self.data_xz.as_mut() {
+                let value_xz: bool = match &value.data_xz {
+                    Some(value_xz) => (value_xz[src_elmnt] >> src_bit) & 1 == 1,
+                    None => false,
+                };
+
+                if value_xz {
+                    data_xz[dst_elmnt] |= dst_bit;
+                } else {
+                    data_xz[dst_elmnt] &= !dst_bit;
+                }
             }
         }
 
-        ret
+        Ok(truncated)
     }
 
-    /* Receives the number of shift positions and shifts the value to the right without changing the number of bits.
-    The dropped bits are shifted in the LHS of the value. */
+    /** Receives the MSB and LSB indices of a range and returns the selected bits as an unsigned value of msb - lsb + 1 bits.
+    Bits outside of the width are selected as X for 4-state values and as 0 for 2-state values. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// Signed value with usize::BITS < width < 2 * usize::BITS and a range across both elements
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 3],
+    ///     data_01: vec![9223372036854775808, 5],
     ///     data_xz: None,
-    ///     size: 66,
+    ///     size: 67,
     ///     signed: true,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16140901064495857664, 1],
+    ///     data_01: vec![6],
     ///     data_xz: None,
-    ///     size: 66,
-    ///     signed: true,
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.part_select(65, 62), exp);
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// Value with X/Z(s) within the range
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 9223372036854775809],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![61680],
+    ///     data_xz: Some(vec![65280]),
+    ///     size: 16,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![6917529027641081856, 6917529027641081856],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![240],
+    ///     data_xz: Some(vec![255]),
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.part_select(15, 8), exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
-    ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// 4-State value with a range partially outside of the width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 3],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
-    ///     signed: true,
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16140901064495857664, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.part_select(5, 2), exp);
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// 2-State value with a range partially outside of the width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 9223372036854775809],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![6917529027641081856, 6917529027641081856],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.part_select(5, 2), exp);
     /// ```
+    pub fn part_select(&self, msb: usize, lsb: usize) -> SvPrimaryLiteralIntegral {
+        self.try_part_select(msb, lsb)
+            .expect("Expected the MSB index to be greater than or equal to the LSB index!")
+    }
+
+    /** Same as part_select, but returns an error instead of panicking if the LSB is above the MSB. */
+    /// # Examples
     ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
+    /// ```
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("8'b1010_0110").unwrap();
     ///
-    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// assert_eq!(a.try_part_select(5, 2), Ok(a.part_select(5, 2)));
+    /// assert_eq!(
+    ///     a.try_part_select(2, 5),
+    ///     Err(PrimlitError::ReversedRange { msb: 2, lsb: 5 })
+    /// );
+    /// ```
+    pub fn try_part_select(
+        &self,
+        msb: usize,
+        lsb: usize,
+    ) -> Result<SvPrimaryLiteralIntegral, PrimlitError> {
+        if msb < lsb {
+            return Err(PrimlitError::ReversedRange { msb, lsb });
+        }
+
+        let size: usize = msb - lsb + 1;
+        let elmnts: usize = (size + usize::BITS as usize - 1) / usize::BITS as usize;
+
+        let mut ret = SvPrimaryLiteralIntegral {
+            data_01: vec![0; elmnts],
+            data_xz: if self.is_4state() {
+                Some(vec![0; elmnts])
+            } else {
+                None
+            },
+            size,
+            signed: false,
+        };
+
+        for x in 0..size {
+            let ret_elmnt: usize = x / usize::BITS as usize;
+            let ret_bit: usize = 1 << (x % usize::BITS as usize);
+
+            if lsb + x >= self.size {
+                if let Some(data_xz) = ret.data_xz.as_mut() {
+                    data_xz[ret_elmnt] |= ret_bit;
+                }
+                continue;
+            }
+
+            let elmnt: usize = (lsb + x) / usize::BITS as usize;
+            let shift: usize = (lsb + x) % usize::BITS as usize;
+
+            if (self.data_01[elmnt] >> shift) & 1 == 1 {
+                ret.data_01[ret_elmnt] |= ret_bit;
+            }
+
+            if let (Some(data_xz), Some(self_xz)) = (ret.data_xz.as_mut(), &self.data_xz) {
+                if (self_xz[elmnt] >> shift) & 1 == 1 {
+                    data_xz[ret_elmnt] |= ret_bit;
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /** Reverses the order of the bits within the width, so that the MSB becomes the LSB. The width and signedness are kept. */
+    /// # Examples
+    ///
+    /// Value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 3],
-    ///     data_xz: Some(vec![1, 0]),
-    ///     size: 66,
+    ///     data_01: vec![6],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 5,
     ///     signed: true,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16140901064495857664, 1],
-    ///     data_xz: Some(vec![0, 1]),
-    ///     size: 66,
-    ///     signed: true,
+    ///     data_01: vec![12],
+    ///     data_xz: Some(vec![16]),
+    ///     size: 5,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.reverse_bits(), exp);
     /// ```
-    /// Value with width = 2 * usize::BITS
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 9223372036854775809],
-    ///     data_xz: Some(vec![9223372036854775809, 9223372036854775809]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![1, 2],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
-    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 2],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reverse_bits(), exp);
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS and a single bit set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 70,
+    ///     signed: false,
+    /// };
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![6917529027641081856, 6917529027641081856],
-    ///     data_xz: Some(vec![6917529027641081856, 6917529027641081856]),
-    ///     size: 128,
-    ///     signed: true,
+    ///     data_01: vec![0, 8],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 70,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(b, exp);
+    /// assert_eq!(a.reverse_bits(), exp);
     /// ```
-    pub fn ror(&self, n: usize) -> SvPrimaryLiteralIntegral {
+    pub fn reverse_bits(&self) -> SvPrimaryLiteralIntegral {
         let mut ret: SvPrimaryLiteralIntegral = self.clone();
-        let last_index = ret.data_01.len() - 1;
-        let msb: u32;
-
-        if ret.size % usize::BITS as usize == 0 {
-            msb = usize::BITS;
-        } else {
-            msb = ret.size as u32 % usize::BITS;
+        ret.data_01.iter_mut().for_each(|x| *x = 0);
+        if let Some(data_xz) = ret.data_xz.as_mut() {
+            data_xz.iter_mut().for_each(|x| *x = 0);
         }
 
-        for _x in 0..n {
-            let trailing_one: bool = ret.data_01[0].trailing_zeros() == 0;
-            let mut trailing_one_xz: bool = false;
-
-            if ret.is_4state() {
-                trailing_one_xz = ret.data_xz.as_ref().unwrap()[0].trailing_zeros() == 0;
-            }
+        for x in 0..self.size {
+            let elmnt: usize = x / usize::BITS as usize;
+            let shift: usize = x % usize::BITS as usize;
 
-            ret = ret.lsr(1);
+            let y: usize = self.size - 1 - x;
+            let ret_elmnt: usize = y / usize::BITS as usize;
+            let ret_bit: usize = 1 << (y % usize::BITS as usize);
 
-            if trailing_one {
-                ret.data_01[last_index] = ret.data_01[last_index] + 2usize.pow(msb - 1);
+            if (self.data_01[elmnt] >> shift) & 1 == 1 {
+                ret.data_01[ret_elmnt] |= ret_bit;
             }
 
-            if trailing_one_xz {
-                ret.data_xz.as_mut().unwrap()[last_index] =
-                    ret.data_xz.as_ref().unwrap()[last_index] + 2usize.pow(msb - 1);
+            if let (Some(data_xz), Some(self_xz)) = (ret.data_xz.as_mut(), &self.data_xz) {
+                if (self_xz[elmnt] >> shift) & 1 == 1 {
+                    data_xz[ret_elmnt] |= ret_bit;
+                }
             }
         }
 
         ret
     }
 
-    /** Receives two integral primary literals, concatenates them (logically shifts left the LHS primlit by RHS primlit's size and adds them).
-    Returns an integral SvPrimaryLiteralIntegral with the final value. */
+    /** Reverses the order of the bytes within the width, e.g. to compare against a memory image of the other endianness.
+    The width, signedness and X/Z(s) are kept. If the width is not a multiple of 8, the remaining most significant bits
+    form a partial byte, which becomes the least significant bits, while the whole bytes are reversed above it. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// Value with width = 32 containing X/Z(s)
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![305419896],
+    ///     data_xz: Some(vec![240]),
+    ///     size: 32,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 128,
+    ///     data_01: vec![2018915346],
+    ///     data_xz: Some(vec![4026531840]),
+    ///     size: 32,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.byte_swap(), exp);
     /// ```
-    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
+    /// Value with width = 20, i.e. 2 bytes and 4 remaining bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 0],
-    ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![703710],
     ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: true,
+    ///     size: 20,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![13835058055282163712, 4611686018427387904],
+    ///     data_01: vec![912330],
     ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
+    ///     size: 20,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.byte_swap(), exp);
     /// ```
-    /// Value with width = usize::BITS and value with width < usize::BITS
+    pub fn byte_swap(&self) -> SvPrimaryLiteralIntegral {
+        self._swap_groups(8)
+    }
+
+    /** Reverses the order of the nibbles within the width. The width, signedness and X/Z(s) are kept.
+    If the width is not a multiple of 4, the remaining most significant bits form a partial nibble, which becomes the
+    least significant bits, while the whole nibbles are reversed above it. */
+    /// # Examples
+    ///
+    /// Value with width = 32
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
+    ///     data_01: vec![305419896],
     ///     data_xz: None,
-    ///     size: 4,
+    ///     size: 32,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 4],
+    ///     data_01: vec![2271560481],
     ///     data_xz: None,
-    ///     size: 68,
+    ///     size: 32,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.nibble_swap(), exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
-    ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// Value with width = 18, i.e. 4 nibbles and 2 remaining bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![175053],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 18,
     ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![226026],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 18,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// assert_eq!(a.nibble_swap(), exp);
+    /// ```
+    pub fn nibble_swap(&self) -> SvPrimaryLiteralIntegral {
+        self._swap_groups(4)
+    }
+
+    // Reverses the order of groups of the given number of bits, with a partial most significant group becoming the
+    // least significant bits.
+    fn _swap_groups(&self, group: usize) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        ret.data_01.iter_mut().for_each(|x| *x = 0);
+        if let Some(data_xz) = ret.data_xz.as_mut() {
+            data_xz.iter_mut().for_each(|x| *x = 0);
+        }
+
+        let whole_groups: usize = self.size / group;
+        let remainder: usize = self.size % group;
+
+        for x in 0..self.size {
+            let elmnt: usize = x / usize::BITS as usize;
+            let shift: usize = x % usize::BITS as usize;
+
+            let y: usize = if x / group < whole_groups {
+                remainder + (whole_groups - 1 - x / group) * group + x % group
+            } else {
+                x % group
+            };
+            let ret_elmnt: usize = y / usize::BITS as usize;
+            let ret_bit: usize = 1 << (y % usize::BITS as usize);
+
+            if (self.data_01[elmnt] >> shift) & 1 == 1 {
+                ret.data_01[ret_elmnt] |= ret_bit;
+            }
+
+            if let (Some(data_xz), Some(self_xz)) = (ret.data_xz.as_mut(), &self.data_xz) {
+                if (self_xz[elmnt] >> shift) & 1 == 1 {
+                    data_xz[ret_elmnt] |= ret_bit;
+                }
+            }
+        }
+
+        ret
+    }
+
+    /** Receives the number of shift positions and arithmetically shifts the value to the right without changing the number of bits.
+    Signed values shift in copies of their MSB, so that an X/Z MSB stays X/Z, while unsigned values shift in zeros like lsr. */
+    /// # Examples
     ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: false,
-    /// };
+    /// ## 2-State Primary Literals
     ///
-    /// assert_eq!(c, exp);
-    /// ```
-    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
+    /// Signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775809, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    ///     data_01: vec![200],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// let b: SvPrimaryLiteralIntegral = a.asr(3);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![13835058055282163712, 4611686018427387904],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    ///     data_01: vec![249],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(b, exp);
     /// ```
-    /// Value with width = usize::BITS and value with width < usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 4,
+    ///     data_01: vec![0, 2],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// let b: SvPrimaryLiteralIntegral = a.asr(2);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 4],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 68,
+    ///     data_01: vec![9223372036854775808, 3],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(b, exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
-    ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// Unsigned value with the MSB set
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
-    ///     signed: false,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
+    ///     data_01: vec![200],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// let b: SvPrimaryLiteralIntegral = a.asr(3);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
-    ///     size: 128,
+    ///     data_01: vec![25],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(b, exp);
     /// ```
-    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Signed value with an X MSB
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![8]),
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// let b: SvPrimaryLiteralIntegral = a.asr(2);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904, 4611686018427387904]),
-    ///     size: 128,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![14]),
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(b, exp);
     /// ```
-    /// Value with width = usize::BITS and value with width < usize::BITS
+    /// Signed value with a Z MSB shifted by more than its width
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![8]),
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 4,
-    ///     signed: true,
-    /// };
-    ///
-    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
+    /// let b: SvPrimaryLiteralIntegral = a.asr(6);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 4],
-    ///     data_xz: Some(vec![0, 4]),
-    ///     size: 68,
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(b, exp);
     /// ```
-    pub fn cat(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut ret: SvPrimaryLiteralIntegral = self.clone();
-        ret = ret.lsl(right_nu.size);
+    pub fn asr(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
 
-        let mut left_nu: SvPrimaryLiteralIntegral = ret.clone();
+        if !self.signed {
+            return self.lsr(n);
+        }
 
-        if left_nu.is_4state() || right_nu.is_4state() {
-            let mut left_xz = SvPrimaryLiteralIntegral {
-                data_01: left_nu.data_xz.as_ref().unwrap().clone(),
-                data_xz: None,
-                size: left_nu.size,
-                signed: false,
-            };
+        let msb_01: bool = self.is_set_msb_01();
+        let msb_xz: bool = self.is_set_msb_xz();
+        let mut ret: SvPrimaryLiteralIntegral = self.lsr(std::cmp::min(n, self.size));
 
-            let right_xz = SvPrimaryLiteralIntegral {
-                data_01: right_nu.data_xz.as_ref().unwrap().clone(),
-                data_xz: None,
-                size: right_nu.size,
-                signed: false,
-            };
+        for x in (self.size - std::cmp::min(n, self.size))..self.size {
+            let elmnt: usize = x / usize::BITS as usize;
+            let bit: usize = 1 << (x % usize::BITS as usize);
 
-            left_xz._unsigned_primlit_add(right_xz.clone());
-            left_nu.data_xz = Some(left_xz.data_01.clone());
-        }
+            if msb_01 {
+                ret.data_01[elmnt] |= bit;
+            }
 
-        ret._unsigned_primlit_add(right_nu.clone());
-        ret.size = self.size + right_nu.size;
-        ret.data_xz = left_nu.data_xz.clone();
+            if msb_xz {
+                ret.data_xz.as_mut().unwrap()[elmnt] |= bit;
+            }
+        }
 
         ret
     }
 
-    /** Emulates the less than operator "<" as defined in 1800-2017 | 11.4.4 Relational operators */
+    /** Receives the number of shift positions and shifts the value to the right without changing the number of bits,
+    logically like lsr, i.e. `>>`, or arithmetically like asr, i.e. `>>>`. Only asr depends on the signedness of the value. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// Signed negative value shifted logically and arithmetically
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![200],
     ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
+    ///     size: 8,
+    ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// let logical = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![50],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    ///     size: 8,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// let arithmetic = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![242],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(a.shift_right(2, false), logical);
+    /// assert_eq!(a.shift_right(2, true), arithmetic);
+    /// assert_eq!(a >> 2, logical);
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    /// Unsigned value, which shifts in zeros either way
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![200],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
-    /// };
+    /// assert_eq!(a.shift_right(2, true), a.shift_right(2, false));
+    /// ```
+    pub fn shift_right(&self, n: usize, arithmetic: bool) -> SvPrimaryLiteralIntegral {
+        if arithmetic {
+            self.asr(n)
+        } else {
+            self.lsr(n)
+        }
+    }
+
+    /** Receives the number of shift positions and shifts the value to the left without changing the number of bits.
+    The dropped bits are shifted in the RHS of the value. */
+    /// # Examples
     ///
-    /// let c = a.lt(b);
+    /// ## 2-State Primary Literals
     ///
-    /// assert_eq!(c, logic1b_0());
-    /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2, 2],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
+    /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2, 2],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
+    ///     data_xz: Some(vec![3, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
+    /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    /// let b: SvPrimaryLiteralIntegral = a.rol(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2, 2],
+    ///     data_xz: Some(vec![0, 2]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Same unsigned value twice but with different widths
+    /// Rotation by more than the width of the value
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![9],
     ///     data_xz: None,
-    ///     size: 63,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// let b: SvPrimaryLiteralIntegral = a.rol(6);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
+    /// assert_eq!(a.rol(4), a);
     /// ```
-    /// Same signed positive value twice but with different widths
+    pub fn rol(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        // Rotating by the width of the value gives the value itself.
+        let n = if ret.size == 0 { 0 } else { n % ret.size };
+
+        for _x in 0..n {
+            let previous_size = ret.size;
+            let leading_one: bool = ret.is_set_msb_01();
+            let leading_one_xz: bool = ret.is_set_msb_xz();
+
+            ret = ret.lsl(1);
+            ret._truncate(previous_size);
+            if leading_one {
+                ret.data_01[0] = ret.data_01[0] + 1;
+            }
+
+            if leading_one_xz {
+                ret.data_xz.as_mut().unwrap()[0] = ret.data_xz.as_ref().unwrap()[0] + 1;
+            }
+        }
+
+        ret
+    }
+
+    /* Receives the number of shift positions and shifts the value to the right without changing the number of bits.
+    The dropped bits are shifted in the LHS of the value. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![9223372036854775809, 3],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16140901064495857664, 1],
+    ///     data_xz: None,
     ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
+    /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 3],
+    ///     data_01: vec![9223372036854775809, 9223372036854775809],
     ///     data_xz: None,
-    ///     size: 66,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6917529027641081856, 6917529027641081856],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775809, 3],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16140901064495857664, 1],
+    ///     data_xz: Some(vec![0, 0]),
     ///     size: 66,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(b, exp);
     /// ```
-    /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
+    /// Value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775809, 9223372036854775809],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6917529027641081856, 6917529027641081856],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// assert_eq!(b, exp);
+    /// ```
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
     /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775809, 3],
+    ///     data_xz: Some(vec![1, 0]),
+    ///     size: 66,
+    ///     signed: true,
+    /// };
     ///
-    /// ## 4-State Primary Literals (No X/Z(s))
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
     ///
-    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16140901064495857664, 1],
+    ///     data_xz: Some(vec![0, 1]),
+    ///     size: 66,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// Value with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775809, 9223372036854775809],
+    ///     data_xz: Some(vec![9223372036854775809, 9223372036854775809]),
+    ///     size: 128,
+    ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// let b: SvPrimaryLiteralIntegral = a.ror(2);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6917529027641081856, 6917529027641081856],
+    ///     data_xz: Some(vec![6917529027641081856, 6917529027641081856]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// ```
+    /// Rotation by more than the width of the value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// let b: SvPrimaryLiteralIntegral = a.ror(6);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(b, exp);
+    /// assert_eq!(a.ror(4), a);
     /// ```
-    /// Value with width = usize::BITS and value with width < usize::BITS
+    pub fn ror(&self, n: usize) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        // Rotating by the width of the value gives the value itself.
+        let n = if ret.size == 0 { 0 } else { n % ret.size };
+        let last_index = ret.data_01.len() - 1;
+        let msb: u32;
+
+        if ret.size % usize::BITS as usize == 0 {
+            msb = usize::BITS;
+        } else {
+            msb = ret.size as u32 % usize::BITS;
+        }
+
+        for _x in 0..n {
+            let trailing_one: bool = ret.data_01[0].trailing_zeros() == 0;
+            let mut trailing_one_xz: bool = false;
+
+            if ret.is_4state() {
+                trailing_one_xz = ret.data_xz.as_ref().unwrap()[0].trailing_zeros() == 0;
+            }
+
+            ret = ret.lsr(1);
+
+            if trailing_one {
+                ret.data_01[last_index] |= 1 << (msb - 1);
+            }
+
+            if trailing_one_xz {
+                ret.data_xz.as_mut().unwrap()[last_index] |= 1 << (msb - 1);
+            }
+        }
+
+        ret
+    }
+
+    /** Receives two integral primary literals, concatenates them (logically shifts left the LHS primlit by RHS primlit's size and adds them).
+    Returns an integral SvPrimaryLiteralIntegral with the final value. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
+    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_01: vec![9223372036854775809, 0],
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_xz: None,
+    ///     size: 63,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_0());
-    /// ```
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13835058055282163712, 4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: true,
+    /// };
     ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// assert_eq!(c, exp);
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// Value with width = usize::BITS and value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
-    ///     signed: false,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 4],
+    ///     data_xz: None,
+    ///     size: 68,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_xz: Some(vec![0]),
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.lt(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
+    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
+    ///     data_01: vec![9223372036854775809, 0],
+    ///     data_xz: Some(vec![0, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
@@ -2978,241 +4588,421 @@ impl SvPrimaryLiteralIntegral {
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 63,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.lt(b);
-    ///
-    /// assert_eq!(c, logic1b_x());
-    /// ```
-    pub fn lt(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu = self.clone();
-
-        if left_nu.contains_xz() || right_nu.contains_xz() {
-            logic1b_x()
-        } else if left_nu.signed != right_nu.signed {
-            left_nu.signed = false;
-            right_nu.signed = false;
-
-            left_nu.lt(right_nu.clone())
-        } else {
-            if left_nu.signed {
-                let left_nu_neg: bool = left_nu.is_set_msb_01();
-                let right_nu_neg: bool = right_nu.is_set_msb_01();
-
-                if left_nu_neg && !right_nu_neg {
-                    logic1b_1()
-                } else if !left_nu_neg && right_nu_neg {
-                    logic1b_0()
-                } else {
-                    if left_nu_neg {
-                        left_nu._matched_sign_extend(&mut right_nu);
-
-                        for x in (0..left_nu.data_01.len()).rev() {
-                            if left_nu.data_01[x] < right_nu.data_01[x] {
-                                return logic1b_1();
-                            }
-                        }
-
-                        logic1b_0()
-                    } else {
-                        left_nu.signed = false;
-                        right_nu.signed = false;
-
-                        left_nu.lt(right_nu.clone())
-                    }
-                }
-            } else {
-                left_nu._matched_zero_extend(&mut right_nu);
-
-                for x in (0..left_nu.data_01.len()).rev() {
-                    if left_nu.data_01[x] < right_nu.data_01[x] {
-                        return logic1b_1();
-                    }
-                }
-
-                logic1b_0()
-            }
-        }
-    }
-
-    /** Emulates the less than or equal operator "<=" as defined in 1800-2017 | 11.4.4 Relational operators */
-    /// # Examples
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// ## 2-State Primary Literals
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13835058055282163712, 4611686018427387904],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
     ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Value with width = usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 4],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 68,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
+    ///     data_xz: Some(vec![9223372036854775808]),
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
+    /// Value with usize::BITS < width < 2 * usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904, 4611686018427387904]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// Value with width = usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
+    ///     data_xz: Some(vec![4611686018427387904]),
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
-    ///     size: 65,
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c: SvPrimaryLiteralIntegral = a.cat(b);
     ///
-    /// assert_eq!(c, logic1b_1());
-    /// ```
-    /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 4],
+    ///     data_xz: Some(vec![0, 4]),
+    ///     size: 68,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: true,
-    /// };
+    /// assert_eq!(c, exp);
+    /// ```
+    /// 2-state value and 4-state value, in either order
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("8'hfd").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("8'bz").unwrap();
     ///
-    /// let c = a.le(b);
+    /// assert_eq!(
+    ///     a.cat(b.clone()),
+    ///     SvPrimaryLiteralIntegral::from_sv_literal("16'b1111_1101_zzzz_zzzz").unwrap()
+    /// );
+    /// assert_eq!(
+    ///     b.cat(a),
+    ///     SvPrimaryLiteralIntegral::from_sv_literal("16'bzzzz_zzzz_1111_1101").unwrap()
+    /// );
+    /// ```
+    pub fn cat(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+
+        if ret.is_4state() != right_nu.is_4state() {
+            if !ret.is_4state() {
+                ret = ret.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        ret = ret.lsl(right_nu.size);
+
+        let mut left_nu: SvPrimaryLiteralIntegral = ret.clone();
+
+        if left_nu.is_4state() || right_nu.is_4state() {
+            let mut left_xz = SvPrimaryLiteralIntegral {
+                data_01: left_nu.data_xz.as_ref().unwrap().clone(),
+                data_xz: None,
+                size: left_nu.size,
+                signed: false,
+            };
+
+            let right_xz = SvPrimaryLiteralIntegral {
+                data_01: right_nu.data_xz.as_ref().unwrap().clone(),
+                data_xz: None,
+                size: right_nu.size,
+                signed: false,
+            };
+
+            left_xz._unsigned_primlit_add(right_xz.clone());
+            left_nu.data_xz = Some(left_xz.data_01.clone());
+        }
+
+        ret._unsigned_primlit_add(right_nu.clone());
+        ret.size = self.size + right_nu.size;
+        ret.data_xz = left_nu.data_xz.clone();
+
+        ret
+    }
+
+    /** Emulates the concatenation operator "{a, b, c}" as defined in 1800-2017 | 11.4.12 Concatenation operators, for any number of values.
+    The first value holds the MSBs and the last value the LSBs. The result is unsigned and has the sum of the widths of the values as its width,
+    which is allocated up front instead of concatenating one value at a time. It is a 4-state value if any of the values is.
+    An empty slice returns a value of zero bits. */
+    /// # Examples
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// Four values of different widths, with X/Z(s)
     /// ```
-    /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'b10x1").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("1'b1").unwrap();
+    /// let c = SvPrimaryLiteralIntegral::from_sv_literal("8'sha5").unwrap();
+    /// let d = SvPrimaryLiteralIntegral::from_sv_literal("3'bz01").unwrap();
+    ///
+    /// let exp = SvPrimaryLiteralIntegral::from_sv_literal("16'b10x1_1_1010_0101_z01").unwrap();
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::cat_many(&[a, b, c, d]), exp);
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// Values with width > usize::BITS across several elements, same as concatenating them one at a time
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![usize::MAX, 3],
     ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: true,
+    ///     size: 66,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// let exp = a.cat(b.clone()).cat(c.clone()).cat(a.clone());
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::cat_many(&[a.clone(), b, c, a]), exp);
+    /// assert_eq!(exp.size, 197);
     /// ```
-    /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
+    /// Empty slice
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 0,
+    ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// assert_eq!(SvPrimaryLiteralIntegral::cat_many(&[]), exp);
+    /// ```
+    pub fn cat_many(parts: &[SvPrimaryLiteralIntegral]) -> SvPrimaryLiteralIntegral {
+        let size: usize = parts.iter().map(|x| x.size).sum();
+        let elmnts: usize = SvPrimaryLiteralIntegral::_elmnts(size);
+
+        let mut ret = SvPrimaryLiteralIntegral {
+            data_01: vec![0; elmnts],
+            data_xz: if parts.iter().any(|x| x.is_4state()) {
+                Some(vec![0; elmnts])
+            } else {
+                None
+            },
+            size,
+            signed: false,
+        };
+
+        // The parts are placed from the LSBs upwards, i.e. starting with the last part
+        let mut offset: usize = 0;
+
+        for part in parts.iter().rev() {
+            SvPrimaryLiteralIntegral::_or_shifted(&mut ret.data_01, &part.data_01, offset);
+
+            if let (Some(ret_xz), Some(part_xz)) = (ret.data_xz.as_mut(), part.data_xz.as_ref()) {
+                SvPrimaryLiteralIntegral::_or_shifted(ret_xz, part_xz, offset);
+            }
+
+            offset += part.size;
+        }
+
+        ret
+    }
+
+    // Sets the bits of data which are set in value shifted left by offset bits, ignoring the bits shifted beyond data.
+    fn _or_shifted(data: &mut [usize], value: &[usize], offset: usize) {
+        let shift: usize = offset % usize::BITS as usize;
+
+        for (x, elmnt) in value.iter().enumerate() {
+            let dst: usize = offset / usize::BITS as usize + x;
+
+            if dst < data.len() {
+                data[dst] |= elmnt << shift;
+            }
+
+            if shift != 0 && dst + 1 < data.len() {
+                data[dst + 1] |= elmnt >> (usize::BITS as usize - shift);
+            }
+        }
+    }
+
+    /** Emulates the replication operator "{count{value}}" as defined in 1800-2017 | 11.4.12.1 Replication operator.
+    The result is unsigned and has count * size bits. A count of zero returns a value of zero bits, which can still be concatenated.
+    The value is repeatedly doubled so that only log2(count) concatenations are needed. */
+    /// # Examples
+    ///
+    /// Value replicated 3 times
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 2,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![42],
+    ///     data_xz: None,
+    ///     size: 6,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(a.replicate(3), exp);
     /// ```
-    /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
+    /// Value with X/Z(s) replicated to usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6148914691236517205, 5],
+    ///     data_xz: Some(vec![12297829382473034410, 10]),
+    ///     size: 68,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.replicate(34), exp);
+    /// ```
+    /// Value replicated 0 times and concatenated
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.replicate(0).size, 0);
+    /// assert_eq!(a.cat(a.replicate(0)), a);
+    /// ```
+    pub fn replicate(&self, count: usize) -> SvPrimaryLiteralIntegral {
+        let mut ret: Option<SvPrimaryLiteralIntegral> = None;
+        let mut doubled: SvPrimaryLiteralIntegral = self.clone();
+        let mut remaining: usize = count;
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                ret = match ret {
+                    Some(x) => Some(x.cat(doubled.clone())),
+                    None => Some(doubled.clone()),
+                };
+            }
+
+            remaining >>= 1;
+            if remaining > 0 {
+                doubled = doubled.cat(doubled.clone());
+            }
+        }
+
+        let mut ret = ret.unwrap_or(SvPrimaryLiteralIntegral {
+            data_01: vec![0],
+            data_xz: if self.is_4state() {
+                Some(vec![0])
+            } else {
+                None
+            },
+            size: 0,
+            signed: false,
+        });
+        ret.signed = false;
+
+        ret
+    }
+
+    /** Emulates the less than operator "<" as defined in 1800-2017 | 11.4.4 Relational operators */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS and unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 3,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Same unsigned value twice but with different widths
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3221,82 +5011,82 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Same signed positive value twice but with different widths
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
-    ///     size: 66,
-    ///     signed: true,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 3],
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
-    ///     size: 66,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
-    ///     size: 66,
-    ///     signed: false,
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
+    /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3305,171 +5095,388 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    ///     size: 63,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
-    ///
-    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 63,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Value with width = usize::BITS and value with width < usize::BITS
+    /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
+    /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
-    ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
-    ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// Same unsigned value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     data_xz: None,
     ///     size: 63,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775808, 3],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.le(b);
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and unsigned value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
+    /// ```
+    /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
+    /// ```
+    /// Value with width = usize::BITS and value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Two unsigned values both with width <= usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
-    pub fn le(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        if self.contains_xz() || right_nu.contains_xz() {
+    /// Two unsigned values both with width <= usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
+    /// ```
+    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.lt(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
+    /// ```
+    pub fn lt(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        self._lt(right_nu)
+    }
+
+    // The comparison without the operand checks, as the other operations also compare their
+    // intermediate values, e.g. _minimum_width via is_negative, which need not validate.
+    fn _lt(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut left_nu = self.clone();
+
+        if left_nu.contains_xz() || right_nu.contains_xz() {
             logic1b_x()
+        } else if left_nu.signed != right_nu.signed {
+            left_nu.signed = false;
+            right_nu.signed = false;
+
+            left_nu._lt(right_nu.clone())
         } else {
-            let lt = self.lt(right_nu.clone());
-            let logical_eq = self.logical_eq(right_nu.clone());
+            if left_nu.signed {
+                let left_nu_neg: bool = left_nu.is_set_msb_01();
+                let right_nu_neg: bool = right_nu.is_set_msb_01();
 
-            if lt == logic1b_1() || logical_eq == logic1b_1() {
-                return logic1b_1();
-            }
+                if left_nu_neg && !right_nu_neg {
+                    logic1b_1()
+                } else if !left_nu_neg && right_nu_neg {
+                    logic1b_0()
+                } else {
+                    if left_nu_neg {
+                        left_nu._matched_sign_extend(&mut right_nu);
 
-            logic1b_0()
+                        for x in (0..left_nu.data_01.len()).rev() {
+                            if left_nu.data_01[x] < right_nu.data_01[x] {
+                                return logic1b_1();
+                            } else if left_nu.data_01[x] > right_nu.data_01[x] {
+                                return logic1b_0();
+                            }
+                        }
+
+                        logic1b_0()
+                    } else {
+                        left_nu.signed = false;
+                        right_nu.signed = false;
+
+                        left_nu._lt(right_nu.clone())
+                    }
+                }
+            } else {
+                left_nu._matched_zero_extend(&mut right_nu);
+
+                for x in (0..left_nu.data_01.len()).rev() {
+                    if left_nu.data_01[x] < right_nu.data_01[x] {
+                        return logic1b_1();
+                    } else if left_nu.data_01[x] > right_nu.data_01[x] {
+                        return logic1b_0();
+                    }
+                }
+
+                logic1b_0()
+            }
         }
     }
 
-    /** Emulates the greater than operator ">" as defined in 1800-2017 | 11.4.4 Relational operators */
+    /** Emulates the less than or equal operator "<=" as defined in 1800-2017 | 11.4.4 Relational operators */
     /// # Examples
     ///
     /// ## 2-State Primary Literals
     ///
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3484,13 +5491,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3505,13 +5512,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -3526,13 +5533,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3547,13 +5554,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3568,13 +5575,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3589,13 +5596,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3610,13 +5617,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3631,13 +5638,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Same unsigned value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3652,13 +5659,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -3673,13 +5680,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 3],
     ///     data_xz: None,
@@ -3694,13 +5701,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and unsigned value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -3709,19 +5716,19 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
     ///     size: 66,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3736,16 +5743,16 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     ///
     /// ## 4-State Primary Literals (No X/Z(s))
     ///
     /// Value with width < usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -3760,13 +5767,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Value with width = usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
@@ -3781,13 +5788,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -3802,16 +5809,16 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     ///
     /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -3826,13 +5833,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -3847,13 +5854,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
     /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -3868,62 +5875,54 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.gt(b);
+    /// let c = a.le(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
-    pub fn gt(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu = self.clone();
-
-        if left_nu.contains_xz() || right_nu.contains_xz() {
+    pub fn le(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() || right_nu.contains_xz() {
             logic1b_x()
-        } else if left_nu.signed != right_nu.signed {
-            left_nu.signed = false;
-            right_nu.signed = false;
-
-            left_nu.gt(right_nu.clone())
         } else {
-            if left_nu.signed {
-                let left_nu_neg: bool = left_nu.is_set_msb_01();
-                let right_nu_neg: bool = right_nu.is_set_msb_01();
-
-                if left_nu_neg && !right_nu_neg {
-                    logic1b_0()
-                } else if !left_nu_neg && right_nu_neg {
-                    logic1b_1()
-                } else {
-                    left_nu._matched_sign_extend(&mut right_nu);
-
-                    for x in (0..left_nu.data_01.len()).rev() {
-                        if left_nu.data_01[x] > right_nu.data_01[x] {
-                            return logic1b_1();
-                        }
-                    }
-
-                    logic1b_0()
-                }
-            } else {
-                left_nu._matched_zero_extend(&mut right_nu);
-
-                for x in (0..left_nu.data_01.len()).rev() {
-                    if left_nu.data_01[x] > right_nu.data_01[x] {
-                        return logic1b_1();
-                    }
-                }
+            let lt = self._lt(right_nu.clone());
+            let logical_eq = self._logical_eq(right_nu.clone());
 
-                logic1b_0()
+            if lt == logic1b_1() || logical_eq == logic1b_1() {
+                return logic1b_1();
             }
+
+            logic1b_0()
         }
     }
 
-    /** Emulates the greater than or equal operator ">=" as defined in 1800-2017 | 11.4.4 Relational operators */
+    /** Emulates the greater than operator ">" as defined in 1800-2017 | 11.4.4 Relational operators */
     /// # Examples
     ///
     /// ## 2-State Primary Literals
     ///
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS and unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.gt(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
+    /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -3938,13 +5937,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -3959,13 +5958,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -3980,13 +5979,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4001,13 +6000,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4022,13 +6021,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4043,13 +6042,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4064,13 +6063,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4085,13 +6084,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
     /// Same unsigned value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4106,13 +6105,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -4127,13 +6126,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 3],
     ///     data_xz: None,
@@ -4148,13 +6147,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and unsigned value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -4163,19 +6162,19 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
     ///     size: 66,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4190,16 +6189,16 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     ///
     /// ## 4-State Primary Literals (No X/Z(s))
     ///
     /// Value with width < usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
@@ -4214,13 +6213,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_0());
     /// ```
     /// Value with width = usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
@@ -4235,13 +6234,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -4256,7 +6255,7 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_1());
     /// ```
@@ -4265,7 +6264,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -4280,13 +6279,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
@@ -4301,13 +6300,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
     /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -4322,33 +6321,74 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.ge(b);
+    /// let c = a.gt(b);
     ///
     /// assert_eq!(c, logic1b_x());
     /// ```
-    pub fn ge(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        if self.contains_xz() || right_nu.contains_xz() {
+    pub fn gt(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        self._gt(right_nu)
+    }
+
+    // gt without the operand checks, see _lt.
+    fn _gt(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut left_nu = self.clone();
+
+        if left_nu.contains_xz() || right_nu.contains_xz() {
             logic1b_x()
+        } else if left_nu.signed != right_nu.signed {
+            left_nu.signed = false;
+            right_nu.signed = false;
+
+            left_nu._gt(right_nu.clone())
         } else {
-            let gt = self.gt(right_nu.clone());
-            let logical_eq = self.logical_eq(right_nu.clone());
+            if left_nu.signed {
+                let left_nu_neg: bool = left_nu.is_set_msb_01();
+                let right_nu_neg: bool = right_nu.is_set_msb_01();
 
-            if gt == logic1b_1() || logical_eq == logic1b_1() {
-                return logic1b_1();
-            }
+                if left_nu_neg && !right_nu_neg {
+                    logic1b_0()
+                } else if !left_nu_neg && right_nu_neg {
+                    logic1b_1()
+                } else {
+                    left_nu._matched_sign_extend(&mut right_nu);
 
-            logic1b_0()
+                    for x in (0..left_nu.data_01.len()).rev() {
+                        if left_nu.data_01[x] > right_nu.data_01[x] {
+                            return logic1b_1();
+                        } else if left_nu.data_01[x] < right_nu.data_01[x] {
+                            return logic1b_0();
+                        }
+                    }
+
+                    logic1b_0()
+                }
+            } else {
+                left_nu._matched_zero_extend(&mut right_nu);
+
+                for x in (0..left_nu.data_01.len()).rev() {
+                    if left_nu.data_01[x] > right_nu.data_01[x] {
+                        return logic1b_1();
+                    } else if left_nu.data_01[x] < right_nu.data_01[x] {
+                        return logic1b_0();
+                    }
+                }
+
+                logic1b_0()
+            }
         }
     }
 
-    /** Emulates the case equality operator "===" as defined in 1800-2017 | 11.4.5 Equality operators */
+    /** Emulates the greater than or equal operator ">=" as defined in 1800-2017 | 11.4.4 Relational operators */
     /// # Examples
     ///
     /// ## 2-State Primary Literals
     ///
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4363,13 +6403,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4384,13 +6424,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -4405,13 +6445,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4426,13 +6466,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4447,13 +6487,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4468,13 +6508,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4489,13 +6529,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_0());
     /// ```
     /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4510,13 +6550,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Same unsigned value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -4531,13 +6571,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -4552,13 +6592,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 3],
     ///     data_xz: None,
@@ -4573,13 +6613,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and unsigned value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -4594,13 +6634,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -4615,20 +6655,20 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     ///
     /// ## 4-State Primary Literals (No X/Z(s))
     ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// Value with width < usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
@@ -4639,34 +6679,34 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// Value with width = usize::BITS and value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![0, 0]),
@@ -4675,64 +6715,64 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_1());
     /// ```
     ///
     /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
-    /// Two signed values both with width = usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![9223372036854775808]),
     ///     size: 64,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_x());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Two signed values with usize::BITS < width < 2 * usize::BITS
+    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: Some(vec![9223372036854775808, 0]),
@@ -4741,424 +6781,482 @@ impl SvPrimaryLiteralIntegral {
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let c = a.ge(b);
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Two signed values with usize::BITS < width < 2 * usize::BITS
+    pub fn ge(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() || right_nu.contains_xz() {
+            logic1b_x()
+        } else {
+            let gt = self._gt(right_nu.clone());
+            let logical_eq = self._logical_eq(right_nu.clone());
+
+            if gt == logic1b_1() || logical_eq == logic1b_1() {
+                return logic1b_1();
+            }
+
+            logic1b_0()
+        }
+    }
+
+    /** Returns the minimum of two integral primary literals, as decided by lt.
+    The result has the width of the widest operand and is only signed if both operands are signed.
+    If any of the operands contains X/Z(s), all the bits of the result are X. */
+    /// # Examples
+    ///
+    /// Signed negative value and signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(a.min_primlit(b), exp);
     /// ```
-    /// Two signed values both with width <= usize::BITS
+    /// Signed negative value and unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(a.min_primlit(b), exp);
     /// ```
-    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
+    /// Signed negative value with a smaller width and signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 1]),
-    ///     size: 65,
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![254],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(a.min_primlit(b), exp);
     /// ```
-    /// Two signed values both with width <= usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
+    ///     data_01: vec![18446744073709551615, 1],
+    ///     data_xz: None,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![1],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, bit1b_0());
+    /// assert_eq!(a.min_primlit(b), exp);
     /// ```
-    /// Signed value with width = usize::BITS and signed values with usize::BITS < width < 2 * usize::BITS
+    /// Unsigned value containing X/Z(s) and signed value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![9223372036854775808, 1]),
-    ///     size: 65,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.case_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![255]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, bit1b_1());
+    /// assert_eq!(a.min_primlit(b), exp);
     /// ```
-    pub fn case_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu = self.clone();
-        if left_nu.signed != right_nu.signed {
-            left_nu.signed = false;
-            right_nu.signed = false;
-
-            left_nu.case_eq(right_nu.clone())
-        } else if left_nu.contains_xz() != right_nu.contains_xz() {
-            bit1b_0()
-        } else if left_nu.contains_xz() && right_nu.contains_xz() {
-            if left_nu.signed {
-                left_nu._matched_sign_extend(&mut right_nu);
-            } else {
-                left_nu._matched_zero_extend(&mut right_nu);
-            }
-
-            let data_01 = left_nu.data_01 == right_nu.data_01;
-            let data_xz = left_nu.data_xz.as_ref().unwrap() == right_nu.data_xz.as_ref().unwrap();
-
-            if data_01 && data_xz {
-                return bit1b_1();
-            }
-            bit1b_0()
-        } else {
-            if left_nu.signed {
-                left_nu._matched_sign_extend(&mut right_nu);
-            } else {
-                left_nu._matched_zero_extend(&mut right_nu);
-            }
-
-            if left_nu.data_01 == right_nu.data_01 {
-                return bit1b_1();
-            }
-
-            bit1b_0()
-        }
+    pub fn min_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self._select_primlit(right_nu, SvPrimaryLiteralIntegral::lt)
     }
 
-    /** Emulates the logical equality operator "==" as defined in 1800-2017 | 11.4.5 Equality operators */
+    /** Returns the maximum of two integral primary literals, as decided by gt.
+    The result has the width of the widest operand and is only signed if both operands are signed.
+    If any of the operands contains X/Z(s), all the bits of the result are X. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// Signed negative value and signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![255],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![3],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(a.max_primlit(b), exp);
     /// ```
-    /// Two signed values both with width = usize::BITS
+    /// Signed negative value and unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![255],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![3],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(a.max_primlit(b), exp);
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    /// Signed negative value with a smaller width and signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![14],
     ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    ///     size: 8,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(a.max_primlit(b), exp);
     /// ```
-    /// Same signed positive value twice but with different widths
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![18446744073709551615, 1],
     ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![1],
     ///     data_xz: None,
-    ///     size: 66,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
-    ///
-    /// assert_eq!(c, logic1b_1());
-    /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
     ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// assert_eq!(a.max_primlit(b), exp);
+    /// ```
+    /// Unsigned value containing X/Z(s) and signed value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![255]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(a.max_primlit(b), exp);
     /// ```
-    /// Value with width < usize::BITS and value with width = usize::BITS
+    pub fn max_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self._select_primlit(right_nu, SvPrimaryLiteralIntegral::gt)
+    }
+
+    /// Returns the left operand if the comparison holds and the right operand otherwise, matched to the width and sign of the result.
+    fn _select_primlit(
+        &self,
+        mut right_nu: SvPrimaryLiteralIntegral,
+        op: fn(&SvPrimaryLiteralIntegral, SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral,
+    ) -> SvPrimaryLiteralIntegral {
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+
+        if left_nu.is_4state() != right_nu.is_4state() {
+            if !left_nu.is_4state() {
+                left_nu = left_nu.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        let final_num_bits: usize = std::cmp::max(left_nu.size, right_nu.size);
+        let signed: bool = left_nu.signed && right_nu.signed;
+
+        if left_nu.contains_xz() || right_nu.contains_xz() {
+            return logic_x(final_num_bits, signed);
+        }
+
+        let pick_left: bool = op(&left_nu, right_nu.clone()).data_01[0] == 1;
+
+        if signed {
+            left_nu._matched_sign_extend(&mut right_nu);
+        } else {
+            left_nu.signed = false;
+            right_nu.signed = false;
+            left_nu._matched_zero_extend(&mut right_nu);
+        }
+
+        let mut ret: SvPrimaryLiteralIntegral = if pick_left { left_nu } else { right_nu };
+        ret._truncate(final_num_bits);
+
+        ret
+    }
+
+    /** Emulates the case equality operator "===" as defined in 1800-2017 | 11.4.5 Equality operators */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 63,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
-    ///     signed: true,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
-    ///
-    /// Two signed values both with width = usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    /// Signed positive value with width = usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Two signed values with usize::BITS < width < 2 * usize::BITS
+    /// Signed negative value with width = usize::BITS and signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Two signed values with usize::BITS < width < 2 * usize::BITS
+    /// Signed negative value with width < usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775808, 0]),
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.logical_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    pub fn logical_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu = self.clone();
-
-        if left_nu.contains_xz() || right_nu.contains_xz() {
-            logic1b_x()
-        } else if left_nu.signed != right_nu.signed {
-            left_nu.signed = false;
-            right_nu.signed = false;
-
-            left_nu.logical_eq(right_nu.clone())
-        } else {
-            left_nu.case_eq(right_nu.clone()).to_4state()
-        }
-    }
-
-    /** Emulates the wildcard equality operator "==?" as defined in 1800-2017 | 11.4.6 Wildcard equality operators */
-    /// # Examples
-    ///
-    /// ## 2-State Primary Literals
-    ///
-    /// Two unsigned values both with width <= usize::BITS
+    /// Signed negative value with width = usize::BITS and signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -5173,13 +7271,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Two signed values both with width = usize::BITS
+    /// Signed positive value with width = usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -5194,13 +7292,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, bit1b_0());
     /// ```
-    /// Two unsigned values both with width <= usize::BITS
+    /// Same unsigned value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
@@ -5215,13 +7313,13 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_1());
     /// ```
     /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
@@ -5236,82 +7334,145 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_1());
     /// ```
-    ///
-    /// ## 4-State Primary Literals (No X/Z(s))
-    ///
-    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS and signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 3],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_0());
+    /// assert_eq!(c, bit1b_1());
     /// ```
-    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: false,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_1());
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// Signed negative value with = usize::BITS and unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_1());
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_0());
+    /// ```
+    /// Value with width < usize::BITS and value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_1());
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
     ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_1());
     /// ```
     ///
     /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
     /// Two signed values both with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![4611686018427387904]),
@@ -5326,16 +7487,16 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_0());
     /// ```
     /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: Some(vec![4611686018427387904]),
     ///     size: 63,
     ///     signed: false,
     /// };
@@ -5347,1505 +7508,1578 @@ impl SvPrimaryLiteralIntegral {
     ///     signed: false,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_1());
     /// ```
     /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![1, 0]),
+    ///     data_xz: Some(vec![0, 0]),
     ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_1());
+    /// assert_eq!(c, bit1b_0());
     /// ```
     /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775809, 0]),
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![9223372036854775809, 1]),
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let c = a.wildcard_eq(b);
+    /// let c = a.case_eq(b);
     ///
-    /// assert_eq!(c, logic1b_x());
+    /// assert_eq!(c, bit1b_1());
     /// ```
-    pub fn wildcard_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu = self.clone();
+    /// Two signed values both with width <= usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_0());
+    /// ```
+    /// Signed value with usize::BITS < width < 2 * usize::BITS and signed value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_1());
+    /// ```
+    /// Two signed values both with width <= usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_0());
+    /// ```
+    /// Signed value with width = usize::BITS and signed values with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c = a.case_eq(b);
+    ///
+    /// assert_eq!(c, bit1b_1());
+    /// ```
+    pub fn case_eq(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        self._case_eq(right_nu)
+    }
 
+    // case_eq without the operand checks, see _lt.
+    fn _case_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut left_nu = self.clone();
         if left_nu.signed != right_nu.signed {
             left_nu.signed = false;
             right_nu.signed = false;
 
-            left_nu.wildcard_eq(right_nu.clone())
-        } else if !right_nu.contains_xz() {
-            left_nu.logical_eq(right_nu.clone())
+            left_nu._case_eq(right_nu.clone())
+        } else if left_nu.contains_xz() != right_nu.contains_xz() {
+            bit1b_0()
+        } else if left_nu.contains_xz() && right_nu.contains_xz() {
+            if left_nu.signed {
+                left_nu._matched_sign_extend(&mut right_nu);
+            } else {
+                left_nu._matched_zero_extend(&mut right_nu);
+            }
+
+            let data_01 = left_nu.data_01 == right_nu.data_01;
+            let data_xz = left_nu.data_xz.as_ref().unwrap() == right_nu.data_xz.as_ref().unwrap();
+
+            if data_01 && data_xz {
+                return bit1b_1();
+            }
+            bit1b_0()
         } else {
             if left_nu.signed {
                 left_nu._matched_sign_extend(&mut right_nu);
             } else {
                 left_nu._matched_zero_extend(&mut right_nu);
             }
-            let last_index = right_nu.data_01.len() - 1;
-            for _x in 0..left_nu.size {
-                let left_msb_x: bool = !left_nu.is_set_msb_01() && left_nu.is_set_msb_xz();
-                let left_msb_z: bool = left_nu.is_set_msb_01() && left_nu.is_set_msb_xz();
-                let left_msb_0: bool = !left_nu.is_set_msb_01() && !left_nu.is_set_msb_xz();
-                let left_msb_1: bool = left_nu.is_set_msb_01() && !left_nu.is_set_msb_xz();
-
-                let right_msb_x: bool = !right_nu.is_set_msb_01() && right_nu.is_set_msb_xz();
-                let right_msb_z: bool = right_nu.is_set_msb_01() && right_nu.is_set_msb_xz();
-
-                if right_msb_x {
-                    if left_msb_z {
-                        right_nu.data_01[last_index] =
-                            right_nu.data_01[last_index] + 2usize.pow(usize::BITS - 1);
-                    } else if left_msb_1 {
-                        right_nu.data_01[last_index] =
-                            right_nu.data_01[last_index] + 2usize.pow(usize::BITS - 1);
-                        right_nu.data_xz.as_mut().unwrap()[last_index] =
-                            right_nu.data_xz.as_ref().unwrap()[last_index]
-                                - 2usize.pow(usize::BITS - 1);
-                    } else if left_msb_0 {
-                        right_nu.data_xz.as_mut().unwrap()[last_index] =
-                            right_nu.data_xz.as_ref().unwrap()[last_index]
-                                - 2usize.pow(usize::BITS - 1);
-                    }
-                } else if right_msb_z {
-                    if left_msb_x {
-                        right_nu.data_01[last_index] =
-                            right_nu.data_01[last_index] - 2usize.pow(usize::BITS - 1);
-                    } else if left_msb_1 {
-                        right_nu.data_xz.as_mut().unwrap()[last_index] =
-                            right_nu.data_xz.as_ref().unwrap()[last_index]
-                                - 2usize.pow(usize::BITS - 1);
-                    } else if left_msb_0 {
-                        right_nu.data_01[last_index] =
-                            right_nu.data_01[last_index] - 2usize.pow(usize::BITS - 1);
-                        right_nu.data_xz.as_mut().unwrap()[last_index] =
-                            right_nu.data_xz.as_ref().unwrap()[last_index]
-                                - 2usize.pow(usize::BITS - 1);
-                    }
-                }
 
-                left_nu = left_nu.rol(1);
-                right_nu = right_nu.rol(1);
+            if left_nu.data_01 == right_nu.data_01 {
+                return bit1b_1();
             }
 
-            left_nu.logical_eq(right_nu)
+            bit1b_0()
         }
     }
 
-    /** Receives a signed or unsigned integral primary literal and deduces an equivalent representation with the minimum number of bits required.
-    The correct final number of bits is set to the argument. */
+    /** Emulates the logical equality operator "==" as defined in 1800-2017 | 11.4.5 Equality operators */
     /// # Examples
     ///
     /// ## 2-State Primary Literals
     ///
-    /// Signed negative value with width < usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![65533],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
-    ///     size: 16,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![5],
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
-    ///     size: 3,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed negative value with width = usize::BITS
+    /// Two signed values both with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
+    /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: None,
     ///     size: 64,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed positive value with width = 2 * usize::BITS
+    /// Same signed positive value twice but with different widths
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
-    ///     size: 128,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
     ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed value = 0 with width = 2 * usize::BITS
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: None,
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: None,
-    ///     size: 1,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Unsigned value with width = 2 * usize::BITS
+    /// Value with width < usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 0],
-    ///     data_xz: None,
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 2,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Unsigned value = 0 with width = 2 * usize::BITS
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: None,
-    ///     size: 1,
-    ///     signed: false,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 66,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
     ///
-    /// ## 4-State Primary Literals (No X/Z(s))
+    /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
-    /// Signed negative value with width < usize::BITS
+    /// Two signed values both with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![65533],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 16,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![5],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Signed negative value with width = usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
     ///     size: 64,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
+    /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Signed positive value with width = 2 * usize::BITS
+    /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 66,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.logical_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Signed value = 0 with width = 2 * usize::BITS
+    pub fn logical_eq(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        self._logical_eq(right_nu)
+    }
+
+    // logical_eq without the operand checks, see _lt.
+    fn _logical_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut left_nu = self.clone();
+
+        if left_nu.contains_xz() || right_nu.contains_xz() {
+            logic1b_x()
+        } else if left_nu.signed != right_nu.signed {
+            left_nu.signed = false;
+            right_nu.signed = false;
+
+            left_nu._logical_eq(right_nu.clone())
+        } else {
+            left_nu._case_eq(right_nu.clone()).to_4state()
+        }
+    }
+
+    /** Emulates the wildcard equality operator "==?" as defined in 1800-2017 | 11.4.6 Wildcard equality operators */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 1,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Unsigned value with width = 2 * usize::BITS
+    /// Two signed values both with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 2,
-    ///     signed: false,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Unsigned value = 0 with width = 2 * usize::BITS
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// a._minimum_width();
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 1,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
-    /// ```
-    pub fn _minimum_width(&mut self) {
-        if !self.signed {
-            if self.is_zero() {
-                for _x in 0..self.data_01.len() {
-                    let last_index = self.data_01.len() - 1;
-                    self.data_01.remove(last_index);
-                }
-                self.data_01.push(0);
-                self.size = 1;
-            } else {
-                for _x in 0..self.data_01.len() {
-                    let last_index = self.data_01.len() - 1;
-                    if self.data_01[last_index] == 0 {
-                        self.data_01.remove(last_index);
-                    }
-                }
-
-                self.size = (usize::BITS as usize
-                    - self.data_01[self.data_01.len() - 1].leading_zeros() as usize)
-                    + (self.data_01.len() - 1) * usize::BITS as usize;
-            }
-        } else {
-            let mut min_num_found: bool = false;
-            let mut vec_elements_to_rm: usize = 0;
-
-            if self.is_negative() {
-                for x in (0..self.data_01.len()).rev() {
-                    while !min_num_found {
-                        let pre_leading = self.data_01[x].leading_zeros();
-
-                        let minimized_value: usize =
-                            self.data_01[x] - 2usize.pow(usize::BITS - pre_leading - 1);
-                        let post_leading = minimized_value.leading_zeros();
-
-                        if post_leading == usize::BITS {
-                            if x == 0 || self.data_01[x - 1].leading_zeros() != 0 {
-                                min_num_found = true;
-                                break;
-                            }
-                        }
-
-                        if post_leading != (pre_leading + 1) {
-                            min_num_found = true;
-                            break;
-                        } else {
-                            self.data_01[x] = minimized_value;
-                            self.size = self.size - 1;
-
-                            if post_leading == usize::BITS {
-                                vec_elements_to_rm = vec_elements_to_rm + 1;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                for _x in 0..vec_elements_to_rm {
-                    let last_index = self.data_01.len() - 1;
-                    self.data_01.remove(last_index);
-                }
-            } else if self.is_zero() {
-                for _x in 0..self.data_01.len() {
-                    let last_index = self.data_01.len() - 1;
-                    self.data_01.remove(last_index);
-                }
-                self.data_01.push(0);
-                self.size = 1;
-            } else {
-                for _x in 0..self.data_01.len() {
-                    let last_index = self.data_01.len() - 1;
-                    if self.data_01[last_index] == 0 {
-                        self.data_01.remove(last_index);
-                    }
-                }
-
-                let last_index = self.data_01.len() - 1;
-                if self.data_01[last_index].leading_zeros() == 0 {
-                    self.data_01.push(0);
-                }
-
-                self.size = (usize::BITS as usize
-                    - self.data_01[self.data_01.len() - 1].leading_zeros() as usize
-                    + 1)
-                    + (self.data_01.len() - 1) * usize::BITS as usize;
-            }
-        }
-
-        if self.is_4state() && (self.data_01.len() < self.data_xz.as_ref().unwrap().len()) {
-            for _x in 0..(self.data_xz.as_ref().unwrap().len() - self.data_01.len()) {
-                let last_index = self.data_01.len() - 1;
-                self.data_xz.as_mut().unwrap().remove(last_index);
-            }
-        }
-    }
-
-    /** Receives the number of bits in which an integral primary literal should be truncated.
-    The correct final number of bits is set but the signedness doesn't change. */
-    /// # Examples
-    ///
-    /// ## 2-State Primary Literals
+    /// let c = a.wildcard_eq(b);
     ///
-    /// Signed negative value with width = usize::BITS truncated to 64 bits
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    /// Same signed positive value twice but with different widths
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
-    ///     size: 128,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._truncate(64);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed negative value with width = usize::BITS truncated to 5 bits
+    ///
+    /// ## 4-State Primary Literals (No X/Z(s))
+    ///
+    /// Value with width = usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387905, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._truncate(5);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: None,
-    ///     size: 5,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    /// Unsigned value with width = usize::BITS truncated to 69 bits
+    /// Value with width < usize::BITS and value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775809],
-    ///     data_xz: None,
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// a._truncate(69);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: None,
-    ///     size: 69,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Unsigned value with width = usize::BITS truncated to 1 bit
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS and signed positive value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 0],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
-    /// a._truncate(1);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: None,
-    ///     size: 1,
-    ///     signed: false,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 66,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
     ///
-    /// ## 4-State Primary Literals (No X/Z(s))
+    /// ## 4-State Primary Literals (Containing X/Z(s))
     ///
-    /// Signed negative value with width = usize::BITS truncated to 64 bits
+    /// Two signed values both with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// a._truncate(64);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
+    /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: Some(vec![9223372036854775808]),
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
-    /// ```
-    /// Signed negative value with width = usize::BITS truncated to 5 bits
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387905, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: true,
-    /// };
-    ///
-    /// a._truncate(5);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
-    ///     signed: true,
-    /// };
+    /// let c = a.wildcard_eq(b);
     ///
-    /// assert_eq!(a, exp);
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Unsigned value with width = usize::BITS truncated to 69 bits
+    /// Two unsigned values both with width <= usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775809],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
-    ///     signed: false,
-    /// };
-    ///
-    /// a._truncate(69);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 69,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
-    /// ```
-    /// Unsigned value with width = usize::BITS truncated to 1 bit
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 128,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
-    /// a._truncate(1);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 1,
-    ///     signed: false,
-    /// };
+    /// let c = a.wildcard_eq(b);
     ///
-    /// assert_eq!(a, exp);
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    ///
-    /// ## 4-State Primary Literals (Containing X/Z(s))
-    ///
-    /// Signed value with width = usize::BITS truncated to 64 bits
+    /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._truncate(64);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
-    ///     size: 64,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![1, 0]),
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Signed value with width = usize::BITS truncated to 5 bits
+    /// Two signed values with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387905, 9223372036854775808],
-    ///     data_xz: Some(vec![4611686018427387905, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775809, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// a._truncate(5);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: Some(vec![1]),
-    ///     size: 5,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![9223372036854775809, 1]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_1());
     /// ```
-    /// Unsigned value with width = usize::BITS truncated to 69 bits
+    /// X in the left value that is not masked by the right value
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775809],
-    ///     data_xz: Some(vec![0, 9223372036854775809]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// a._truncate(69);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 1]),
-    ///     size: 69,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_x());
     /// ```
-    /// Unsigned value with width = usize::BITS truncated to 1 bit
+    /// X in the left value against a 2-state right value, with a definite mismatch in another bit
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let mut a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 0],
-    ///     data_xz: Some(vec![1, 0]),
-    ///     size: 128,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// a._truncate(1);
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1],
-    ///     data_xz: Some(vec![1]),
-    ///     size: 1,
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(a, exp);
+    /// let c = a.wildcard_eq(b);
+    ///
+    /// assert_eq!(c, logic1b_0());
     /// ```
-    pub fn _truncate(&mut self, size: usize) {
-        if size == 0 {
-            panic!("Cannot truncate the value to zero bits!");
-        } else if self.size >= size {
-            let elmnts_to_be_rm: usize;
-            let bits_to_be_rm: usize;
-
-            if (size % usize::BITS as usize) == 0 {
-                elmnts_to_be_rm = self.data_01.len() - size / usize::BITS as usize;
-                bits_to_be_rm = 0;
-            } else {
-                elmnts_to_be_rm = self.data_01.len() - (size / usize::BITS as usize) - 1;
-                bits_to_be_rm = usize::BITS as usize - size % usize::BITS as usize;
-            }
+    pub fn wildcard_eq(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut left_nu = self.clone();
 
-            for _x in 0..elmnts_to_be_rm {
-                let last_index = self.data_01.len() - 1;
-                self.data_01.remove(last_index);
-            }
+        if left_nu.signed != right_nu.signed {
+            left_nu.signed = false;
+            right_nu.signed = false;
 
-            if bits_to_be_rm != 0 {
-                let last_index = self.data_01.len() - 1;
-                for x in
-                    ((usize::BITS as usize - bits_to_be_rm + 1)..(usize::BITS as usize + 1)).rev()
-                {
-                    if self.data_01[last_index].leading_zeros() == (usize::BITS - x as u32) {
-                        self.data_01[last_index] =
-                            self.data_01[last_index] - 2usize.pow(x as u32 - 1);
-                    }
-                }
-            }
+            return left_nu.wildcard_eq(right_nu.clone());
+        }
 
-            if self.is_4state() {
-                let elmnts_to_be_rm: usize;
-                let bits_to_be_rm: usize;
+        if left_nu.signed {
+            left_nu._matched_sign_extend(&mut right_nu);
+        } else {
+            left_nu._matched_zero_extend(&mut right_nu);
+        }
 
-                if (size % usize::BITS as usize) == 0 {
-                    elmnts_to_be_rm =
-                        self.data_xz.as_ref().unwrap().len() - size / usize::BITS as usize;
-                    bits_to_be_rm = 0;
-                } else {
-                    elmnts_to_be_rm =
-                        self.data_xz.as_ref().unwrap().len() - (size / usize::BITS as usize) - 1;
-                    bits_to_be_rm = usize::BITS as usize - size % usize::BITS as usize;
+        // X/Z bits of the right operand are wildcards that match any bit of the left operand.
+        // X/Z bits of the left operand are not, so comparing them against a 0/1 of the right
+        // operand is ambiguous and gives X, unless another bit is a definite mismatch.
+        let mut mismatch = false;
+        let mut ambiguous = false;
+
+        for x in 0..left_nu.data_01.len() {
+            let mut mask = if x == left_nu.data_01.len() - 1 {
+                match left_nu.size % usize::BITS as usize {
+                    0 => usize::MAX,
+                    bits => (1 << bits) - 1,
                 }
+            } else {
+                usize::MAX
+            };
 
-                for _x in 0..elmnts_to_be_rm {
-                    let last_index = self.data_xz.as_ref().unwrap().len() - 1;
-                    self.data_xz.as_mut().unwrap().remove(last_index);
-                }
+            let left_xz = left_nu.data_xz.as_ref().map_or(0, |data_xz| data_xz[x]);
+            let right_xz = right_nu.data_xz.as_ref().map_or(0, |data_xz| data_xz[x]);
+            mask &= !right_xz;
 
-                if bits_to_be_rm != 0 {
-                    let last_index = self.data_xz.as_ref().unwrap().len() - 1;
-                    for x in ((usize::BITS as usize - bits_to_be_rm + 1)
-                        ..(usize::BITS as usize + 1))
-                        .rev()
-                    {
-                        if self.data_xz.as_ref().unwrap()[last_index].leading_zeros()
-                            == (usize::BITS - x as u32)
-                        {
-                            self.data_xz.as_mut().unwrap()[last_index] =
-                                self.data_xz.as_ref().unwrap()[last_index]
-                                    - 2usize.pow(x as u32 - 1);
-                        }
-                    }
-                }
-            }
+            mismatch |= (left_nu.data_01[x] ^ right_nu.data_01[x]) & !left_xz & mask != 0;
+            ambiguous |= left_xz & mask != 0;
+        }
 
-            self.size = size;
+        if mismatch {
+            logic1b_0()
+        } else if ambiguous {
+            logic1b_x()
         } else {
-            panic!("The original number of bits is smaller than the requested one!");
+            logic1b_1()
         }
     }
 
+    /** Receives a signed or unsigned integral primary literal and deduces an equivalent representation with the minimum number of bits required.
+    The correct final number of bits is set to the argument. */
     /// # Examples
     ///
-    /// ## 2-State Primary Literals - Signed Addition
+    /// ## 2-State Primary Literals
     ///
-    /// Signed negative value with width = usize::BITS added with itself
+    /// Signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![65533],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 16,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 3,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed negative value with width = 2 * usize::BITS added with a signed negative value with width = usize::BITS
+    /// Signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 9223372036854775808, 1],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
-    ///     size: 129,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed negative value with width < usize::BITS added with a signed positive value with width = usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
-    /// ```
-    /// Signed negative value with width = usize::BITS added with a signed positive value with width = usize::BITS
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![13835058055282163712, 1],
-    ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
-    /// };
-    ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS added with a signed positive value with width = usize::BITS
+    /// Signed positive value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = 2 * usize::BITS added with a signed positive value with width = usize::BITS
+    /// Signed value = 0 with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904, 4611686018427387904],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
     ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 4611686018427387904, 0],
+    ///     data_01: vec![0],
     ///     data_xz: None,
-    ///     size: 129,
+    ///     size: 1,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    ///
-    /// ## 2-State Primary Literals - Signed Unsigned Addition
-    ///
-    /// Signed negative value with width = usize::BITS added with an unsigned value with width = usize::BITS
+    /// Unsigned value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
     ///     data_xz: None,
-    ///     size: 64,
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
+    ///     data_01: vec![3],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 2,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS added with an unsigned value with width < usize::BITS
+    /// Unsigned value = 0 with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
     ///     data_xz: None,
-    ///     size: 63,
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_01: vec![0],
     ///     data_xz: None,
-    ///     size: 65,
+    ///     size: 1,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
     ///
-    /// ## 2-State Primary Literals - Unsigned Addition
+    /// ## 4-State Primary Literals (No X/Z(s))
     ///
-    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
+    /// Signed negative value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![65533],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 16,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
-    ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: false,
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Unsigned value with width < usize::BITS added with an unsigned value with width < usize::BITS
+    /// Signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: None,
-    ///     size: 63,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
+    ///     data_xz: Some(vec![0]),
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Unsigned value with width = 2 * usize::BITS added with an unsigned value with width = usize::BITS
+    /// Signed negative value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 128,
-    ///     signed: false,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
-    ///     signed: false,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 9223372036854775809, 0],
-    ///     data_xz: None,
-    ///     size: 129,
-    ///     signed: false,
-    /// };
-    ///
-    /// assert_eq!(c, exp);
-    /// ```
-    ///
-    /// ## 4-State Primary Literals - Signed Addition (No X/Z(s))
-    ///
-    /// Signed negative value with width = usize::BITS added with itself
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Signed positive value with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     size: 66,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed negative value with width = 2 * usize::BITS added with a signed negative value with width = usize::BITS
+    /// Signed value = 0 with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
     ///     data_xz: Some(vec![0, 0]),
     ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// a._minimum_width();
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 1,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 9223372036854775808, 1],
-    ///     data_xz: Some(vec![0, 0, 0]),
-    ///     size: 129,
-    ///     signed: true,
-    /// };
-    ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed negative value with width < usize::BITS added with a signed positive value with width = usize::BITS
+    /// Unsigned value with width = 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
-    ///     signed: true,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// a._minimum_width();
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 2,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
-    ///
-    /// let exp = SvPrimaryLiteralIntegral {
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Unsigned value = 0 with width = 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
     ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     size: 128,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
-    /// ```
-    /// Signed negative value with width = usize::BITS added with a signed positive value with width = usize::BITS
-    /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// a._minimum_width();
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    ///     size: 1,
+    ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    /// assert_eq!(a, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Unsigned value with width = 3 * usize::BITS and an X in the lowest element
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4, 0, 0],
+    ///     data_xz: Some(vec![1, 0, 0]),
+    ///     size: 192,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![13835058055282163712, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 3,
+    ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS added with a signed positive value with width = usize::BITS
+    /// Signed value with usize::BITS < width < 2 * usize::BITS and a Z in the lowest element
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3, 0],
+    ///     data_xz: Some(vec![2, 0]),
+    ///     size: 70,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._minimum_width();
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 3,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = 2 * usize::BITS added with a signed positive value with width = usize::BITS
+    pub fn _minimum_width(&mut self) {
+        if !self.signed {
+            if self.is_zero() {
+                for _x in 0..self.data_01.len() {
+                    let last_index = self.data_01.len() - 1;
+                    self.data_01.remove(last_index);
+                }
+                self.data_01.push(0);
+                self.size = 1;
+            } else {
+                for _x in 0..self.data_01.len() {
+                    let last_index = self.data_01.len() - 1;
+                    if self.data_01[last_index] == 0 {
+                        self.data_01.remove(last_index);
+                    }
+                }
+
+                self.size = (usize::BITS as usize
+                    - self.data_01[self.data_01.len() - 1].leading_zeros() as usize)
+                    + (self.data_01.len() - 1) * usize::BITS as usize;
+            }
+        } else {
+            let mut min_num_found: bool = false;
+            let mut vec_elements_to_rm: usize = 0;
+
+            if self.is_negative() {
+                for x in (0..self.data_01.len()).rev() {
+                    while !min_num_found {
+                        let pre_leading = self.data_01[x].leading_zeros();
+
+                        let highest_bit: usize = usize::BITS
+                            .checked_sub(pre_leading + 1)
+                            .map_or(0, |x| 1 << x);
+                        let minimized_value: usize = self.data_01[x] & !highest_bit;
+                        let post_leading = minimized_value.leading_zeros();
+
+                        if post_leading == usize::BITS {
+                            if x == 0 || self.data_01[x - 1].leading_zeros() != 0 {
+                                min_num_found = true;
+                                break;
+                            }
+                        }
+
+                        if post_leading != (pre_leading + 1) {
+                            min_num_found = true;
+                            break;
+                        } else {
+                            self.data_01[x] = minimized_value;
+                            self.size = self.size - 1;
+
+                            if post_leading == usize::BITS {
+                                vec_elements_to_rm = vec_elements_to_rm + 1;
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                for _x in 0..vec_elements_to_rm {
+                    let last_index = self.data_01.len() - 1;
+                    self.data_01.remove(last_index);
+                }
+            } else if self.is_zero() {
+                for _x in 0..self.data_01.len() {
+                    let last_index = self.data_01.len() - 1;
+                    self.data_01.remove(last_index);
+                }
+                self.data_01.push(0);
+                self.size = 1;
+            } else {
+                for _x in 0..self.data_01.len() {
+                    let last_index = self.data_01.len() - 1;
+                    if self.data_01[last_index] == 0 {
+                        self.data_01.remove(last_index);
+                    }
+                }
+
+                let last_index = self.data_01.len() - 1;
+                if self.data_01[last_index].leading_zeros() == 0 {
+                    self.data_01.push(0);
+                }
+
+                self.size = (usize::BITS as usize
+                    - self.data_01[self.data_01.len() - 1].leading_zeros() as usize
+                    + 1)
+                    + (self.data_01.len() - 1) * usize::BITS as usize;
+            }
+        }
+
+        if self.is_4state() {
+            let len = self.data_01.len();
+            self.data_xz.as_mut().unwrap().truncate(len);
+        }
+    }
+
+    /** Receives the number of bits in which an integral primary literal should be truncated.
+    The correct final number of bits is set but the signedness doesn't change. */
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Signed negative value with width = usize::BITS truncated to 64 bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904, 4611686018427387904],
-    ///     data_xz: Some(vec![0, 0]),
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: None,
     ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
-    /// };
-    ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._truncate(64);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 4611686018427387904, 0],
-    ///     data_xz: Some(vec![0, 0, 0]),
-    ///     size: 129,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals - Signed Unsigned Addition (No X/Z(s))
-    ///
-    /// Signed negative value with width = usize::BITS added with an unsigned value with width = usize::BITS
+    /// Signed negative value with width = usize::BITS truncated to 5 bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387905, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: false,
-    /// };
-    ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._truncate(5);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: false,
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Signed positive value with width = usize::BITS added with an unsigned value with width < usize::BITS
+    /// Unsigned value with width = usize::BITS truncated to 69 bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
-    ///     signed: true,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775809],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    /// a._truncate(69);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: None,
+    ///     size: 69,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS truncated to 1 bit
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
+    /// a._truncate(1);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 1,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
     ///
-    /// ## 4-State Primary Literals - Unsigned Addition (No X/Z(s))
+    /// ## 4-State Primary Literals (No X/Z(s))
     ///
-    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
+    /// Signed negative value with width = usize::BITS truncated to 64 bits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._truncate(64);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Signed negative value with width = usize::BITS truncated to 5 bits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387905, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._truncate(5);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS truncated to 69 bits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775809],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// a._truncate(69);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 1],
+    ///     data_01: vec![9223372036854775808, 1],
     ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     size: 69,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
-    /// Unsigned value with width < usize::BITS added with an unsigned value with width < usize::BITS
+    /// Unsigned value with width = usize::BITS truncated to 1 bit
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
+    /// a._truncate(1);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
     ///     data_xz: Some(vec![0]),
-    ///     size: 63,
+    ///     size: 1,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// assert_eq!(a, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// Signed value with width = usize::BITS truncated to 64 bits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808, 9223372036854775808]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._truncate(64);
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: Some(vec![9223372036854775808]),
     ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Signed value with width = usize::BITS truncated to 5 bits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387905, 9223372036854775808],
+    ///     data_xz: Some(vec![4611686018427387905, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// a._truncate(5);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS truncated to 69 bits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775809],
+    ///     data_xz: Some(vec![0, 9223372036854775809]),
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// a._truncate(69);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 1]),
+    ///     size: 69,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a, exp);
     /// ```
-    /// Unsigned value with width = 2 * usize::BITS added with an unsigned value with width = usize::BITS
+    /// Unsigned value with width = usize::BITS truncated to 1 bit
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
-    /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0]),
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 0],
+    ///     data_xz: Some(vec![1, 0]),
     ///     size: 128,
     ///     signed: false,
     /// };
     ///
-    /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    /// a._truncate(1);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 1,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a + b;
+    /// assert_eq!(a, exp);
+    /// ```
+    pub fn _truncate(&mut self, size: usize) {
+        self.try_truncate(size)
+            .expect("Could not truncate the value!")
+    }
+
+    /** Same as _truncate, but returns an error instead of panicking if the requested number of bits is zero or larger than the original one. */
+    /// # Examples
+    ///
+    /// ```
+    /// # use python_svdata::sv_error::PrimlitError;
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let mut a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.try_truncate(0), Err(PrimlitError::ZeroWidth));
+    /// assert_eq!(
+    ///     a.try_truncate(5),
+    ///     Err(PrimlitError::TooNarrow {
+    ///         size: 4,
+    ///         requested: 5
+    ///     })
+    /// );
+    /// assert_eq!(a.try_truncate(2), Ok(()));
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 9223372036854775809, 0],
-    ///     data_xz: Some(vec![0, 0, 0]),
-    ///     size: 129,
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 2,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a, exp);
     /// ```
+    pub fn try_truncate(&mut self, size: usize) -> Result<(), PrimlitError> {
+        if size == 0 {
+            return Err(PrimlitError::ZeroWidth);
+        } else if self.size >= size {
+            let elmnts = size.div_ceil(usize::BITS as usize);
+
+            // The bits of the last element beyond the width are cleared, unless all of them are used
+            let used_bits = size % usize::BITS as usize;
+            let mask: usize = if used_bits == 0 {
+                usize::MAX
+            } else {
+                (1 << used_bits) - 1
+            };
+
+            self.data_01.truncate(elmnts);
+            self.data_01[elmnts - 1] &= mask;
+
+            if let Some(data_xz) = self.data_xz.as_mut() {
+                data_xz.truncate(elmnts);
+                data_xz[elmnts - 1] &= mask;
+            }
+
+            self.size = size;
+        } else {
+            return Err(PrimlitError::TooNarrow {
+                size: self.size,
+                requested: size,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Addition between two integral primary literals.
+    /// The result has one bit more than the widest operand and is only signed if both operands are signed.
+    /// If any of the operands contains X/Z(s), the bits from the lowest X/Z bit of both operands up to the MSB are X, while the bits below it are exact.
     ///
-    /// ## 4-State Primary Literals - Signed Addition (Containing X/Z(s))
+    /// # Examples
     ///
-    /// Signed value with width = usize::BITS added with signed negative value with width = usize::BITS
+    /// ## 2-State Primary Literals - Signed Addition
+    ///
+    /// Signed negative value with width = usize::BITS added with itself
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
@@ -6853,56 +9087,111 @@ impl SvPrimaryLiteralIntegral {
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed value with width = usize::BITS added with signed positive value with width = usize::BITS
+    /// Signed negative value with width = 2 * usize::BITS added with a signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 9223372036854775808, 1],
+    ///     data_xz: None,
+    ///     size: 129,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed negative value with width < usize::BITS added with a signed positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed value with width = usize::BITS added with signed positive value with width = usize::BITS
+    /// Signed negative value with width = usize::BITS added with a signed positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13835058055282163712, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with width = usize::BITS added with a signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
@@ -6910,20 +9199,48 @@ impl SvPrimaryLiteralIntegral {
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
+    /// Signed positive value with width = 2 * usize::BITS added with a signed positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904, 4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 4611686018427387904, 0],
+    ///     data_xz: None,
+    ///     size: 129,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
     ///
-    /// ## 4-State Primary Literals - Signed Unsigned Addition (Containing X/Z(s))
+    /// ## 2-State Primary Literals - Signed Unsigned Addition
     ///
     /// Signed negative value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
     ///     data_xz: None,
@@ -6933,7 +9250,7 @@ impl SvPrimaryLiteralIntegral {
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
@@ -6941,87 +9258,114 @@ impl SvPrimaryLiteralIntegral {
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width < usize::BITS added with a signed positive value with width < usize::BITS
+    /// Signed positive value with width = usize::BITS added with an unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![7],
-    ///     data_xz: Some(vec![3]),
-    ///     size: 3,
-    ///     signed: false,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![15],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
-    ///     signed: true,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![63]),
-    ///     size: 6,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
     ///
-    /// ## 4-State Primary Literals - Unsigned Addition (Containing X/Z(s))
+    /// ## 2-State Primary Literals - Unsigned Addition
     ///
     /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
     ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
+    /// Unsigned value with width < usize::BITS added with an unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: None,
+    ///     size: 63,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
     ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width = 2 * usize::BITS added with an unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 128,
+    ///     signed: false,
+    /// };
+    ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: false,
     /// };
@@ -7029,1159 +9373,1426 @@ impl SvPrimaryLiteralIntegral {
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
-    ///     size: 65,
+    ///     data_01: vec![0, 9223372036854775809, 0],
+    ///     data_xz: None,
+    ///     size: 129,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width < usize::BITS added with an unsigned value with usize::BITS < width < 2 * usize::BITS
+    ///
+    /// ## 4-State Primary Literals - Signed Addition (No X/Z(s))
+    ///
+    /// Signed negative value with width = usize::BITS added with itself
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed negative value with width = 2 * usize::BITS added with a signed negative value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 9223372036854775808, 1],
+    ///     data_xz: Some(vec![0, 0, 0]),
+    ///     size: 129,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed negative value with width < usize::BITS added with a signed positive value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
     ///     size: 63,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
     /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 1]),
+    ///     data_xz: Some(vec![0, 0]),
     ///     size: 65,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    pub fn add_primlit(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut ret: SvPrimaryLiteralIntegral = self.clone();
-
-        if ret.is_4state() != right_nu.is_4state() {
-            if !ret.is_4state() {
-                ret = ret.to_4state();
-            } else {
-                right_nu = right_nu.to_4state();
-            }
-        }
-
-        if !ret.contains_xz() && !right_nu.contains_xz() {
-            // Possible carry out from the MSB
-            let final_num_bits: usize;
-            let elmnts_sign_extension: usize;
-
-            if ret.size > right_nu.size {
-                final_num_bits = ret.size + 1;
-                elmnts_sign_extension = ret.data_01.len() + 1;
-            } else {
-                final_num_bits = right_nu.size + 1;
-                elmnts_sign_extension = right_nu.data_01.len() + 1;
-            }
-
-            if ret.signed == false || right_nu.signed == false {
-                ret.signed = false;
-            } else {
-                let mut matched_prim_lit = bit1b_0();
-                matched_prim_lit.signed = true;
-                for _x in 0..(elmnts_sign_extension - 1) {
-                    matched_prim_lit.data_01.push(0);
-                }
-                matched_prim_lit.size = elmnts_sign_extension * usize::BITS as usize;
-
-                ret._matched_sign_extend(&mut matched_prim_lit);
-                right_nu._matched_sign_extend(&mut matched_prim_lit);
-            }
-
-            ret._unsigned_primlit_add(right_nu.clone());
-
-            if ret.signed {
-                ret._truncate(final_num_bits);
-            } else {
-                ret.size = final_num_bits;
-                if (ret.data_01.len() * usize::BITS as usize) < final_num_bits {
-                    ret.data_01.push(0);
-                }
-            }
-
-            if ret.is_4state() {
-                ret.data_xz = ret.to_4state().data_xz;
-            }
-
-            ret
-        } else {
-            if ret.size < right_nu.size {
-                ret.size = right_nu.size;
-            }
-
-            // Possible carry out from the MSB
-            let final_num_bits = ret.size + 1;
-
-            ret = SvPrimaryLiteralIntegral {
-                data_01: vec![0],
-                data_xz: Some(vec![1]),
-                signed: !(ret.signed == false || right_nu.signed == false),
-                size: 1,
-            };
-
-            let x_primlit = SvPrimaryLiteralIntegral {
-                data_01: vec![0],
-                data_xz: Some(vec![1]),
-                signed: ret.signed,
-                size: 1,
-            };
-
-            for _x in 0..(final_num_bits - 1) {
-                ret = ret.cat(x_primlit.clone());
-            }
-
-            ret
-        }
-    }
-
-    pub fn mul_unsigned(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut ret: SvPrimaryLiteralIntegral;
-        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
-        let mut add_ver: Vec<SvPrimaryLiteralIntegral> = Vec::new();
-
-        for x in 0..right_nu.size {
-            if right_nu.data_01[0].trailing_zeros() == 0 {
-                if x == 0 {
-                    add_ver.push(left_nu.clone());
-                } else {
-                    left_nu = left_nu.lsl(1);
-                    add_ver.push(left_nu.clone());
-                }
-            } else if x != 0 {
-                left_nu = left_nu.lsl(1);
-            }
-
-            right_nu = right_nu.lsr(1);
-        }
-        ret = SvPrimaryLiteralIntegral {
-            data_01: vec![0],
-            data_xz: None,
-            signed: false,
-            size: 1,
-        };
-
-        for y in 0..add_ver.len() {
-            ret = ret.add_primlit(add_ver[y].clone());
-        }
-
-        ret
-    }
-
-    /// # Examples
-    ///
-    /// ## 2-State Primary Literals - Signed Multiplication
-    ///
-    /// Signed negative value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Signed negative value with width = usize::BITS added with a signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 2,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 5,
+    ///     data_01: vec![13835058055282163712, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed negative value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Signed positive value with width = usize::BITS added with a signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: None,
-    ///     size: 67,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Signed positive value with width = 2 * usize::BITS added with a signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 3,
+    ///     data_01: vec![4611686018427387904, 4611686018427387904],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![52],
-    ///     data_xz: None,
-    ///     size: 6,
+    ///     data_01: vec![9223372036854775808, 4611686018427387904, 0],
+    ///     data_xz: Some(vec![0, 0, 0]),
+    ///     size: 129,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed negative value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals - Signed Unsigned Addition (No X/Z(s))
+    ///
+    /// Signed negative value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
-    ///     size: 65,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 14],
-    ///     data_xz: None,
-    ///     size: 68,
-    ///     signed: true,
+    ///     data_01: vec![0, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with width < usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// Signed positive value with width = usize::BITS added with an unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 3,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 4,
-    ///     signed: true,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: None,
-    ///     size: 7,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed positive value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals - Unsigned Addition (No X/Z(s))
+    ///
+    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: None,
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 4,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: None,
-    ///     size: 69,
-    ///     signed: true,
+    ///     data_01: vec![0, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    ///
-    /// ## 2-State Primary Literals - Signed Unsigned Multiplication
-    ///
-    /// Unsigned value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Unsigned value with width < usize::BITS added with an unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 2,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 63,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: None,
-    ///     size: 5,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Unsigned value with width = 2 * usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 64,
+    ///     data_01: vec![9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 128,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: None,
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: None,
-    ///     size: 67,
+    ///     data_01: vec![0, 9223372036854775809, 0],
+    ///     data_xz: Some(vec![0, 0, 0]),
+    ///     size: 129,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
     ///
-    /// ## 2-State Primary Literals - Unsigned Multiplication
+    /// ## 4-State Primary Literals - Signed Addition (Containing X/Z(s))
     ///
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Signed value with width < usize::BITS and a Z high nibble added with a signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: None,
-    ///     size: 2,
-    ///     signed: false,
+    ///     data_01: vec![247],
+    ///     data_xz: Some(vec![240]),
+    ///     size: 8,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![1],
     ///     data_xz: None,
-    ///     size: 3,
-    ///     signed: false,
+    ///     size: 8,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: None,
-    ///     size: 5,
-    ///     signed: false,
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![496]),
+    ///     size: 9,
+    ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// Signed value with width = usize::BITS added with signed negative value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![8],
-    ///     data_xz: None,
-    ///     size: 4,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: None,
+    ///     data_xz: Some(vec![0]),
     ///     size: 64,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 4],
-    ///     data_xz: None,
-    ///     size: 68,
-    ///     signed: false,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![13835058055282163712, 1]),
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Signed value with width = usize::BITS added with signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 9223372036854775808, 9223372036854775808],
-    ///     data_xz: None,
-    ///     size: 192,
-    ///     signed: false,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16],
-    ///     data_xz: None,
-    ///     size: 5,
-    ///     signed: false,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16, 0, 8, 8],
-    ///     data_xz: None,
-    ///     size: 197,
-    ///     signed: false,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![13835058055282163712, 1]),
+    ///     size: 65,
+    ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals - Signed Multiplication (No X/Z(s))
-    ///
-    /// Signed negative value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Signed value with width = usize::BITS added with signed positive value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 2,
+    ///     data_01: vec![4611686018427387904],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
-    ///     size: 3,
+    ///     size: 64,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![13835058055282163712, 1]),
+    ///     size: 65,
     ///     signed: true,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed negative value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals - Signed Unsigned Addition (Containing X/Z(s))
+    ///
+    /// Signed negative value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
     ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
+    ///     data_xz: None,
     ///     size: 64,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 67,
-    ///     signed: true,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Unsigned value with width < usize::BITS added with a signed positive value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![7],
+    ///     data_xz: Some(vec![3]),
     ///     size: 3,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![15],
     ///     data_xz: Some(vec![0]),
-    ///     size: 3,
+    ///     size: 5,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![52],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![63]),
     ///     size: 6,
-    ///     signed: true,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed negative value with width < usize::BITS
+    ///
+    /// ## 4-State Primary Literals - Unsigned Addition (Containing X/Z(s))
+    ///
+    /// Unsigned value with width < usize::BITS and an X high nibble added with an unsigned value with width < usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![240]),
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 14],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 68,
-    ///     signed: true,
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![496]),
+    ///     size: 9,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with width < usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// Unsigned value with width < usize::BITS and an X high nibble added with an unsigned value with width < usize::BITS carrying out of the low nibble
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![12],
+    ///     data_xz: Some(vec![240]),
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 4,
-    ///     signed: true,
+    ///     data_01: vec![6],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 7,
-    ///     signed: true,
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![496]),
+    ///     size: 9,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![9223372036854775808],
     ///     data_xz: Some(vec![0]),
-    ///     size: 4,
-    ///     signed: true,
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
+    ///
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 69,
-    ///     signed: true,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
+    ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    ///
-    /// ## 4-State Primary Literals - Signed Unsigned Multiplication (No X/Z(s))
-    ///
-    /// Unsigned value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Unsigned value with width = usize::BITS added with an unsigned value with width = usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 2,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![9223372036854775808]),
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![9223372036854775808, 1]),
+    ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
-    /// Unsigned value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Unsigned value with width < usize::BITS added with an unsigned value with usize::BITS < width < 2 * usize::BITS
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 63,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![4611686018427387904],
     ///     data_xz: Some(vec![0]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     size: 64,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// let c: SvPrimaryLiteralIntegral = a + b;
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 2],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 67,
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![13835058055282163712, 1]),
+    ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// assert_eq!(c, exp);
     /// ```
+    pub fn add_primlit(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+
+        if ret.is_4state() != right_nu.is_4state() {
+            if !ret.is_4state() {
+                ret = ret.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        if !ret.contains_xz() && !right_nu.contains_xz() {
+            // Possible carry out from the MSB
+            let final_num_bits: usize;
+            let elmnts_sign_extension: usize;
+
+            if ret.size > right_nu.size {
+                final_num_bits = ret.size + 1;
+                elmnts_sign_extension = ret.data_01.len() + 1;
+            } else {
+                final_num_bits = right_nu.size + 1;
+                elmnts_sign_extension = right_nu.data_01.len() + 1;
+            }
+
+            if ret.signed == false || right_nu.signed == false {
+                ret.signed = false;
+            } else {
+                let mut matched_prim_lit = bit1b_0();
+                matched_prim_lit.signed = true;
+                for _x in 0..(elmnts_sign_extension - 1) {
+                    matched_prim_lit.data_01.push(0);
+                }
+                matched_prim_lit.size = elmnts_sign_extension * usize::BITS as usize;
+
+                ret._matched_sign_extend(&mut matched_prim_lit);
+                right_nu._matched_sign_extend(&mut matched_prim_lit);
+            }
+
+            ret._unsigned_primlit_add(right_nu.clone());
+
+            if ret.signed {
+                ret._truncate(final_num_bits);
+            } else {
+                ret.size = final_num_bits;
+                if (ret.data_01.len() * usize::BITS as usize) < final_num_bits {
+                    ret.data_01.push(0);
+                }
+            }
+
+            if ret.is_4state() {
+                ret.data_xz = ret.to_4state().data_xz;
+            }
+
+            ret
+        } else {
+            // The bits below the lowest X/Z bit of both operands are not affected by it
+            let first_xz: usize = [&ret, &right_nu]
+                .iter()
+                .filter_map(|x| {
+                    let data_xz = x.data_xz.as_ref().unwrap();
+                    data_xz
+                        .iter()
+                        .position(|y| *y != 0)
+                        .map(|y| y * usize::BITS as usize + data_xz[y].trailing_zeros() as usize)
+                })
+                .min()
+                .unwrap();
+
+            let mut left_nu = ret.clone();
+            left_nu.data_xz = None;
+            right_nu.data_xz = None;
+
+            ret = left_nu.add_primlit(right_nu).to_4state();
+
+            for x in first_xz..ret.size {
+                let elmnt: usize = x / usize::BITS as usize;
+                let bit: usize = 1 << (x % usize::BITS as usize);
+
+                ret.data_01[elmnt] &= !bit;
+                ret.data_xz.as_mut().unwrap()[elmnt] |= bit;
+            }
+
+            ret
+        }
+    }
+
+    /// Addition between two integral primary literals, truncated to the given number of bits.
+    /// Returns the sum along with whether it overflowed, i.e. whether the truncated sum differs from the full sum.
+    /// For unsigned values this is the carry out, for signed values the sign of the truncated sum is inconsistent with the operands.
+    /// If the sum contains X/Z(s), it is never reported as overflowing.
+    /// A sum with fewer bits than requested is extended instead and never overflows.
     ///
-    /// ## 4-State Primary Literals - Unsigned Multiplication (No X/Z(s))
+    /// # Examples
     ///
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Unsigned values whose sum just overflows
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
+    ///     data_01: vec![9],
     ///     data_xz: None,
-    ///     size: 2,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![12],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.add_with_carry(b, 4), (exp, true));
     /// ```
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// Unsigned values whose sum just fits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![8],
-    ///     data_xz: Some(vec![0]),
+    ///     data_01: vec![9],
+    ///     data_xz: None,
     ///     size: 4,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![6],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 4],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 68,
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.add_with_carry(b, 4), (exp, false));
     /// ```
-    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Signed positive values whose sum just overflows
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 9223372036854775808, 9223372036854775808],
-    ///     data_xz: Some(vec![0, 0, 0]),
-    ///     size: 192,
-    ///     signed: false,
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
-    ///     signed: false,
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16, 0, 8, 8],
-    ///     data_xz: Some(vec![0, 0, 0, 0]),
-    ///     size: 197,
-    ///     signed: false,
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.add_with_carry(b, 4), (exp, true));
     /// ```
-    ///
-    /// ## 4-State Primary Literals - Signed Multiplication (Containing X/Z(s))
-    ///
-    /// Signed negative value with width < usize::BITS mult/ed with signed value with width < usize::BITS
+    /// Signed negative values whose sum just overflows
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 2,
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![8],
-    ///     data_xz: Some(vec![4]),
+    ///     data_01: vec![15],
+    ///     data_xz: None,
     ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![63]),
-    ///     size: 6,
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.add_with_carry(b, 4), (exp, true));
     /// ```
-    /// Signed value with width = usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// Signed values with a carry out but no overflow
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![4611686018427387904]),
-    ///     size: 64,
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 3,
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 7]),
-    ///     size: 67,
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.add_with_carry(b, 4), (exp, false));
     /// ```
-    /// Signed value with width < usize::BITS mult/ed with signed value with width < usize::BITS
+    pub fn add_with_carry(
+        &self,
+        right_nu: SvPrimaryLiteralIntegral,
+        width: usize,
+    ) -> (SvPrimaryLiteralIntegral, bool) {
+        let sum: SvPrimaryLiteralIntegral = self.add_primlit(right_nu);
+        let mut ret: SvPrimaryLiteralIntegral = sum.clone();
+
+        if width >= sum.size {
+            let ret = if sum.signed {
+                sum.sign_extend_to(width)
+            } else {
+                sum.zero_extend_to(width)
+            };
+
+            return (ret.unwrap(), false);
+        }
+
+        ret._truncate(width);
+
+        if sum.contains_xz() {
+            return (ret, false);
+        }
+
+        // The sum overflowed if extending the truncated sum back does not give the full sum.
+        let extended = if sum.signed {
+            ret.sign_extend_to(sum.size)
+        } else {
+            ret.zero_extend_to(sum.size)
+        };
+
+        let overflow = extended.unwrap()._case_eq(sum) != bit1b_1();
+
+        (ret, overflow)
+    }
+
+    /// Addition between two integral primary literals that clamps to the range of a value of the given width and signedness.
+    /// Each operand keeps its own signedness, so the sum is exact before it is clamped, and the result has exactly `width` bits.
+    /// If any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed values that overflow the signed 8-bit range upward
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![1]),
-    ///     size: 3,
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![3]),
-    ///     size: 3,
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![63]),
-    ///     size: 6,
+    ///     data_01: vec![127],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_add(b, 8, true), exp);
     /// ```
-    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed value with width < usize::BITS
+    /// Signed values that overflow the signed 8-bit range downward
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0]),
-    ///     size: 65,
+    ///     data_01: vec![156],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![1]),
-    ///     size: 3,
+    ///     data_01: vec![156],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 15]),
-    ///     size: 68,
+    ///     data_01: vec![128],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_add(b, 8, true), exp);
     /// ```
-    /// Signed value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// Signed values whose sum fits
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![1]),
-    ///     size: 2,
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
+    ///     data_01: vec![156],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0],
-    ///     data_xz: Some(vec![127]),
-    ///     size: 7,
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_add(b, 8, true), exp);
     /// ```
-    /// Signed value with usize::BITS < width < 2 * usize::BITS mult/ed with signed value with width < usize::BITS
+    /// Unsigned values that overflow the unsigned 8-bit range
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 1]),
-    ///     size: 66,
-    ///     signed: true,
+    ///     data_01: vec![200],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 4,
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.saturating_add(b, 8, false), exp);
+    /// ```
+    /// Value containing an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![100],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
+    /// assert_eq!(a.saturating_add(usize_to_primlit(1), 8, true), logic_x(8, true));
+    /// ```
+    pub fn saturating_add(
+        &self,
+        right_nu: SvPrimaryLiteralIntegral,
+        width: usize,
+        signed: bool,
+    ) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() || right_nu.contains_xz() {
+            return logic_x(width, signed);
+        }
+
+        self._as_signed()
+            .add_primlit(right_nu._as_signed())
+            ._saturate(width, signed)
+    }
+
+    /// Subtraction between two integral primary literals that clamps to the range of a value of the given width and signedness.
+    /// Each operand keeps its own signedness, so the difference is exact before it is clamped, and the result has exactly `width` bits.
+    /// If any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed values that overflow the signed 8-bit range upward
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![156],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 63]),
-    ///     size: 70,
+    ///     data_01: vec![127],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_sub(b, 8, true), exp);
+    /// ```
+    /// Signed values that overflow the signed 8-bit range downward
     /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![156],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// ## 4-State Primary Literals - Signed Unsigned Multiplication (Containing X/Z(s))
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
     ///
-    /// Unsigned value with width < usize::BITS mult/ed with a signed negative value with width < usize::BITS
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![128],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.saturating_sub(b, 8, true), exp);
+    /// ```
+    /// Unsigned values whose difference is negative
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
-    ///     data_xz: Some(vec![3]),
-    ///     size: 2,
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
+    ///     data_01: vec![10],
     ///     data_xz: None,
-    ///     size: 3,
-    ///     signed: true,
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
     ///     data_01: vec![0],
-    ///     data_xz: Some(vec![31]),
-    ///     size: 5,
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_sub(b, 8, false), exp);
     /// ```
-    /// Unsigned value with width = usize::BITS mult/ed with a signed value with width < usize::BITS
+    /// Unsigned values whose difference is clamped to a signed 4-bit range
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![4]),
-    ///     size: 3,
-    ///     signed: true,
+    ///     data_01: vec![100],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 7]),
-    ///     size: 67,
-    ///     signed: false,
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.saturating_sub(b, 4, true), exp);
     /// ```
+    pub fn saturating_sub(
+        &self,
+        right_nu: SvPrimaryLiteralIntegral,
+        width: usize,
+        signed: bool,
+    ) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() || right_nu.contains_xz() {
+            return logic_x(width, signed);
+        }
+
+        self._as_signed()
+            .sub_primlit(right_nu._as_signed())
+            ._saturate(width, signed)
+    }
+
+    // Returns a signed value with the same value, zero extending an unsigned value by one bit.
+    fn _as_signed(&self) -> SvPrimaryLiteralIntegral {
+        if self.signed {
+            return self.clone();
+        }
+
+        let mut ret: SvPrimaryLiteralIntegral = self.zero_extend_to(self.size + 1).unwrap();
+        ret.signed = true;
+
+        ret
+    }
+
+    // Clamps a signed value to the range of a value of the given width and signedness.
+    fn _saturate(&self, width: usize, signed: bool) -> SvPrimaryLiteralIntegral {
+        let extended: SvPrimaryLiteralIntegral = self
+            .sign_extend_to(std::cmp::max(self.size, width) + 1)
+            .unwrap();
+        let bits: Vec<char> = extended.to_bit_vector();
+        let negative: bool = bits[0] == '1';
+
+        // The bits above the result, including its sign bit if it is signed, must all be copies of the sign.
+        let upper: usize = bits.len() - width + if signed { 1 } else { 0 };
+        let fits: bool = (signed || !negative) && bits[..upper].iter().all(|x| *x == bits[0]);
+
+        let mut ret: SvPrimaryLiteralIntegral = if fits {
+            let mut ret: SvPrimaryLiteralIntegral = extended.clone();
+            ret._truncate(width);
+            ret
+        } else {
+            let (first, rest) = match (signed, negative) {
+                (false, false) => ('1', '1'),
+                (false, true) => ('0', '0'),
+                (true, false) => ('0', '1'),
+                (true, true) => ('1', '0'),
+            };
+
+            let mut bits: Vec<char> = vec![rest; width];
+            bits[0] = first;
+            SvPrimaryLiteralIntegral::from_bit_vector(&bits, signed).unwrap()
+        };
+
+        ret.signed = signed;
+        if self.is_4state() && !ret.is_4state() {
+            ret = ret.to_4state();
+        }
+
+        ret
+    }
+
+    /// Subtraction between two integral primary literals.
+    /// The result has one bit more than the widest operand and is only signed if both operands are signed.
+    /// If any of the operands contains X/Z(s), all the bits of the result are X.
     ///
-    /// ## 4-State Primary Literals - Unsigned Multiplication (Containing X/Z(s))
+    /// # Examples
     ///
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Signed positive value subtracted from a signed positive value
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![3],
+    ///     data_01: vec![5],
     ///     data_xz: None,
-    ///     size: 2,
-    ///     signed: false,
+    ///     size: 4,
+    ///     signed: true,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![4],
-    ///     data_xz: Some(vec![4]),
+    ///     data_01: vec![3],
+    ///     data_xz: None,
     ///     size: 3,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![31]),
+    ///     data_01: vec![2],
+    ///     data_xz: None,
     ///     size: 5,
-    ///     signed: false,
+    ///     signed: true,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.sub_primlit(b), exp);
     /// ```
-    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// Signed value subtracted from a smaller signed value
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0],
-    ///     data_xz: Some(vec![8]),
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
     ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![30],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.sub_primlit(b), exp);
+    /// ```
+    /// Unsigned value subtracted from a smaller unsigned value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 2,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![9223372036854775808],
+    ///     data_01: vec![5],
     ///     data_xz: Some(vec![0]),
-    ///     size: 64,
+    ///     size: 3,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 15]),
-    ///     size: 68,
+    ///     data_01: vec![14],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.sub_primlit(b), exp);
     /// ```
-    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS subtracted by a borrow across the elements
     /// ```
-    /// # use svdata::sv_primlit_integral::*;
+    /// # use python_svdata::sv_primlit_integral::*;
     /// let a = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![1, 9223372036854775808, 0],
-    ///     data_xz: Some(vec![0, 0, 9223372036854775808]),
-    ///     size: 192,
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
+    ///     size: 65,
     ///     signed: false,
     /// };
     ///
     /// let b = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![16],
-    ///     data_xz: Some(vec![0]),
-    ///     size: 5,
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 1,
     ///     signed: false,
     /// };
     ///
-    /// let c: SvPrimaryLiteralIntegral = a * b;
-    ///
     /// let exp = SvPrimaryLiteralIntegral {
-    ///     data_01: vec![0, 0, 0, 0],
-    ///     data_xz: Some(vec![18446744073709551615, 18446744073709551615, 18446744073709551615, 31]),
-    ///     size: 197,
+    ///     data_01: vec![18446744073709551615, 0],
+    ///     data_xz: None,
+    ///     size: 66,
     ///     signed: false,
     /// };
     ///
-    /// assert_eq!(c, exp);
+    /// assert_eq!(a.sub_primlit(b), exp);
     /// ```
-    pub fn mult(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
-        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
-        let mut ret: SvPrimaryLiteralIntegral;
-
-        if left_nu.is_4state() != right_nu.is_4state() {
-            if !left_nu.is_4state() {
-                left_nu = left_nu.to_4state();
-            } else {
-                right_nu = right_nu.to_4state();
+    /// Value that contains X(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![7]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.sub_primlit(b), exp);
+    /// ```
+    pub fn sub_primlit(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+
+        if ret.is_4state() != right_nu.is_4state() {
+            if !ret.is_4state() {
+                ret = ret.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
             }
         }
 
-        let final_num_bits: usize = left_nu.size + right_nu.size;
-        let elmnts_sign_extension: usize = left_nu.data_01.len() + right_nu.data_01.len();
+        if !ret.contains_xz() && !right_nu.contains_xz() {
+            // Possible borrow into the MSB
+            let final_num_bits: usize;
+            let elmnts_extension: usize;
 
-        if !left_nu.contains_xz() && !right_nu.contains_xz() {
-            if left_nu.signed && right_nu.signed {
-                let mut matched_prim_lit = bit1b_0();
-                matched_prim_lit.signed = true;
-                for _x in 0..(elmnts_sign_extension - 1) {
-                    matched_prim_lit.data_01.push(0);
-                }
-                matched_prim_lit.size = elmnts_sign_extension * usize::BITS as usize;
+            if ret.size > right_nu.size {
+                final_num_bits = ret.size + 1;
+                elmnts_extension = ret.data_01.len() + 1;
+            } else {
+                final_num_bits = right_nu.size + 1;
+                elmnts_extension = right_nu.data_01.len() + 1;
+            }
 
-                left_nu._matched_sign_extend(&mut matched_prim_lit);
-                right_nu._matched_sign_extend(&mut matched_prim_lit);
+            let mut matched_prim_lit = bit1b_0();
+            for _x in 0..(elmnts_extension - 1) {
+                matched_prim_lit.data_01.push(0);
             }
+            matched_prim_lit.size = elmnts_extension * usize::BITS as usize;
 
-            ret = left_nu.mul_unsigned(right_nu.clone());
-            if ret.size > final_num_bits {
-                ret._truncate(final_num_bits);
+            if ret.signed == false || right_nu.signed == false {
+                ret.signed = false;
+                right_nu.signed = false;
+
+                ret._matched_zero_extend(&mut matched_prim_lit);
+                right_nu._matched_zero_extend(&mut matched_prim_lit);
             } else {
-                ret.size = final_num_bits;
-                // Due to the addition within unsigned_mult we can always expect that ret.data_01.len() is sufficient enough for final_num_bits.
+                matched_prim_lit.signed = true;
+
+                ret._matched_sign_extend(&mut matched_prim_lit);
+                right_nu._matched_sign_extend(&mut matched_prim_lit);
             }
 
-            ret.signed = left_nu.signed && right_nu.signed;
+            ret._unsigned_primlit_sub(right_nu.clone());
+            ret._truncate(final_num_bits);
 
             if ret.is_4state() {
                 ret.data_xz = ret.to_4state().data_xz;
             }
+
+            ret
         } else {
-            let final_num_bits = left_nu.size + right_nu.size;
+            if ret.size < right_nu.size {
+                ret.size = right_nu.size;
+            }
+
+            // Possible borrow into the MSB
+            let final_num_bits = ret.size + 1;
 
             ret = SvPrimaryLiteralIntegral {
                 data_01: vec![0],
                 data_xz: Some(vec![1]),
-                signed: !(left_nu.signed == false || right_nu.signed == false),
+                signed: !(ret.signed == false || right_nu.signed == false),
                 size: 1,
             };
 
@@ -8195,236 +10806,5083 @@ impl SvPrimaryLiteralIntegral {
             for _x in 0..(final_num_bits - 1) {
                 ret = ret.cat(x_primlit.clone());
             }
+
+            ret
+        }
+    }
+
+    pub fn mul_unsigned(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral;
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+        let mut add_ver: Vec<SvPrimaryLiteralIntegral> = Vec::new();
+
+        // A zero product still has the number of bits of both operands.
+        let mut zero = SvPrimaryLiteralIntegral::new(
+            self.size + right_nu.size,
+            self.signed && right_nu.signed,
+        );
+        if self.is_4state() || right_nu.is_4state() {
+            zero = zero.to_4state();
+        }
+
+        for x in 0..right_nu.size {
+            if right_nu.data_01[0].trailing_zeros() == 0 {
+                if x == 0 {
+                    add_ver.push(left_nu.clone());
+                } else {
+                    left_nu = left_nu.lsl(1);
+                    add_ver.push(left_nu.clone());
+                }
+            } else if x != 0 {
+                left_nu = left_nu.lsl(1);
+            }
+
+            right_nu = right_nu.lsr(1);
+        }
+        if add_ver.is_empty() {
+            return zero;
+        }
+
+        ret = SvPrimaryLiteralIntegral {
+            data_01: vec![0],
+            data_xz: None,
+            signed: false,
+            size: 1,
+        };
+
+        for y in 0..add_ver.len() {
+            ret = ret.add_primlit(add_ver[y].clone());
         }
 
-        ret
+        ret
+    }
+
+    /// # Examples
+    ///
+    /// ## 2-State Primary Literals - Signed Multiplication
+    ///
+    /// Signed negative value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed negative value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![52],
+    ///     data_xz: None,
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 14],
+    ///     data_xz: None,
+    ///     size: 68,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with width < usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: None,
+    ///     size: 7,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: None,
+    ///     size: 69,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 2-State Primary Literals - Signed Unsigned Multiplication
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 2-State Primary Literals - Unsigned Multiplication
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4],
+    ///     data_xz: None,
+    ///     size: 68,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 9223372036854775808, 9223372036854775808],
+    ///     data_xz: None,
+    ///     size: 192,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16],
+    ///     data_xz: None,
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16, 0, 8, 8],
+    ///     data_xz: None,
+    ///     size: 197,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Signed Multiplication (No X/Z(s))
+    ///
+    /// Signed negative value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed negative value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 67,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![52],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 14],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 68,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with width < usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 7,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 69,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Signed Unsigned Multiplication (No X/Z(s))
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 2],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Unsigned Multiplication (No X/Z(s))
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 68,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 9223372036854775808, 9223372036854775808],
+    ///     data_xz: Some(vec![0, 0, 0]),
+    ///     size: 192,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16, 0, 8, 8],
+    ///     data_xz: Some(vec![0, 0, 0, 0]),
+    ///     size: 197,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Signed Multiplication (Containing X/Z(s))
+    ///
+    /// Signed negative value with width < usize::BITS mult/ed with signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![4]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![63]),
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed value with width = usize::BITS mult/ed with signed positive value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![4611686018427387904]),
+    ///     size: 64,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 7]),
+    ///     size: 67,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed value with width < usize::BITS mult/ed with signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![63]),
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed positive value with usize::BITS < width < 2 * usize::BITS mult/ed with signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 15]),
+    ///     size: 68,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed value with width < usize::BITS mult/ed with signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![127]),
+    ///     size: 7,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Signed value with usize::BITS < width < 2 * usize::BITS mult/ed with signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 1]),
+    ///     size: 66,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 63]),
+    ///     size: 70,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Signed Unsigned Multiplication (Containing X/Z(s))
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with a signed negative value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![31]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width = usize::BITS mult/ed with a signed value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![4]),
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 7]),
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## 4-State Primary Literals - Unsigned Multiplication (Containing X/Z(s))
+    ///
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![4]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![31]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with width < usize::BITS mult/ed with an unsigned value with width = usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![8]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9223372036854775808],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 15]),
+    ///     size: 68,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned value with 2 * usize::BITS < width < 3 * usize::BITS mult/ed with an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 9223372036854775808, 0],
+    ///     data_xz: Some(vec![0, 0, 9223372036854775808]),
+    ///     size: 192,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![16],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0, 0, 0],
+    ///     data_xz: Some(vec![18446744073709551615, 18446744073709551615, 18446744073709551615, 31]),
+    ///     size: 197,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c, exp);
+    /// ```
+    ///
+    /// ## Multiplication by Zero
+    ///
+    /// Signed value with usize::BITS < width < 2 * usize::BITS mult/ed with zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: None,
+    ///     size: 73,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(c.size, 73);
+    /// assert_eq!(c, exp);
+    /// ```
+    /// Unsigned 4-state value with usize::BITS < width < 2 * usize::BITS mult/ed with zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let c: SvPrimaryLiteralIntegral = a * b;
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 73,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(c.size, 73);
+    /// assert_eq!(c, exp);
+    /// ```
+    pub fn mult(&self, mut right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+        let mut ret: SvPrimaryLiteralIntegral;
+
+        if left_nu.is_4state() != right_nu.is_4state() {
+            if !left_nu.is_4state() {
+                left_nu = left_nu.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        let final_num_bits: usize = left_nu.size + right_nu.size;
+        let elmnts_sign_extension: usize = left_nu.data_01.len() + right_nu.data_01.len();
+
+        if !left_nu.contains_xz() && !right_nu.contains_xz() {
+            if left_nu.signed && right_nu.signed {
+                let mut matched_prim_lit = bit1b_0();
+                matched_prim_lit.signed = true;
+                for _x in 0..(elmnts_sign_extension - 1) {
+                    matched_prim_lit.data_01.push(0);
+                }
+                matched_prim_lit.size = elmnts_sign_extension * usize::BITS as usize;
+
+                left_nu._matched_sign_extend(&mut matched_prim_lit);
+                right_nu._matched_sign_extend(&mut matched_prim_lit);
+            }
+
+            ret = left_nu.mul_unsigned(right_nu.clone());
+            if ret.size > final_num_bits {
+                ret._truncate(final_num_bits);
+            } else {
+                ret.size = final_num_bits;
+                // Due to the addition within unsigned_mult we can always expect that ret.data_01.len() is sufficient enough for final_num_bits.
+            }
+
+            ret.signed = left_nu.signed && right_nu.signed;
+
+            if ret.is_4state() {
+                ret.data_xz = ret.to_4state().data_xz;
+            }
+        } else {
+            let final_num_bits = left_nu.size + right_nu.size;
+
+            ret = SvPrimaryLiteralIntegral {
+                data_01: vec![0],
+                data_xz: Some(vec![1]),
+                signed: !(left_nu.signed == false || right_nu.signed == false),
+                size: 1,
+            };
+
+            let x_primlit = SvPrimaryLiteralIntegral {
+                data_01: vec![0],
+                data_xz: Some(vec![1]),
+                signed: ret.signed,
+                size: 1,
+            };
+
+            for _x in 0..(final_num_bits - 1) {
+                ret = ret.cat(x_primlit.clone());
+            }
+        }
+
+        ret
+    }
+
+    /// Division between two integral primary literals.
+    /// The result has the number of bits of the widest operand and is only signed if both operands are signed.
+    /// Signed division truncates towards zero.
+    /// If the divisor is zero or any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed positive value divided by a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    /// Signed negative value divided by a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    /// Signed positive value divided by a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    /// Signed negative value divided by a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS divided by an unsigned value with width < usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6148914691236517207, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    /// Division by zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.div_primlit(b), exp);
+    /// ```
+    pub fn div_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.divmod(right_nu).0
+    }
+
+    /// Modulus between two integral primary literals.
+    /// The result has the number of bits of the widest operand and is only signed if both operands are signed.
+    /// The sign of the result follows the sign of the first operand.
+    /// If the divisor is zero or any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed positive value modulus a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    /// Signed negative value modulus a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    /// Signed positive value modulus a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    /// Signed negative value modulus a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    /// Unsigned value modulus an unsigned value with a smaller width
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    /// Value that contains X(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.mod_primlit(b), exp);
+    /// ```
+    pub fn mod_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self.divmod(right_nu).1
+    }
+
+    /// Division and modulus between two integral primary literals, returning the quotient and the remainder.
+    /// Both have the number of bits of the widest operand and are only signed if both operands are signed.
+    /// The quotient truncates towards zero and the sign of the remainder follows the sign of the first operand.
+    /// If the divisor is zero or any of the operands contains X/Z(s), all the bits of both are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed negative value divided by a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp_quotient = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp_remainder = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.divmod(b), (exp_quotient, exp_remainder));
+    /// ```
+    /// Unsigned value divided by a signed value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![200],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp_quotient = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp_remainder = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.divmod(b), (exp_quotient, exp_remainder));
+    /// ```
+    /// Division by zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.divmod(b), (exp.clone(), exp));
+    /// ```
+    /// Zero width values, of which the divisor is zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::new(0, false);
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 0,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.divmod(a.clone()), (exp.clone(), exp.clone()));
+    /// assert_eq!(a.clone() / a.clone(), exp);
+    /// assert_eq!(a.clone() % a, exp);
+    /// ```
+    pub fn divmod(
+        &self,
+        mut right_nu: SvPrimaryLiteralIntegral,
+    ) -> (SvPrimaryLiteralIntegral, SvPrimaryLiteralIntegral) {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+
+        if left_nu.is_4state() != right_nu.is_4state() {
+            if !left_nu.is_4state() {
+                left_nu = left_nu.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        let signed: bool = left_nu.signed && right_nu.signed;
+        let final_num_bits: usize = std::cmp::max(left_nu.size, right_nu.size);
+
+        if left_nu.contains_xz() || right_nu.contains_xz() || right_nu.is_zero() {
+            let x_primlit = logic_x(final_num_bits, signed);
+            return (x_primlit.clone(), x_primlit);
+        }
+
+        let left_neg: bool = signed && left_nu.is_set_msb_01();
+        let right_neg: bool = signed && right_nu.is_set_msb_01();
+
+        let (quotient, remainder) = left_nu
+            ._magnitude(left_neg)
+            ._unsigned_primlit_divmod(right_nu._magnitude(right_neg));
+
+        (
+            quotient._signed_result(
+                left_neg != right_neg,
+                final_num_bits,
+                signed,
+                left_nu.is_4state(),
+            ),
+            remainder._signed_result(left_neg, final_num_bits, signed, left_nu.is_4state()),
+        )
+    }
+
+    /// Power between two integral primary literals, where the first operand is the base and the second the exponent.
+    /// The width and the sign of the result follow the base only, so the result wraps around instead of growing:
+    /// 3 ** 3 with a 4-bit base is 27 truncated to 4 bits, i.e. 11.
+    /// A negative exponent yields X for a base of 0, 1 for a base of 1, 1 or -1 for a base of -1 depending on the parity of the
+    /// exponent, and 0 for any other base. Any exponent equal to zero yields 1.
+    /// If any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Signed positive value raised to a signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1024],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Unsigned value raised to an unsigned value, where the result is truncated to the width of the base
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 64,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![11],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Signed negative value raised to an unsigned value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS raised to an unsigned value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4294967296, 0],
+    ///     data_xz: None,
+    ///     size: 72,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
+    ///     size: 72,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Signed value of -1 raised to a signed negative odd value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![13],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), a);
+    /// ```
+    /// Signed positive value raised to a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Zero raised to a signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![255]),
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    /// Zero raised to zero
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(a.clone()), exp);
+    /// ```
+    /// Value that contains X(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.pow_primlit(b), exp);
+    /// ```
+    pub fn pow_primlit(&self, exp: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(exp.validate(), Ok(()));
+
+        let final_num_bits: usize = self.size;
+        let signed: bool = self.signed;
+        let four_state: bool = self.is_4state() || exp.is_4state();
+
+        if self.contains_xz() || exp.contains_xz() {
+            return logic_x(final_num_bits, signed);
+        }
+
+        let one = usize_to_primlit(1)._signed_result(false, final_num_bits, false, false);
+        let mut base: SvPrimaryLiteralIntegral = self._magnitude(false);
+
+        if exp.signed && exp.is_set_msb_01() {
+            let minus_one = one._signed_result(true, final_num_bits, false, false);
+
+            if base.is_zero() {
+                return logic_x(final_num_bits, signed);
+            } else if base == one {
+                return one._signed_result(false, final_num_bits, signed, four_state);
+            } else if signed && base == minus_one {
+                let odd: bool = exp.data_01[0] & 1 == 1;
+                return one._signed_result(odd, final_num_bits, signed, four_state);
+            } else {
+                return bit1b_0()._signed_result(false, final_num_bits, signed, four_state);
+            }
+        }
+
+        // Exponentiation by squaring, truncating to the width of the base after every multiplication.
+        let exp_num_bits: usize = match exp.data_01.iter().rposition(|x| *x != 0) {
+            Some(x) => {
+                x * usize::BITS as usize + (usize::BITS - exp.data_01[x].leading_zeros()) as usize
+            }
+            None => 0,
+        };
+
+        let mut ret: SvPrimaryLiteralIntegral = one;
+
+        for x in 0..exp_num_bits {
+            if (exp.data_01[x / usize::BITS as usize] >> (x % usize::BITS as usize)) & 1 == 1 {
+                ret = ret.mul_unsigned(base.clone())._signed_result(
+                    false,
+                    final_num_bits,
+                    false,
+                    false,
+                );
+            }
+
+            if x + 1 < exp_num_bits {
+                base = base.mul_unsigned(base.clone())._signed_result(
+                    false,
+                    final_num_bits,
+                    false,
+                    false,
+                );
+            }
+        }
+
+        ret._signed_result(false, final_num_bits, signed, four_state)
+    }
+
+    /// Modular exponentiation "(base ** exp) % modulus", where all the operands are treated as unsigned values.
+    /// The result is unsigned and has the number of bits of the modulus, which also bounds the intermediate results
+    /// since they are reduced by the modulus after every step. It is only a 4-state value if any of the operands is.
+    /// If the modulus is zero or any of the operands contains X/Z(s), all the bits of the result are X.
+    ///
+    /// # Examples
+    ///
+    /// Small modular power, 4 ** 13 % 497
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let base = SvPrimaryLiteralIntegral::from_sv_literal("8'd4").unwrap();
+    /// let exp = SvPrimaryLiteralIntegral::from_sv_literal("8'd13").unwrap();
+    /// let modulus = SvPrimaryLiteralIntegral::from_sv_literal("16'd497").unwrap();
+    ///
+    /// let exp_result = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![445],
+    ///     data_xz: None,
+    ///     size: 16,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(base.pow_mod(exp, modulus), exp_result);
+    /// ```
+    /// Exponent with width > usize::BITS, 3 ** (2 ** 100 + 12345) % 1000003
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let base = SvPrimaryLiteralIntegral::from_sv_literal("2'd3").unwrap();
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12345, 68719476736],
+    ///     data_xz: None,
+    ///     size: 101,
+    ///     signed: false,
+    /// };
+    /// let modulus = SvPrimaryLiteralIntegral::from_sv_literal("32'd1000003").unwrap();
+    ///
+    /// assert_eq!(base.pow_mod(exp, modulus).data_01, vec![584341]);
+    /// ```
+    /// Zero exponent, and a modulus of one
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let base = SvPrimaryLiteralIntegral::from_sv_literal("8'd7").unwrap();
+    /// let zero = SvPrimaryLiteralIntegral::from_sv_literal("8'd0").unwrap();
+    /// let one = SvPrimaryLiteralIntegral::from_sv_literal("4'd1").unwrap();
+    /// let modulus = SvPrimaryLiteralIntegral::from_sv_literal("4'd5").unwrap();
+    ///
+    /// assert_eq!(base.pow_mod(zero.clone(), modulus), one);
+    /// assert_eq!(base.pow_mod(zero, one).data_01, vec![0]);
+    /// ```
+    /// Zero modulus, and an operand that contains X(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let base = SvPrimaryLiteralIntegral::from_sv_literal("8'd4").unwrap();
+    /// let exp = SvPrimaryLiteralIntegral::from_sv_literal("8'b0000_1x01").unwrap();
+    /// let modulus = SvPrimaryLiteralIntegral::from_sv_literal("16'd497").unwrap();
+    /// let zero = SvPrimaryLiteralIntegral::from_sv_literal("16'd0").unwrap();
+    ///
+    /// assert_eq!(base.pow_mod(base.clone(), zero), logic_x(16, false));
+    /// assert_eq!(base.pow_mod(exp, modulus), logic_x(16, false));
+    /// ```
+    pub fn pow_mod(
+        &self,
+        exp: SvPrimaryLiteralIntegral,
+        modulus: SvPrimaryLiteralIntegral,
+    ) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(exp.validate(), Ok(()));
+        debug_assert_eq!(modulus.validate(), Ok(()));
+
+        let final_num_bits: usize = modulus.size;
+        let four_state: bool = self.is_4state() || exp.is_4state() || modulus.is_4state();
+
+        if self.contains_xz() || exp.contains_xz() || modulus.contains_xz() || modulus.is_zero() {
+            return logic_x(final_num_bits, false);
+        }
+
+        let modulus: SvPrimaryLiteralIntegral = modulus._magnitude(false);
+        let reduce = |x: SvPrimaryLiteralIntegral| {
+            x.mod_primlit(modulus.clone())
+                ._signed_result(false, final_num_bits, false, false)
+        };
+
+        // Exponentiation by squaring, like pow_primlit, reducing by the modulus after every multiplication.
+        let exp_num_bits: usize = match exp.data_01.iter().rposition(|x| *x != 0) {
+            Some(x) => {
+                x * usize::BITS as usize + (usize::BITS - exp.data_01[x].leading_zeros()) as usize
+            }
+            None => 0,
+        };
+
+        let mut base: SvPrimaryLiteralIntegral = reduce(self._magnitude(false));
+        let mut ret: SvPrimaryLiteralIntegral = reduce(usize_to_primlit(1)._magnitude(false));
+
+        for x in 0..exp_num_bits {
+            if (exp.data_01[x / usize::BITS as usize] >> (x % usize::BITS as usize)) & 1 == 1 {
+                ret = reduce(ret.mul_unsigned(base.clone()));
+            }
+
+            if x + 1 < exp_num_bits {
+                base = reduce(base.mul_unsigned(base.clone()));
+            }
+        }
+
+        ret._signed_result(false, final_num_bits, false, four_state)
+    }
+
+    /// Bitwise AND between two integral primary literals.
+    /// The result has the number of bits of the widest operand and is only signed if both operands are signed.
+    /// A 0 in either operand yields 0, otherwise an X/Z in either operand yields X.
+    ///
+    /// # Examples
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// The bits 0, 1, X and Z AND/ed with X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![14]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a & b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z AND/ed with Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![14]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a & b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z AND/ed with 0
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a & b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z AND/ed with 1
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a & b, exp);
+    /// ```
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Signed negative value with width < usize::BITS AND/ed with a wider signed positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![63],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![62],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a & b, exp);
+    /// ```
+    pub fn and_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self._bitwise_primlit(right_nu, |left_01, left_xz, right_01, right_xz| {
+            let one = (left_01 & !left_xz) & (right_01 & !right_xz);
+            let zero = (!left_01 & !left_xz) | (!right_01 & !right_xz);
+
+            (one, !(one | zero))
+        })
+    }
+
+    /// Bitwise OR between two integral primary literals.
+    /// The result has the number of bits of the widest operand and is only signed if both operands are signed.
+    /// A 1 in either operand yields 1, otherwise an X/Z in either operand yields X.
+    ///
+    /// # Examples
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// The bits 0, 1, X and Z OR/ed with X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![13]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a | b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z OR/ed with Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![13]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a | b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z OR/ed with 0
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a | b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z OR/ed with 1
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a | b, exp);
+    /// ```
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Signed negative value with width < usize::BITS OR/ed with a wider unsigned value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![14],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![48],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![62],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a | b, exp);
+    /// ```
+    pub fn or_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self._bitwise_primlit(right_nu, |left_01, left_xz, right_01, right_xz| {
+            let one = (left_01 & !left_xz) | (right_01 & !right_xz);
+            let zero = (!left_01 & !left_xz) & (!right_01 & !right_xz);
+
+            (one, !(one | zero))
+        })
+    }
+
+    /// Bitwise XOR between two integral primary literals.
+    /// The result has the number of bits of the widest operand and is only signed if both operands are signed.
+    /// An X/Z in either operand yields X.
+    ///
+    /// # Examples
+    ///
+    /// ## 4-State Primary Literals (Containing X/Z(s))
+    ///
+    /// The bits 0, 1, X and Z XOR/ed with 1
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a ^ b, exp);
+    /// ```
+    /// The bits 0, 1, X and Z XOR/ed with 0
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![12]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a ^ b, exp);
+    /// ```
+    ///
+    /// ## 2-State Primary Literals
+    ///
+    /// Unsigned value XOR/ed with an unsigned value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![6],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a ^ b, exp);
+    /// ```
+    pub fn xor_primlit(&self, right_nu: SvPrimaryLiteralIntegral) -> SvPrimaryLiteralIntegral {
+        self._bitwise_primlit(right_nu, |left_01, left_xz, right_01, right_xz| {
+            let xz = left_xz | right_xz;
+
+            ((left_01 ^ right_01) & !xz, xz)
+        })
+    }
+
+    /** Applies a bitwise operation to two integral primary literals, element by element.
+    The operands are sign extended if both are signed and zero extended otherwise, before the operation receives the data_01 and data_xz
+    elements of both operands and returns the data_01 and data_xz elements of the result. */
+    fn _bitwise_primlit(
+        &self,
+        mut right_nu: SvPrimaryLiteralIntegral,
+        op: fn(usize, usize, usize, usize) -> (usize, usize),
+    ) -> SvPrimaryLiteralIntegral {
+        debug_assert_eq!(self.validate(), Ok(()));
+        debug_assert_eq!(right_nu.validate(), Ok(()));
+
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+
+        if left_nu.is_4state() != right_nu.is_4state() {
+            if !left_nu.is_4state() {
+                left_nu = left_nu.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        let final_num_bits: usize = std::cmp::max(left_nu.size, right_nu.size);
+
+        if left_nu.signed && right_nu.signed {
+            left_nu._matched_sign_extend(&mut right_nu);
+        } else {
+            left_nu.signed = false;
+            right_nu.signed = false;
+            left_nu._matched_zero_extend(&mut right_nu);
+        }
+
+        let mut ret: SvPrimaryLiteralIntegral = left_nu.clone();
+
+        for x in 0..ret.data_01.len() {
+            let (left_xz, right_xz) = match (&left_nu.data_xz, &right_nu.data_xz) {
+                (Some(left_xz), Some(right_xz)) => (left_xz[x], right_xz[x]),
+                _ => (0, 0),
+            };
+
+            let (data_01, data_xz) = op(left_nu.data_01[x], left_xz, right_nu.data_01[x], right_xz);
+
+            ret.data_01[x] = data_01;
+            if let Some(ret_xz) = ret.data_xz.as_mut() {
+                ret_xz[x] = data_xz;
+            }
+        }
+
+        ret._truncate(final_num_bits);
+
+        ret
+    }
+
+    /// Reduction AND of an integral primary literal, returning a single bit.
+    /// The result is 0 if any bit is 0, otherwise X if any bit is X/Z, otherwise 1.
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with all bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_and(), bit1b_1());
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS and one bit unset
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 3],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_and(), bit1b_0());
+    /// ```
+    /// Value containing a 0 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_and(), logic1b_0());
+    /// ```
+    /// Value containing a 1 and a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_and(), logic1b_x());
+    /// ```
+    pub fn reduce_and(&self) -> SvPrimaryLiteralIntegral {
+        let (zero, _, xz) = self._reduction_bits();
+
+        if zero {
+            self._reduction_result(false)
+        } else if xz {
+            logic1b_x()
+        } else {
+            self._reduction_result(true)
+        }
+    }
+
+    /// Reduction OR of an integral primary literal, returning a single bit.
+    /// The result is 1 if any bit is 1, otherwise X if any bit is X/Z, otherwise 0.
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with all bits unset
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_or(), bit1b_0());
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS and the MSB set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_or(), bit1b_1());
+    /// ```
+    /// Value containing a 1 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_or(), logic1b_1());
+    /// ```
+    /// Value containing a 0 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_or(), logic1b_x());
+    /// ```
+    pub fn reduce_or(&self) -> SvPrimaryLiteralIntegral {
+        let (_, one, xz) = self._reduction_bits();
+
+        if one {
+            self._reduction_result(true)
+        } else if xz {
+            logic1b_x()
+        } else {
+            self._reduction_result(false)
+        }
+    }
+
+    /// Reduction XOR of an integral primary literal, returning a single bit.
+    /// The result is X if any bit is X/Z, otherwise 1 if an odd number of bits is 1, otherwise 0.
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with an odd number of bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_xor(), bit1b_1());
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS and an even number of bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_xor(), logic1b_0());
+    /// ```
+    /// Value containing a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_xor(), logic1b_x());
+    /// ```
+    pub fn reduce_xor(&self) -> SvPrimaryLiteralIntegral {
+        let (_, _, xz) = self._reduction_bits();
+
+        if xz {
+            logic1b_x()
+        } else {
+            let ones: u32 = self.data_01.iter().map(|x| x.count_ones()).sum();
+            self._reduction_result(ones % 2 == 1)
+        }
+    }
+
+    /// Reduction NAND of an integral primary literal, returning a single bit.
+    ///
+    /// # Examples
+    ///
+    /// Value containing a 0 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_nand(), logic1b_1());
+    /// ```
+    pub fn reduce_nand(&self) -> SvPrimaryLiteralIntegral {
+        self.reduce_and().inv()
+    }
+
+    /// Reduction NOR of an integral primary literal, returning a single bit.
+    ///
+    /// # Examples
+    ///
+    /// Value with all bits unset
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_nor(), bit1b_1());
+    /// ```
+    pub fn reduce_nor(&self) -> SvPrimaryLiteralIntegral {
+        self.reduce_or().inv()
+    }
+
+    /// Reduction XNOR of an integral primary literal, returning a single bit.
+    ///
+    /// # Examples
+    ///
+    /// Value with an odd number of bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_xnor(), logic1b_0());
+    /// ```
+    /// Value containing an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.reduce_xnor(), logic1b_x());
+    /// ```
+    pub fn reduce_xnor(&self) -> SvPrimaryLiteralIntegral {
+        self.reduce_xor().inv()
+    }
+
+    /// Counts the bits within the width that are 1, like $countones.
+    /// Bits that are X/Z are not counted.
+    ///
+    /// # Examples
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 5],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.count_ones(), 66);
+    /// ```
+    /// Value containing a 1, an X and a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: Some(vec![6]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.count_ones(), 1);
+    /// ```
+    /// Value with X/Z(s) only
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.count_ones(), 0);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        let mut ret: usize = 0;
+
+        for x in 0..self.data_01.len() {
+            let bits: usize = self.size.saturating_sub(x * usize::BITS as usize);
+            let mask: usize = if bits >= usize::BITS as usize {
+                usize::MAX
+            } else {
+                (1 << bits) - 1
+            };
+
+            let data_xz: usize = match &self.data_xz {
+                Some(data_xz) => data_xz[x],
+                None => 0,
+            };
+
+            ret += (self.data_01[x] & mask).count_ones() as usize;
+            ret -= (self.data_01[x] & data_xz & mask).count_ones() as usize;
+        }
+
+        ret
+    }
+
+    /// Checks whether exactly one bit is 1, like $onehot, returning a single bit.
+    /// The result is X if the value contains X/Z(s).
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with one bit set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot(), bit1b_1());
+    /// ```
+    /// Value with no bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot(), logic1b_0());
+    /// ```
+    /// Value containing a 1 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot(), logic1b_x());
+    /// ```
+    pub fn is_onehot(&self) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() {
+            logic1b_x()
+        } else {
+            self._reduction_result(self.count_ones() == 1)
+        }
+    }
+
+    /// Checks whether at most one bit is 1, like $onehot0, returning a single bit.
+    /// The result is X if the value contains X/Z(s).
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with no bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot0(), bit1b_1());
+    /// ```
+    /// Value with two bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 4],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot0(), bit1b_0());
+    /// ```
+    /// Value containing a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![2],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.is_onehot0(), logic1b_x());
+    /// ```
+    pub fn is_onehot0(&self) -> SvPrimaryLiteralIntegral {
+        if self.contains_xz() {
+            logic1b_x()
+        } else {
+            self._reduction_result(self.count_ones() <= 1)
+        }
+    }
+
+    /// Logical negation of an integral primary literal, returning a single bit as `!` in systemverilog.
+    /// The result is 0 if any bit is 1, otherwise X if any bit is X/Z, otherwise 1.
+    /// The result is a 4-state value only if the operand is a 4-state value.
+    ///
+    /// # Examples
+    ///
+    /// Value with no bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.logical_not(), bit1b_1());
+    /// ```
+    /// Value containing a 1 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 3,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.logical_not(), logic1b_0());
+    /// ```
+    /// Value containing a 0 and a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.logical_not(), logic1b_x());
+    /// ```
+    pub fn logical_not(&self) -> SvPrimaryLiteralIntegral {
+        let (_, one, xz) = self._reduction_bits();
+
+        if one {
+            self._reduction_result(false)
+        } else if xz {
+            logic1b_x()
+        } else {
+            self._reduction_result(true)
+        }
+    }
+
+    /// Returns the truth value of an integral primary literal as in an `if` in systemverilog.
+    /// The value is true if any bit is 1, otherwise `None` if any bit is X/Z, otherwise false.
+    ///
+    /// # Examples
+    ///
+    /// Value with no bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![0]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_bool(), Some(false));
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS and the MSB set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 4],
+    ///     data_xz: None,
+    ///     size: 67,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.to_bool(), Some(true));
+    /// ```
+    /// Value containing a 0 and an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_bool(), None);
+    /// ```
+    pub fn to_bool(&self) -> Option<bool> {
+        let (_, one, xz) = self._reduction_bits();
+
+        if one {
+            Some(true)
+        } else if xz {
+            None
+        } else {
+            Some(false)
+        }
+    }
+
+    /// Selects between two integral primary literals as the conditional operator `sel ? a : b` in systemverilog.
+    /// Both values are first matched to the number of bits of the widest value and are only signed if both are signed.
+    /// If `sel` is true the result is `a`, if it is false the result is `b`.
+    /// If `sel` is X/Z, the bits on which `a` and `b` agree are kept and all other bits are X.
+    ///
+    /// # Examples
+    ///
+    /// Select that is true
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 2,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::mux(&bit1b_1(), &a, &b), exp);
+    /// ```
+    /// Select that is X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![12],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![10],
+    ///     data_xz: None,
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![8],
+    ///     data_xz: Some(vec![6]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::mux(&logic1b_x(), &a, &b), exp);
+    /// ```
+    /// Select that is Z, with a signed value of usize::BITS < width < 2 * usize::BITS containing a Z
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let sel = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![1]),
+    ///     size: 1,
+    ///     signed: false,
+    /// };
+    ///
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5, 1],
+    ///     data_xz: Some(vec![1, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// let b = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4, 1],
+    ///     data_xz: Some(vec![18446744073709551609, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::mux(&sel, &a, &b), exp);
+    /// ```
+    pub fn mux(
+        sel: &SvPrimaryLiteralIntegral,
+        a: &SvPrimaryLiteralIntegral,
+        b: &SvPrimaryLiteralIntegral,
+    ) -> SvPrimaryLiteralIntegral {
+        let mut left_nu: SvPrimaryLiteralIntegral = a.clone();
+        let mut right_nu: SvPrimaryLiteralIntegral = b.clone();
+
+        if left_nu.is_4state() != right_nu.is_4state() {
+            if !left_nu.is_4state() {
+                left_nu = left_nu.to_4state();
+            } else {
+                right_nu = right_nu.to_4state();
+            }
+        }
+
+        let final_num_bits: usize = std::cmp::max(left_nu.size, right_nu.size);
+
+        if left_nu.signed && right_nu.signed {
+            left_nu._matched_sign_extend(&mut right_nu);
+        } else {
+            left_nu.signed = false;
+            right_nu.signed = false;
+            left_nu._matched_zero_extend(&mut right_nu);
+        }
+
+        let mut ret: SvPrimaryLiteralIntegral = match sel.to_bool() {
+            Some(true) => left_nu,
+            Some(false) => right_nu,
+            None => {
+                if !left_nu.is_4state() {
+                    left_nu = left_nu.to_4state();
+                    right_nu = right_nu.to_4state();
+                }
+
+                let mut ret: SvPrimaryLiteralIntegral = left_nu.clone();
+
+                // Only bits that are the same 0 or 1 in both values are kept.
+                for x in 0..ret.data_01.len() {
+                    let left_xz: usize = ret.data_xz.as_ref().unwrap()[x];
+                    let right_xz: usize = right_nu.data_xz.as_ref().unwrap()[x];
+                    let agree: usize =
+                        !(ret.data_01[x] ^ right_nu.data_01[x]) & !left_xz & !right_xz;
+
+                    ret.data_01[x] &= agree;
+                    ret.data_xz.as_mut().unwrap()[x] = !agree;
+                }
+
+                ret
+            }
+        };
+
+        ret._truncate(final_num_bits);
+
+        ret
+    }
+
+    // Returns whether any of the bits within the width is 0, 1 or X/Z respectively.
+    fn _reduction_bits(&self) -> (bool, bool, bool) {
+        let mut zero: bool = false;
+        let mut one: bool = false;
+        let mut xz: bool = false;
+
+        for x in 0..self.data_01.len() {
+            let bits: usize = self.size - x * usize::BITS as usize;
+            let mask: usize = if bits >= usize::BITS as usize {
+                usize::MAX
+            } else {
+                (1 << bits) - 1
+            };
+
+            let data_xz: usize = match &self.data_xz {
+                Some(data_xz) => data_xz[x],
+                None => 0,
+            };
+
+            zero |= (!self.data_01[x] & !data_xz & mask) != 0;
+            one |= (self.data_01[x] & !data_xz & mask) != 0;
+            xz |= (data_xz & mask) != 0;
+        }
+
+        (zero, one, xz)
+    }
+
+    // Returns a 0 or 1 single bit with the same state as the integral primary literal.
+    fn _reduction_result(&self, value: bool) -> SvPrimaryLiteralIntegral {
+        match (self.is_4state(), value) {
+            (false, false) => bit1b_0(),
+            (false, true) => bit1b_1(),
+            (true, false) => logic1b_0(),
+            (true, true) => logic1b_1(),
+        }
+    }
+
+    /// Returns the absolute value of a 2-state or 4-state integral primary literal without X/Z(s) as a 2-state unsigned value.
+    /// The value is negated if `negative` is set, in which case one extra bit holds the magnitude of the most negative value.
+    fn _magnitude(&self, negative: bool) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        ret.data_xz = None;
+
+        if negative {
+            let mut zero = bit1b_0();
+            zero.signed = true;
+            ret = zero.sub_primlit(ret);
+        }
+
+        ret.signed = false;
+        ret
+    }
+
+    /// Turns an unsigned magnitude, which must fit in `size` bits, into a result with the given number of bits, sign and state.
+    fn _signed_result(
+        &self,
+        negative: bool,
+        size: usize,
+        signed: bool,
+        four_state: bool,
+    ) -> SvPrimaryLiteralIntegral {
+        let mut ret: SvPrimaryLiteralIntegral = self.clone();
+        ret.signed = false;
+
+        if ret.size > size {
+            ret._truncate(size);
+        } else {
+            let mut matched_prim_lit = bit1b_0();
+            for _x in 1..((size + usize::BITS as usize - 1) / usize::BITS as usize) {
+                matched_prim_lit.data_01.push(0);
+            }
+
+            ret._matched_zero_extend(&mut matched_prim_lit);
+            ret._truncate(size);
+        }
+
+        if negative {
+            ret = bit1b_0().sub_primlit(ret);
+            ret._truncate(size);
+        }
+
+        ret.signed = signed;
+        if four_state {
+            ret = ret.to_4state();
+        }
+
+        ret
+    }
+
+    /** Parses the text of an integer literal as defined in 1800-2017 | 5.7.1 Integer literal constants.
+    The literal consists of an optional size, an apostrophe, an optional signedness, a base and its digits, or of decimal digits only.
+    Unsized literals have DEFAULT_UNSIZED_WIDTH bits, or more if their digits need more bits, and unbased unsized literals such as '1
+    fill all of these bits. Literals with fewer digits than their size are zero extended, unless the leftmost digit is an X/Z which is
+    then extended instead. Literals with more digits than their size are truncated.
+    The value is a 4-state value only if the literal contains X/Z(s). */
+    /// # Examples
+    ///
+    /// Sized hexadecimal literal
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("8'hFF"), Ok(exp));
+    /// ```
+    /// Sized binary literal with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("4'b10xz"), Ok(exp));
+    /// ```
+    /// Signed decimal literal with a negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![65533],
+    ///     data_xz: None,
+    ///     size: 16,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("16'sd-3"), Ok(exp));
+    /// ```
+    /// Sized hexadecimal literal extended with the X of its leftmost digit
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![4080]),
+    ///     size: 12,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("12'hx1"), Ok(exp));
+    /// ```
+    /// Decimal literal with a single Z digit
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![15],
+    ///     data_xz: Some(vec![15]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("4'dz"), Ok(exp));
+    /// ```
+    /// Sized literal truncated to its size
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("8'hF_FF"), Ok(exp));
+    /// ```
+    /// Unbased unsized literal
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![4294967295],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("'1"), Ok(exp));
+    /// ```
+    /// Unsized literal wider than the default width
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: None,
+    ///     size: 68,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("'h1_0000_0000_0000_0000"), Ok(exp));
+    /// ```
+    /// Decimal number
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![42],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_sv_literal("42"), Ok(exp));
+    /// ```
+    /// Decimal literal with X/Z(s) and other digits
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// assert!(SvPrimaryLiteralIntegral::from_sv_literal("8'd1x").is_err());
+    /// ```
+    pub fn from_sv_literal(s: &str) -> Result<SvPrimaryLiteralIntegral, String> {
+        let literal: String = s.chars().filter(|x| !x.is_whitespace()).collect();
+
+        let (size, rest): (Option<usize>, &str) = match literal.find('\'') {
+            None => (None, literal.as_str()),
+            Some(0) => (None, &literal[1..]),
+            Some(x) => match literal[..x].replace('_', "").parse::<usize>() {
+                Ok(0) | Err(_) => return Err(format!("Invalid size in the literal {}!", s)),
+                Ok(size) => (Some(size), &literal[x + 1..]),
+            },
+        };
+
+        // Decimal numbers without an apostrophe are signed
+        if !literal.contains('\'') {
+            return SvPrimaryLiteralIntegral::_from_digits(s, rest, 10, None, true);
+        }
+
+        // Unbased unsized literals set all of the bits to the same value
+        if size.is_none() && matches!(rest, "0" | "1" | "x" | "X" | "z" | "Z" | "?") {
+            return SvPrimaryLiteralIntegral::_from_digits(
+                s,
+                &rest.repeat(DEFAULT_UNSIZED_WIDTH),
+                2,
+                None,
+                false,
+            );
+        }
+
+        let (signed, rest): (bool, &str) = match rest.chars().next() {
+            Some('s') | Some('S') => (true, &rest[1..]),
+            _ => (false, rest),
+        };
+
+        let base: u32 = match rest.chars().next() {
+            Some('b') | Some('B') => 2,
+            Some('o') | Some('O') => 8,
+            Some('d') | Some('D') => 10,
+            Some('h') | Some('H') => 16,
+            _ => return Err(format!("Invalid base in the literal {}!", s)),
+        };
+
+        SvPrimaryLiteralIntegral::_from_digits(s, &rest[1..], base, size, signed)
+    }
+
+    // Converts the digits of a literal in the given base into a value of the given size.
+    fn _from_digits(
+        s: &str,
+        digits: &str,
+        base: u32,
+        size: Option<usize>,
+        signed: bool,
+    ) -> Result<SvPrimaryLiteralIntegral, String> {
+        let (negative, digits): (bool, String) = match digits.strip_prefix('-') {
+            Some(x) if base == 10 => (true, x.replace('_', "")),
+            _ => (false, digits.replace('_', "")),
+        };
+
+        if digits.is_empty() {
+            return Err(format!("Missing digits in the literal {}!", s));
+        }
+
+        let is_xz = |x: char| matches!(x, 'x' | 'X' | 'z' | 'Z' | '?');
+        let bits_per_digit: usize = match base {
+            2 => 1,
+            8 => 3,
+            16 => 4,
+            _ => usize::BITS as usize,
+        };
+
+        let mut data_01: Vec<usize> = vec![0];
+        let mut data_xz: Vec<usize> = vec![0];
+        let num_bits: usize;
+
+        // A decimal literal is either a number or a single X/Z digit which applies to all of the bits
+        if base == 10 && !digits.chars().any(is_xz) {
+            for digit in digits.chars() {
+                let mut carry: usize = digit
+                    .to_digit(10)
+                    .ok_or(format!("Invalid digit {} in the literal {}!", digit, s))?
+                    as usize;
+
+                for x in 0..data_01.len() {
+                    let value: u128 = data_01[x] as u128 * 10 + carry as u128;
+                    data_01[x] = value as usize;
+                    carry = (value >> usize::BITS) as usize;
+                }
+
+                if carry != 0 {
+                    data_01.push(carry);
+                    data_xz.push(0);
+                }
+            }
+
+            num_bits = match data_01.iter().rposition(|x| *x != 0) {
+                Some(x) => {
+                    x * usize::BITS as usize + (usize::BITS - data_01[x].leading_zeros()) as usize
+                }
+                None => 1,
+            };
+        } else if base == 10 && digits.len() > 1 {
+            return Err(format!(
+                "Decimal literal {} cannot contain X/Z with other digits!",
+                s
+            ));
+        } else {
+            num_bits = digits.len() * bits_per_digit;
+            data_01 = vec![0; (num_bits + usize::BITS as usize - 1) / usize::BITS as usize];
+            data_xz = data_01.clone();
+
+            for (x, digit) in digits.chars().rev().enumerate() {
+                let (value_01, value_xz): (usize, usize) = match digit {
+                    'x' | 'X' => (0, usize::MAX),
+                    'z' | 'Z' | '?' => (usize::MAX, usize::MAX),
+                    _ => match digit.to_digit(base) {
+                        Some(value) => (value as usize, 0),
+                        None => {
+                            return Err(format!("Invalid digit {} in the literal {}!", digit, s))
+                        }
+                    },
+                };
+
+                for y in 0..bits_per_digit {
+                    let bit: usize = x * bits_per_digit + y;
+
+                    data_01[bit / usize::BITS as usize] |=
+                        ((value_01 >> y) & 1) << (bit % usize::BITS as usize);
+                    data_xz[bit / usize::BITS as usize] |=
+                        ((value_xz >> y) & 1) << (bit % usize::BITS as usize);
+                }
+            }
+        }
+
+        let final_num_bits: usize = match size {
+            Some(size) => size,
+            None if base == 10 && digits.chars().any(is_xz) => DEFAULT_UNSIZED_WIDTH,
+            None => std::cmp::max(num_bits, DEFAULT_UNSIZED_WIDTH),
+        };
+
+        let mut ret = SvPrimaryLiteralIntegral {
+            data_01,
+            data_xz: Some(data_xz),
+            size: num_bits,
+            signed: false,
+        };
+
+        if num_bits > final_num_bits {
+            ret._truncate(final_num_bits);
+        } else {
+            // The leftmost X/Z digit, or every bit of a decimal X/Z digit, is extended to the size.
+            let msb_01: bool = ret.is_set_msb_01();
+            let msb_xz: bool = ret.is_set_msb_xz();
+            let first_bit: usize = if base == 10 && msb_xz { 0 } else { num_bits };
+
+            for _x in ret.data_01.len()
+                ..((final_num_bits + usize::BITS as usize - 1) / usize::BITS as usize)
+            {
+                ret.data_01.push(0);
+                ret.data_xz.as_mut().unwrap().push(0);
+            }
+
+            if msb_xz {
+                for x in first_bit..final_num_bits {
+                    ret.data_xz.as_mut().unwrap()[x / usize::BITS as usize] |=
+                        1 << (x % usize::BITS as usize);
+                    if msb_01 {
+                        ret.data_01[x / usize::BITS as usize] |= 1 << (x % usize::BITS as usize);
+                    }
+                }
+            }
+
+            ret.size = final_num_bits;
+        }
+
+        if negative {
+            ret = bit1b_0().sub_primlit(ret);
+            ret._truncate(final_num_bits);
+        }
+
+        if !ret.contains_xz() {
+            ret.data_xz = None;
+        }
+        ret.signed = signed;
+
+        Ok(ret)
+    }
+
+    /** Renders the value as a sized literal in the given radix, i.e. 2, 8 or 16, e.g. 8'hff or 4'sb10xz.
+    A digit which only contains Z(s) is rendered as z, while a digit which contains any other X/Z(s) is rendered as x.
+    The Display implementation uses this with a radix of 16, or of 2 if the value contains X/Z(s). */
+    /// # Examples
+    ///
+    /// Unsigned value without X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![255],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_string(), "8'hff");
+    /// ```
+    /// Signed value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.to_string(), "65'sh1ffffffffffffffff");
+    /// ```
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_string(), "4'b10xz");
+    /// ```
+    /// Value with X/Z(s) in hexadecimal
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![241],
+    ///     data_xz: Some(vec![242]),
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_string_radix(16), "8'hzx");
+    /// ```
+    /// Value in octal
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![511],
+    ///     data_xz: None,
+    ///     size: 9,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_string_radix(8), "9'o777");
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        self.to_string_radix_grouped(radix, 0)
+    }
+
+    /// Same as to_string_radix, but separates every group of digits, counted from the LSB, with an underscore.
+    /// A group of 0 digits inserts no underscores.
+    ///
+    /// # Examples
+    ///
+    /// Value with width = 32 bits in hexadecimal
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3735928559],
+    ///     data_xz: None,
+    ///     size: 32,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_string_radix_grouped(16, 4), "32'hdead_beef");
+    /// assert_eq!(
+    ///     SvPrimaryLiteralIntegral::from_sv_literal("32'hdead_beef"),
+    ///     Ok(a)
+    /// );
+    /// ```
+    /// Value with X/Z(s) in binary, with a partial group at the MSB
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.to_string_radix_grouped(2, 4), "6'sb00_10xz");
+    /// assert_eq!(a.to_string_radix_grouped(2, 0), a.to_string_radix(2));
+    /// ```
+    pub fn to_string_radix_grouped(&self, radix: u32, group: usize) -> String {
+        let (base, bits_per_digit): (char, usize) = match radix {
+            2 => ('b', 1),
+            8 => ('o', 3),
+            16 => ('h', 4),
+            _ => panic!("Expected a radix of 2, 8 or 16 but found {}!", radix),
+        };
+
+        let mut digits: String = self._radix_digits(bits_per_digit);
+
+        // The digits are ASCII, so they can be split into groups from the LSB as bytes
+        if group != 0 {
+            let mut groups: Vec<&str> = digits
+                .as_bytes()
+                .rchunks(group)
+                .map(|x| std::str::from_utf8(x).unwrap())
+                .collect();
+            groups.reverse();
+            digits = groups.join("_");
+        }
+
+        format!(
+            "{}'{}{}{}",
+            self.size,
+            if self.signed { "s" } else { "" },
+            base,
+            digits
+        )
+    }
+
+    /// Renders the bits of the value in binary, from the MSB to the LSB, with an x or z for every X/Z.
+    /// There is one digit for every bit of the width.
+    ///
+    /// # Examples
+    ///
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 6,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_bin_string(), "0010xz");
+    /// ```
+    pub fn to_bin_string(&self) -> String {
+        self._radix_digits(1)
+    }
+
+    /// Returns the bits of the value as 0, 1, x or z characters, from the MSB to the LSB.
+    /// There is exactly one character for every bit of the width.
+    ///
+    /// # Examples
+    ///
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 6,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_bit_vector(), vec!['0', '0', '1', '0', 'x', 'z']);
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 2],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: true,
+    /// };
+    ///
+    /// let bits = a.to_bit_vector();
+    ///
+    /// assert_eq!(bits.len(), 66);
+    /// assert_eq!(bits[..2], ['1', '0']);
+    /// assert_eq!(bits.iter().filter(|x| **x == '1').count(), 2);
+    /// assert_eq!(bits[65], '1');
+    /// ```
+    pub fn to_bit_vector(&self) -> Vec<char> {
+        let mut ret: Vec<char> = Vec::with_capacity(self.size);
+
+        for x in (0..self.size).rev() {
+            let elmnt: usize = x / usize::BITS as usize;
+            let bit: usize = x % usize::BITS as usize;
+
+            let value_01: usize = self.data_01.get(elmnt).map_or(0, |y| (y >> bit) & 1);
+            let value_xz: usize = match &self.data_xz {
+                Some(data_xz) => data_xz.get(elmnt).map_or(0, |y| (y >> bit) & 1),
+                None => 0,
+            };
+
+            ret.push(match (value_01, value_xz) {
+                (0, 0) => '0',
+                (1, 0) => '1',
+                (0, _) => 'x',
+                _ => 'z',
+            });
+        }
+
+        ret
+    }
+
+    /** Builds an integral primary literal from 0, 1, x/X or z/Z characters, from the MSB to the LSB.
+    The size is the number of characters and the value is only a 4-state value if it contains X/Z(s). */
+    /// # Examples
+    ///
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![9],
+    ///     data_xz: Some(vec![3]),
+    ///     size: 6,
+    ///     signed: true,
+    /// };
+    ///
+    /// let a = SvPrimaryLiteralIntegral::from_bit_vector(&['0', '0', '1', '0', 'X', 'z'], true);
+    ///
+    /// assert_eq!(a, Ok(exp.clone()));
+    /// assert_eq!(exp.to_bit_vector(), vec!['0', '0', '1', '0', 'x', 'z']);
+    /// ```
+    /// Value with an unknown character
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// assert!(SvPrimaryLiteralIntegral::from_bit_vector(&['1', '?'], false).is_err());
+    /// ```
+    pub fn from_bit_vector(
+        bits: &[char],
+        signed: bool,
+    ) -> Result<SvPrimaryLiteralIntegral, String> {
+        if bits.is_empty() {
+            return Err(String::from("Expected at least one bit!"));
+        }
+
+        let mut data_01: Vec<usize> =
+            vec![0; (bits.len() + usize::BITS as usize - 1) / usize::BITS as usize];
+        let mut data_xz: Vec<usize> = data_01.clone();
+
+        for (x, bit) in bits.iter().rev().enumerate() {
+            let (value_01, value_xz): (usize, usize) = match bit {
+                '0' => (0, 0),
+                '1' => (1, 0),
+                'x' | 'X' => (0, 1),
+                'z' | 'Z' => (1, 1),
+                _ => return Err(format!("Invalid bit {}!", bit)),
+            };
+
+            data_01[x / usize::BITS as usize] |= value_01 << (x % usize::BITS as usize);
+            data_xz[x / usize::BITS as usize] |= value_xz << (x % usize::BITS as usize);
+        }
+
+        let mut ret = SvPrimaryLiteralIntegral {
+            data_01,
+            data_xz: Some(data_xz),
+            size: bits.len(),
+            signed,
+        };
+
+        if !ret.contains_xz() {
+            ret.data_xz = None;
+        }
+
+        Ok(ret)
+    }
+
+    /** Parses a VCD binary vector value, e.g. b10x1z, with one digit of 0, 1, x/X or z/Z for every bit from the MSB to the LSB.
+    The leading b/B is optional and the size is the number of digits, i.e. a compressed value is not extended.
+    The value is only a 4-state value if it contains X/Z(s). */
+    /// # Examples
+    ///
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![19],
+    ///     data_xz: Some(vec![5]),
+    ///     size: 5,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_vcd_binary("b10x1z", false), Ok(exp));
+    /// ```
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let exp = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1, 2],
+    ///     data_xz: None,
+    ///     size: 66,
+    ///     signed: true,
+    /// };
+    ///
+    /// let mut s = String::from("10");
+    /// s.push_str(&"0".repeat(63));
+    /// s.push('1');
+    ///
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_vcd_binary(&s, true), Ok(exp));
+    /// ```
+    /// Value with an invalid digit
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// assert!(SvPrimaryLiteralIntegral::from_vcd_binary("b1012", false).is_err());
+    /// ```
+    pub fn from_vcd_binary(s: &str, signed: bool) -> Result<SvPrimaryLiteralIntegral, String> {
+        let digits: &str = s
+            .strip_prefix('b')
+            .or_else(|| s.strip_prefix('B'))
+            .unwrap_or(s);
+        let bits: Vec<char> = digits.chars().collect();
+
+        SvPrimaryLiteralIntegral::from_bit_vector(&bits, signed)
+            .map_err(|x| format!("Invalid VCD value {}: {}", s, x))
+    }
+
+    /** Renders the value as a compressed VCD binary vector value, e.g. b10x1z.
+    Leading digits are dropped as long as a VCD reader extends the remaining digits back to the same value,
+    i.e. leading 0s before a 1, and all but one of the leading 0s, Xs or Zs otherwise. */
+    /// # Examples
+    ///
+    /// Value with leading 0s before a 1
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![5],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_vcd_binary(), "b101");
+    /// ```
+    /// Value with leading 0s before an X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![1],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_vcd_binary(), "b0x1");
+    /// ```
+    /// Value with leading Zs
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![60],
+    ///     data_xz: Some(vec![60]),
+    ///     size: 6,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_vcd_binary(), "bz00");
+    /// assert_eq!(SvPrimaryLiteralIntegral::from_vcd_binary("bz00", false).unwrap().size, 3);
+    /// ```
+    /// Value with all bits unset
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 0],
+    ///     data_xz: None,
+    ///     size: 70,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_vcd_binary(), "b0");
+    /// ```
+    /// Value with all bits set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![7],
+    ///     data_xz: None,
+    ///     size: 3,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.to_vcd_binary(), "b111");
+    /// ```
+    pub fn to_vcd_binary(&self) -> String {
+        let digits: String = self.to_bit_vector().into_iter().collect();
+        let first: char = digits.chars().next().unwrap_or('0');
+        let rest: &str = digits.trim_start_matches(first);
+
+        let compressed: String = if first == '1' {
+            digits.clone()
+        } else if rest.is_empty() {
+            first.to_string()
+        } else if first == '0' && rest.starts_with('1') {
+            rest.to_string()
+        } else {
+            format!("{}{}", first, rest)
+        };
+
+        format!("b{}", compressed)
+    }
+
+    /// Renders the value in hexadecimal, from the MSB to the LSB.
+    /// A nibble which only contains Z(s) is rendered as z, while a nibble which contains any other X/Z(s) is rendered as x.
+    /// The MSB nibble only covers the remaining bits of the width.
+    ///
+    /// # Examples
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18364758544493064720, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_hex_string(), "1fedcba9876543210");
+    /// ```
+    /// Value with a nibble of Z(s), a nibble mixing X and Z and a nibble mixing 0 and X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![3856],
+    ///     data_xz: Some(vec![3890]),
+    ///     size: 12,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_hex_string(), "zxx");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        self._radix_digits(4)
+    }
+
+    /// Renders the value in octal, from the MSB to the LSB.
+    /// A group of 3 bits which only contains Z(s) is rendered as z, while a group which contains any other X/Z(s) is rendered as x.
+    /// The MSB group only covers the remaining bits of the width.
+    ///
+    /// # Examples
+    ///
+    /// Value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![18446744073709551615, 1],
+    ///     data_xz: None,
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_oct_string(), "3777777777777777777777");
+    /// ```
+    /// Value with a group of Z(s), a group mixing X and Z and a group mixing 1 and X
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![469],
+    ///     data_xz: Some(vec![497]),
+    ///     size: 9,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_oct_string(), "zxx");
+    /// ```
+    pub fn to_oct_string(&self) -> String {
+        self._radix_digits(3)
+    }
+
+    /// Renders the value in decimal, with a leading - for negative signed values.
+    /// Returns None if the value contains X/Z(s), since these cannot be represented in decimal.
+    ///
+    /// # Examples
+    ///
+    /// Signed negative value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![253],
+    ///     data_xz: None,
+    ///     size: 8,
+    ///     signed: true,
+    /// };
+    ///
+    /// assert_eq!(a.to_dec_string(), Some(String::from("-3")));
+    /// ```
+    /// Unsigned value with usize::BITS < width < 2 * usize::BITS
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0, 1],
+    ///     data_xz: Some(vec![0, 0]),
+    ///     size: 65,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_dec_string(), Some(String::from("18446744073709551616")));
+    /// ```
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral {
+    ///     data_01: vec![0],
+    ///     data_xz: Some(vec![2]),
+    ///     size: 4,
+    ///     signed: false,
+    /// };
+    ///
+    /// assert_eq!(a.to_dec_string(), None);
+    /// ```
+    pub fn to_dec_string(&self) -> Option<String> {
+        if self.contains_xz() {
+            return None;
+        }
+
+        let negative: bool = self.signed && self.is_set_msb_01();
+        let mut magnitude: Vec<usize> = self._magnitude(negative).data_01;
+        let mut digits: Vec<char> = Vec::new();
+
+        // Repeated division by 10, where every remainder is the next least significant digit
+        loop {
+            let mut remainder: u128 = 0;
+
+            for x in (0..magnitude.len()).rev() {
+                let value: u128 = (remainder << usize::BITS) | magnitude[x] as u128;
+                magnitude[x] = (value / 10) as usize;
+                remainder = value % 10;
+            }
+
+            digits.push(std::char::from_digit(remainder as u32, 10).unwrap());
+
+            if magnitude.iter().all(|x| *x == 0) {
+                break;
+            }
+        }
+
+        if negative {
+            digits.push('-');
+        }
+
+        Some(digits.iter().rev().collect())
+    }
+
+    // Returns whether the value is negative and its magnitude, as long as it has no X/Z(s) and the magnitude fits in 128 bits.
+    fn _to_i128_parts(&self) -> Result<(bool, u128), String> {
+        if self.contains_xz() {
+            return Err(format!("The value {} contains X/Z(s)!", self));
+        }
+
+        let negative: bool = self.signed && self.is_set_msb_01();
+        let mut magnitude: u128 = 0;
+
+        for (x, elmnt) in self._magnitude(negative).data_01.iter().enumerate() {
+            if x * usize::BITS as usize >= u128::BITS as usize {
+                if *elmnt != 0 {
+                    return Err(format!("The value {} does not fit in 128 bits!", self));
+                }
+            } else {
+                magnitude |= (*elmnt as u128) << (x * usize::BITS as usize);
+            }
+        }
+
+        Ok((negative, magnitude))
+    }
+
+    /// Compares two values as unsigned magnitudes after matching their widths, regardless of their signedness.
+    /// Returns None if any of the values contains X/Z(s).
+    /// # Examples
+    ///
+    /// Equal values with different widths
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use std::cmp::Ordering;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("8'd3").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("70'd3").unwrap();
+    ///
+    /// assert_eq!(a.cmp_unsigned(&b), Some(Ordering::Equal));
+    /// ```
+    /// Signed value with the MSB set
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use std::cmp::Ordering;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1111").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("8'd1").unwrap();
+    ///
+    /// assert_eq!(a.cmp_unsigned(&b), Some(Ordering::Greater));
+    /// ```
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'b00x1").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("4'b0001").unwrap();
+    ///
+    /// assert_eq!(a.cmp_unsigned(&b), None);
+    /// ```
+    pub fn cmp_unsigned(&self, other: &SvPrimaryLiteralIntegral) -> Option<Ordering> {
+        if self.contains_xz() || other.contains_xz() {
+            None
+        } else {
+            Some(self._unsigned_cmp(other))
+        }
+    }
+
+    /// Compares two values as two's complement values after sign extending them to matching widths, regardless of their
+    /// signedness. Returns None if any of the values contains X/Z(s).
+    /// # Examples
+    ///
+    /// Equal negative values with different widths
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use std::cmp::Ordering;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1111").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("70'sh3f_ffff_ffff_ffff_ffff").unwrap();
+    ///
+    /// assert_eq!(a.cmp_signed(&b), Some(Ordering::Equal));
+    /// ```
+    /// Equal positive values with different widths
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use std::cmp::Ordering;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'sd3").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("8'sd3").unwrap();
+    ///
+    /// assert_eq!(a.cmp_signed(&b), Some(Ordering::Equal));
+    /// ```
+    /// Negative value and positive value
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// # use std::cmp::Ordering;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'b1111").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("8'd1").unwrap();
+    ///
+    /// assert_eq!(a.cmp_signed(&b), Some(Ordering::Less));
+    /// assert_eq!(b.cmp_signed(&a), Some(Ordering::Greater));
+    /// ```
+    /// Value with X/Z(s)
+    /// ```
+    /// # use python_svdata::sv_primlit_integral::*;
+    /// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1z11").unwrap();
+    /// let b = SvPrimaryLiteralIntegral::from_sv_literal("4'sb0001").unwrap();
+    ///
+    /// assert_eq!(a.cmp_signed(&b), None);
+    /// ```
+    pub fn cmp_signed(&self, other: &SvPrimaryLiteralIntegral) -> Option<Ordering> {
+        if self.contains_xz() || other.contains_xz() {
+            return None;
+        }
+
+        let mut left_nu: SvPrimaryLiteralIntegral = self.clone();
+        let mut right_nu: SvPrimaryLiteralIntegral = other.clone();
+        left_nu.signed = true;
+        right_nu.signed = true;
+
+        if left_nu._lt(right_nu.clone()) == logic1b_1() {
+            Some(Ordering::Less)
+        } else if left_nu._gt(right_nu) == logic1b_1() {
+            Some(Ordering::Greater)
+        } else {
+            Some(Ordering::Equal)
+        }
+    }
+
+    // Compares the 0/1 bits of two values as unsigned magnitudes, ignoring any X/Z(s).
+    fn _unsigned_cmp(&self, other: &SvPrimaryLiteralIntegral) -> Ordering {
+        let left_nu: SvPrimaryLiteralIntegral = self._magnitude(false);
+        let right_nu: SvPrimaryLiteralIntegral = other._magnitude(false);
+
+        if left_nu._lt(right_nu.clone()) == logic1b_1() {
+            Ordering::Less
+        } else if left_nu._gt(right_nu) == logic1b_1() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    // Returns the elements of data_01 or data_xz without the redundant leading elements, after the bits beyond the width are either
+    // cleared or set to extend the MSB.
+    fn _canonical_elmnts(&self, elmnts: &[usize], extend: bool) -> Vec<usize> {
+        let mut ret: Vec<usize> = elmnts.to_vec();
+        ret.truncate((self.size + usize::BITS as usize - 1) / usize::BITS as usize);
+
+        let remaining_bits: usize = self.size % usize::BITS as usize;
+        if remaining_bits != 0 {
+            let last_index = ret.len() - 1;
+            let mask: usize = (1 << remaining_bits) - 1;
+
+            if extend {
+                ret[last_index] |= !mask;
+            } else {
+                ret[last_index] &= mask;
+            }
+        }
+
+        let fill: usize = if extend { usize::MAX } else { 0 };
+        while ret.last() == Some(&fill) {
+            ret.pop();
+        }
+
+        ret
+    }
+
+    // Renders the digits of the value, from the MSB to the LSB, for a radix of 2 ** bits_per_digit.
+    fn _radix_digits(&self, bits_per_digit: usize) -> String {
+        let mut ret: String = String::new();
+        let num_digits: usize = (self.size + bits_per_digit - 1) / bits_per_digit;
+
+        for x in (0..num_digits).rev() {
+            let mut value: u32 = 0;
+            let mut any_xz: bool = false;
+            let mut all_z: bool = true;
+
+            for y in (x * bits_per_digit)..std::cmp::min((x + 1) * bits_per_digit, self.size) {
+                let elmnt: usize = y / usize::BITS as usize;
+                let shift: usize = y % usize::BITS as usize;
+
+                let bit_01: usize = (self.data_01[elmnt] >> shift) & 1;
+                let bit_xz: usize = match &self.data_xz {
+                    Some(data_xz) => (data_xz[elmnt] >> shift) & 1,
+                    None => 0,
+                };
+
+                if bit_xz == 1 {
+                    any_xz = true;
+                    all_z &= bit_01 == 1;
+                } else {
+                    all_z = false;
+                    value |= (bit_01 as u32) << (y - x * bits_per_digit);
+                }
+            }
+
+            ret.push(if any_xz && all_z {
+                'z'
+            } else if any_xz {
+                'x'
+            } else {
+                std::char::from_digit(value, 16).unwrap()
+            });
+        }
+
+        ret
+    }
+}
+
+/** Converts a usize into a 2-state signed primary literal. Width is set by deafult to usize::BITS */
+/// # Examples
+///
+/// Signed positive value
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a: SvPrimaryLiteralIntegral = usize_to_primlit(4611686018427387904);
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![4611686018427387904],
+///     data_xz: None,
+///     size: 64,
+///     signed: true,
+/// };
+///
+/// assert_eq!(a, exp);
+/// ```
+/// Signed negative value
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a: SvPrimaryLiteralIntegral = usize_to_primlit(9223372036854775808);
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![9223372036854775808],
+///     data_xz: None,
+///     size: 64,
+///     signed: true,
+/// };
+///
+/// assert_eq!(a, exp);
+/// ```
+pub fn usize_to_primlit(value: usize) -> SvPrimaryLiteralIntegral {
+    let mut ret = SvPrimaryLiteralIntegral {
+        data_01: vec![value],
+        data_xz: None,
+        size: usize::BITS as usize,
+        signed: true,
+    };
+
+    ret._minimum_width();
+
+    ret
+}
+
+/** Returns an integral primary literal of the given width in which all the bits are X.
+A zero width value has no bits, so it is returned as an empty 4-state value. */
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![0],
+///     data_xz: Some(vec![0]),
+///     size: 0,
+///     signed: true,
+/// };
+///
+/// assert_eq!(logic_x(0, true), exp);
+/// assert_eq!(logic_x(3, false), SvPrimaryLiteralIntegral::from_sv_literal("3'bxxx").unwrap());
+/// ```
+pub fn logic_x(size: usize, signed: bool) -> SvPrimaryLiteralIntegral {
+    if size == 0 {
+        return SvPrimaryLiteralIntegral::new(0, signed).to_4state();
+    }
+
+    let mut ret = SvPrimaryLiteralIntegral {
+        data_01: vec![0; (size + usize::BITS as usize - 1) / usize::BITS as usize],
+        data_xz: Some(vec![
+            usize::MAX;
+            (size + usize::BITS as usize - 1) / usize::BITS as usize
+        ]),
+        size: usize::BITS as usize * ((size + usize::BITS as usize - 1) / usize::BITS as usize),
+        signed,
+    };
+
+    ret._truncate(size);
+
+    ret
+}
+
+pub fn bit1b_0() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![0],
+        data_xz: None,
+        size: 1,
+        signed: false,
+    }
+}
+
+pub fn bit1b_1() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![1],
+        data_xz: None,
+        size: 1,
+        signed: false,
+    }
+}
+
+pub fn logic1b_0() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![0],
+        data_xz: Some(vec![0]),
+        size: 1,
+        signed: false,
+    }
+}
+
+pub fn logic1b_1() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![1],
+        data_xz: Some(vec![0]),
+        size: 1,
+        signed: false,
+    }
+}
+
+pub fn logic1b_x() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![0],
+        data_xz: Some(vec![1]),
+        size: 1,
+        signed: false,
+    }
+}
+
+pub fn _logic1b_z() -> SvPrimaryLiteralIntegral {
+    SvPrimaryLiteralIntegral {
+        data_01: vec![1],
+        data_xz: Some(vec![1]),
+        size: 1,
+        signed: false,
+    }
+}
+
+/// Returns a 4-state 1-bit 0, i.e. `1'b0` of type logic.
+#[pyfunction]
+#[pyo3(name = "logic_0")]
+pub fn py_logic_0() -> SvPrimaryLiteralIntegral {
+    logic1b_0()
+}
+
+/// Returns a 4-state 1-bit 1, i.e. `1'b1` of type logic.
+#[pyfunction]
+#[pyo3(name = "logic_1")]
+pub fn py_logic_1() -> SvPrimaryLiteralIntegral {
+    logic1b_1()
+}
+
+/// Returns a 4-state 1-bit X, i.e. `1'bx` of type logic.
+#[pyfunction]
+#[pyo3(name = "logic_x")]
+pub fn py_logic_x() -> SvPrimaryLiteralIntegral {
+    logic1b_x()
+}
+
+/// Returns a 2-state 1-bit 0, i.e. `1'b0` of type bit.
+#[pyfunction]
+#[pyo3(name = "bit_0")]
+pub fn py_bit_0() -> SvPrimaryLiteralIntegral {
+    bit1b_0()
+}
+
+/// Returns a 2-state 1-bit 1, i.e. `1'b1` of type bit.
+#[pyfunction]
+#[pyo3(name = "bit_1")]
+pub fn py_bit_1() -> SvPrimaryLiteralIntegral {
+    bit1b_1()
+}
+
+impl fmt::Display for SvPrimaryLiteralIntegral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        if self.contains_xz() {
+            write!(f, "{}", self.to_string_radix(2))
+        } else {
+            write!(f, "{}", self.to_string_radix(16))
+        }
+    }
+}
+
+/// Orders integral primary literals by their values, compared as unsigned magnitudes after matching their widths.
+/// Unlike the "<" operator, which returns X as soon as any of the operands contains X/Z(s), this is a total order:
+/// values with X/Z(s) sort after all values without, and are ordered among themselves by their X/Z bits and then their 0/1 bits.
+/// Values of equal magnitude are further ordered by width, signedness and state, so that only equal values compare as equal.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral::from_sv_literal("8'b0000_0011").unwrap();
+/// let b = SvPrimaryLiteralIntegral::from_sv_literal("4'b0011").unwrap();
+/// let c = SvPrimaryLiteralIntegral::from_sv_literal("4'b00x1").unwrap();
+/// let d = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1111").unwrap();
+/// let e = SvPrimaryLiteralIntegral::from_sv_literal("70'h1").unwrap();
+///
+/// let mut values = vec![c.clone(), a.clone(), d.clone(), b.clone(), e.clone()];
+/// values.sort();
+///
+/// assert_eq!(values, vec![e, b, a, d, c]);
+/// ```
+impl Ord for SvPrimaryLiteralIntegral {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering: Ordering = match (self.contains_xz(), other.contains_xz()) {
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => self.cmp_unsigned(other).unwrap(),
+            (true, true) => {
+                let to_primlit = |x: &SvPrimaryLiteralIntegral| SvPrimaryLiteralIntegral {
+                    data_01: x.data_xz.clone().unwrap(),
+                    data_xz: None,
+                    size: x.size,
+                    signed: false,
+                };
+
+                to_primlit(self)
+                    ._unsigned_cmp(&to_primlit(other))
+                    .then(self._unsigned_cmp(other))
+            }
+        };
+
+        ordering
+            .then(self.size.cmp(&other.size))
+            .then(self.signed.cmp(&other.signed))
+            .then(self.data_xz.cmp(&other.data_xz))
+            .then(self.data_01.cmp(&other.data_01))
+    }
+}
+
+impl PartialOrd for SvPrimaryLiteralIntegral {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes integral primary literals by their canonical values, i.e. without the redundant leading zeros, or the redundant leading
+/// sign bits of signed values, so that values which only differ in width hash equally. The signedness and the X/Z bits are hashed too.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # use std::collections::HashSet;
+/// # use std::hash::{Hash, Hasher};
+/// let hash = |x: &SvPrimaryLiteralIntegral| {
+///     let mut hasher = DefaultHasher::new();
+///     x.hash(&mut hasher);
+///     hasher.finish()
+/// };
+///
+/// let a = SvPrimaryLiteralIntegral::from_sv_literal("4'b0011").unwrap();
+/// let b = SvPrimaryLiteralIntegral::from_sv_literal("8'b0000_0011").unwrap();
+/// let c = SvPrimaryLiteralIntegral::from_sv_literal("4'b00x1").unwrap();
+/// let d = SvPrimaryLiteralIntegral::from_sv_literal("4'sb1111").unwrap();
+/// let e = SvPrimaryLiteralIntegral::from_sv_literal("8'sb1111_1111").unwrap();
+///
+/// assert_eq!(hash(&a), hash(&b));
+/// assert_eq!(hash(&d), hash(&e));
+/// assert_ne!(hash(&a), hash(&c));
+///
+/// let mut values: HashSet<SvPrimaryLiteralIntegral> = HashSet::new();
+/// values.insert(a.clone());
+/// values.insert(c.clone());
+/// values.insert(d.clone());
+///
+/// assert!(values.contains(&a));
+/// assert!(values.contains(&c));
+/// assert!(values.contains(&d));
+/// assert!(!values.contains(&SvPrimaryLiteralIntegral::from_sv_literal("4'b0010").unwrap()));
+/// ```
+impl Hash for SvPrimaryLiteralIntegral {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let extend_01: bool = self.signed && self.is_set_msb_01();
+        let extend_xz: bool = self.signed && self.is_set_msb_xz();
+
+        self.signed.hash(state);
+        extend_01.hash(state);
+        self._canonical_elmnts(&self.data_01, extend_01).hash(state);
+        extend_xz.hash(state);
+        match &self.data_xz {
+            Some(data_xz) => self._canonical_elmnts(data_xz, extend_xz).hash(state),
+            None => Vec::<usize>::new().hash(state),
+        }
+    }
+}
+
+impl Add for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add_primlit(rhs.clone())
+    }
+}
+
+impl Add<usize> for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn add(self, rhs: usize) -> Self {
+        let rhs = usize_to_primlit(rhs);
+        self.add_primlit(rhs.clone())
+    }
+}
+
+/// Subtraction, as `a - b` in systemverilog.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![7],
+///     data_xz: None,
+///     size: 5,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a - b, exp);
+/// ```
+impl Sub for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_primlit(rhs)
+    }
+}
+
+impl Mul for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mult(rhs.clone())
+    }
+}
+
+/// Division, as `a / b` in systemverilog.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![2],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a / b, exp);
+/// ```
+impl Div for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.div_primlit(rhs)
+    }
+}
+
+/// Modulus, as `a % b` in systemverilog.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![1],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a % b, exp);
+/// ```
+impl Rem for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self.mod_primlit(rhs)
+    }
+}
+
+/// Bitwise AND, as `a & b` in systemverilog.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![4],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a & b, exp);
+/// ```
+impl BitAnd for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.and_primlit(rhs)
     }
 }
 
-/** Converts a usize into a 2-state signed primary literal. Width is set by deafult to usize::BITS */
+/// Bitwise OR, as `a | b` in systemverilog.
+///
 /// # Examples
 ///
-/// Signed positive value
 /// ```
-/// # use svdata::sv_primlit_integral::*;
-/// let a: SvPrimaryLiteralIntegral = usize_to_primlit(4611686018427387904);
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
 ///
 /// let exp = SvPrimaryLiteralIntegral {
-///     data_01: vec![4611686018427387904],
+///     data_01: vec![15],
 ///     data_xz: None,
-///     size: 64,
-///     signed: true,
+///     size: 4,
+///     signed: false,
 /// };
 ///
-/// assert_eq!(a, exp);
+/// assert_eq!(a | b, exp);
 /// ```
-/// Signed negative value
+impl BitOr for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.or_primlit(rhs)
+    }
+}
+
+/// Bitwise XOR, as `a ^ b` in systemverilog.
+///
+/// # Examples
+///
 /// ```
-/// # use svdata::sv_primlit_integral::*;
-/// let a: SvPrimaryLiteralIntegral = usize_to_primlit(9223372036854775808);
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![13],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let b = SvPrimaryLiteralIntegral {
+///     data_01: vec![6],
+///     data_xz: None,
+///     size: 4,
+///     signed: false,
+/// };
 ///
 /// let exp = SvPrimaryLiteralIntegral {
-///     data_01: vec![9223372036854775808],
+///     data_01: vec![11],
 ///     data_xz: None,
-///     size: 64,
-///     signed: true,
+///     size: 4,
+///     signed: false,
 /// };
 ///
-/// assert_eq!(a, exp);
+/// assert_eq!(a ^ b, exp);
 /// ```
-pub fn usize_to_primlit(value: usize) -> SvPrimaryLiteralIntegral {
-    let mut ret = SvPrimaryLiteralIntegral {
-        data_01: vec![value],
-        data_xz: None,
-        size: usize::BITS as usize,
-        signed: true,
-    };
-
-    ret._minimum_width();
-
-    ret
-}
+impl BitXor for SvPrimaryLiteralIntegral {
+    type Output = Self;
 
-pub fn bit1b_0() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![0],
-        data_xz: None,
-        size: 1,
-        signed: false,
+    fn bitxor(self, rhs: Self) -> Self {
+        self.xor_primlit(rhs)
     }
 }
 
-pub fn bit1b_1() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![1],
-        data_xz: None,
-        size: 1,
-        signed: false,
-    }
-}
+/// Bitwise negation, as `~a` in systemverilog. X and Z bits both become X.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![9],
+///     data_xz: Some(vec![12]),
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![2],
+///     data_xz: Some(vec![12]),
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// assert_eq!(!a, exp);
+/// ```
+impl Not for SvPrimaryLiteralIntegral {
+    type Output = Self;
 
-pub fn logic1b_0() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![0],
-        data_xz: Some(vec![0]),
-        size: 1,
-        signed: false,
+    fn not(self) -> Self {
+        self.inv()
     }
 }
 
-pub fn logic1b_1() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![1],
-        data_xz: Some(vec![0]),
-        size: 1,
-        signed: false,
-    }
-}
+impl Shl<usize> for SvPrimaryLiteralIntegral {
+    type Output = Self;
 
-pub fn logic1b_x() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![0],
-        data_xz: Some(vec![1]),
-        size: 1,
-        signed: false,
+    fn shl(self, rhs: usize) -> Self {
+        self.lsl(rhs)
     }
 }
 
-pub fn _logic1b_z() -> SvPrimaryLiteralIntegral {
-    SvPrimaryLiteralIntegral {
-        data_01: vec![1],
-        data_xz: Some(vec![1]),
-        size: 1,
-        signed: false,
+/// Logical right shift, as `a >> n` in systemverilog, which shifts in zeros even if the value is
+/// signed. The arithmetic right shift `a >>> n` is `a.shift_right(n, true)`, i.e. asr.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![12],
+///     data_xz: None,
+///     size: 4,
+///     signed: true,
+/// };
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![3],
+///     data_xz: None,
+///     size: 4,
+///     signed: true,
+/// };
+///
+/// assert_eq!(a.clone() >> 2, exp);
+/// assert_eq!(a.clone() >> 2, a.shift_right(2, false));
+/// assert_ne!(a.clone() >> 2, a.shift_right(2, true));
+/// ```
+impl Shr<usize> for SvPrimaryLiteralIntegral {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self {
+        self.lsr(rhs)
     }
 }
 
-impl fmt::Display for SvPrimaryLiteralIntegral {
-    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
-        writeln!(f, "NumBits: {}", self.size)?;
-        writeln!(f, "Signed: {}", self.signed)?;
-        writeln!(f, "4State: {}", self.is_4state())?;
-
-        let mut string_vec: Vec<String> = Vec::new();
-        let mut s: String = String::new();
-        let mut mod_primlit = self.clone();
-        let first_elmnt_bits: u32;
+impl Neg for SvPrimaryLiteralIntegral {
+    type Output = Self;
 
-        if mod_primlit.size % usize::BITS as usize == 0 {
-            first_elmnt_bits = usize::BITS;
+    fn neg(self) -> Self {
+        if self.contains_xz() {
+            logic1b_x()
         } else {
-            first_elmnt_bits = mod_primlit.size as u32 % usize::BITS;
-        }
-        let remaining_bits = usize::BITS - first_elmnt_bits;
-
-        let last_index = mod_primlit.data_01.len() - 1;
-
-        for _x in 0..first_elmnt_bits {
-            if mod_primlit.is_4state()
-                && (mod_primlit.data_xz.as_ref().unwrap()[last_index].leading_zeros()
-                    == remaining_bits)
-            {
-                if mod_primlit.data_01[last_index].leading_zeros() == remaining_bits {
-                    s.push('Z');
-                } else {
-                    s.push('X');
-                }
-            } else if mod_primlit.data_01[last_index].leading_zeros() == remaining_bits {
-                s.push('1');
-            } else {
-                s.push('0');
-            }
-
-            mod_primlit = mod_primlit.rol(1);
-        }
-
-        string_vec.push(s);
-
-        if self.data_01.len() > 1 {
-            for x in (0..(self.data_01.len() - 1)).rev() {
-                let mut mod_primlit = self.clone();
-                let mut s: String = String::new();
-
-                for _y in 0..usize::BITS {
-                    if mod_primlit.is_4state()
-                        && (mod_primlit.data_xz.as_ref().unwrap()[x].leading_zeros() == 0)
-                    {
-                        if mod_primlit.data_01[x].leading_zeros() == 0 {
-                            s.push('Z');
-                        } else {
-                            s.push('X');
-                        }
-                    } else if mod_primlit.data_01[x].leading_zeros() == 0 {
-                        s.push('1');
-                    } else {
-                        s.push('0');
-                    }
-
-                    mod_primlit = mod_primlit.rol(1);
-                }
-
-                string_vec.push(s);
-            }
-        }
-
-        write!(f, "Data: ")?;
-        for x in string_vec {
-            writeln!(f, "{}", x)?;
+            self.negate()
         }
-
-        write!(f, "")
     }
 }
 
-impl Add for SvPrimaryLiteralIntegral {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self {
-        self.add_primlit(rhs.clone())
+/// Converts a u64 into a 2-state unsigned primary literal of the minimum width.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral::from(255u64);
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![255],
+///     data_xz: None,
+///     size: 8,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a, exp);
+/// assert_eq!(u64::try_from(a), Ok(255));
+/// ```
+impl From<u64> for SvPrimaryLiteralIntegral {
+    fn from(value: u64) -> Self {
+        SvPrimaryLiteralIntegral::from(value as u128)
     }
 }
 
-impl Add<usize> for SvPrimaryLiteralIntegral {
-    type Output = Self;
+/// Converts a u128 into a 2-state unsigned primary literal of the minimum width.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral::from(1u128 << 64);
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![0, 1],
+///     data_xz: None,
+///     size: 65,
+///     signed: false,
+/// };
+///
+/// assert_eq!(a, exp);
+/// assert_eq!(u128::try_from(a), Ok(1 << 64));
+/// ```
+impl From<u128> for SvPrimaryLiteralIntegral {
+    fn from(value: u128) -> Self {
+        let size: usize = std::cmp::max(1, (u128::BITS - value.leading_zeros()) as usize);
+        let mut data_01: Vec<usize> = Vec::new();
 
-    fn add(self, rhs: usize) -> Self {
-        let rhs = usize_to_primlit(rhs);
-        self.add_primlit(rhs.clone())
+        for x in 0..((size + usize::BITS as usize - 1) / usize::BITS as usize) {
+            data_01.push((value >> (x * usize::BITS as usize)) as usize);
+        }
+
+        SvPrimaryLiteralIntegral {
+            data_01,
+            data_xz: None,
+            size,
+            signed: false,
+        }
     }
 }
 
-impl Mul for SvPrimaryLiteralIntegral {
-    type Output = Self;
+/// Converts an i64 into a 2-state signed primary literal of the minimum width, including the sign bit.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral::from(-3i64);
+///
+/// let exp = SvPrimaryLiteralIntegral {
+///     data_01: vec![5],
+///     data_xz: None,
+///     size: 3,
+///     signed: true,
+/// };
+///
+/// assert_eq!(a, exp);
+/// assert_eq!(i64::try_from(a), Ok(-3));
+/// ```
+impl From<i64> for SvPrimaryLiteralIntegral {
+    fn from(value: i64) -> Self {
+        let magnitude: u64 = if value < 0 {
+            !value as u64
+        } else {
+            value as u64
+        };
+        let size: usize = (u64::BITS - magnitude.leading_zeros()) as usize + 1;
 
-    fn mul(self, rhs: Self) -> Self {
-        self.mult(rhs.clone())
+        SvPrimaryLiteralIntegral::from(value as u64)._signed_result(false, size, true, false)
     }
 }
 
-impl Shl<usize> for SvPrimaryLiteralIntegral {
-    type Output = Self;
+/// Converts an integral primary literal into a u64.
+/// Fails if the value contains X/Z(s), is negative or does not fit in 64 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![0],
+///     data_xz: Some(vec![1]),
+///     size: 4,
+///     signed: false,
+/// };
+///
+/// assert!(u64::try_from(a).is_err());
+/// ```
+impl TryFrom<SvPrimaryLiteralIntegral> for u64 {
+    type Error = String;
 
-    fn shl(self, rhs: usize) -> Self {
-        self.lsl(rhs)
+    fn try_from(value: SvPrimaryLiteralIntegral) -> Result<Self, Self::Error> {
+        match value._to_i128_parts()? {
+            (false, magnitude) if magnitude <= u64::MAX as u128 => Ok(magnitude as u64),
+            _ => Err(format!("The value {} does not fit in a u64!", value)),
+        }
     }
 }
 
-impl Shr<usize> for SvPrimaryLiteralIntegral {
-    type Output = Self;
+/// Converts an integral primary literal into a u128.
+/// Fails if the value contains X/Z(s), is negative or does not fit in 128 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral::from(-1i64);
+///
+/// assert!(u128::try_from(a).is_err());
+/// ```
+impl TryFrom<SvPrimaryLiteralIntegral> for u128 {
+    type Error = String;
 
-    fn shr(self, rhs: usize) -> Self {
-        self.lsr(rhs)
+    fn try_from(value: SvPrimaryLiteralIntegral) -> Result<Self, Self::Error> {
+        match value._to_i128_parts()? {
+            (false, magnitude) => Ok(magnitude),
+            _ => Err(format!("The value {} does not fit in a u128!", value)),
+        }
     }
 }
 
-impl Neg for SvPrimaryLiteralIntegral {
-    type Output = Self;
-
-    fn neg(self) -> Self {
-        if self.contains_xz() {
-            logic1b_x()
-        } else {
-            self.negate()
+/// Converts an integral primary literal into an i64, where only signed values can be negative.
+/// Fails if the value contains X/Z(s) or does not fit in 64 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_primlit_integral::*;
+/// let a = SvPrimaryLiteralIntegral {
+///     data_01: vec![253],
+///     data_xz: None,
+///     size: 8,
+///     signed: false,
+/// };
+///
+/// assert_eq!(i64::try_from(a), Ok(253));
+/// assert!(i64::try_from(SvPrimaryLiteralIntegral::from(u64::MAX)).is_err());
+/// ```
+impl TryFrom<SvPrimaryLiteralIntegral> for i64 {
+    type Error = String;
+
+    fn try_from(value: SvPrimaryLiteralIntegral) -> Result<Self, Self::Error> {
+        match value._to_i128_parts()? {
+            (false, magnitude) if magnitude <= i64::MAX as u128 => Ok(magnitude as i64),
+            (true, magnitude) if magnitude <= i64::MIN.unsigned_abs() as u128 => {
+                Ok((magnitude as i128).wrapping_neg() as i64)
+            }
+            _ => Err(format!("The value {} does not fit in an i64!", value)),
         }
     }
 }
@@ -0,0 +1,129 @@
+use crate::structures::{SvDataKind, SvSignal, SvSpan};
+use crate::sv_misc::{identifier, source_text, span};
+use crate::sv_port::{port_packeddim_ansi, port_unpackeddim_ansi};
+use sv_parser::{unwrap_node, RefNode, SyntaxTree};
+
+/// Extracts the nets declared by a net declaration, e.g. `wire [3:0] a, b;`.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::structures::SvDataKind;
+/// let svdata = parse_sv_file("tests/systemverilog/signals.sv", None, None, false).unwrap();
+/// let signals = &svdata.modules[0].signals;
+///
+/// assert_eq!(signals[0].identifier, "w");
+/// assert_eq!(signals[0].datakind, SvDataKind::Net);
+/// assert_eq!(signals[0].datatype, None);
+/// assert_eq!(signals[0].packed_dimensions, vec![(String::from("3"), String::from("0"))]);
+/// ```
+pub fn net_declaration(
+    p: &sv_parser::NetDeclarationNetType,
+    syntax_tree: &SyntaxTree,
+) -> Vec<SvSignal> {
+    let (_, _, _, datatype, _, assignments, _) = &p.nodes;
+    let mut ret: Vec<SvSignal> = Vec::new();
+
+    for node in assignments {
+        match node {
+            RefNode::NetDeclAssignment(x) => {
+                let (id, dimensions, _) = &x.nodes;
+                let mut signal = signal_common(
+                    identifier(RefNode::NetIdentifier(id), syntax_tree).unwrap(),
+                    span(RefNode::NetDeclAssignment(x), syntax_tree),
+                    SvDataKind::Net,
+                    datatype,
+                    syntax_tree,
+                );
+
+                for dimension in dimensions {
+                    signal.unpacked_dimensions.extend(port_unpackeddim_ansi(
+                        RefNode::UnpackedDimension(dimension),
+                        syntax_tree,
+                    ));
+                }
+
+                ret.push(signal);
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+/// Extracts the variables declared by a data declaration, e.g. `logic [7:0] a [4];`.
+pub fn variable_declaration(
+    p: &sv_parser::DataDeclarationVariable,
+    syntax_tree: &SyntaxTree,
+) -> Vec<SvSignal> {
+    let (_, _, _, datatype, assignments, _) = &p.nodes;
+    let mut ret: Vec<SvSignal> = Vec::new();
+
+    for node in assignments {
+        match node {
+            RefNode::VariableDeclAssignmentVariable(x) => {
+                let (id, dimensions, _) = &x.nodes;
+                let mut signal = signal_common(
+                    identifier(RefNode::VariableIdentifier(id), syntax_tree).unwrap(),
+                    span(RefNode::VariableDeclAssignmentVariable(x), syntax_tree),
+                    SvDataKind::Variable,
+                    datatype,
+                    syntax_tree,
+                );
+
+                for dimension in dimensions {
+                    signal.unpacked_dimensions.extend(port_unpackeddim_ansi(
+                        RefNode::VariableDimension(dimension),
+                        syntax_tree,
+                    ));
+                }
+
+                ret.push(signal);
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+// The type is shared by all of the signals of a declaration, only the unpacked dimensions are not
+fn signal_common(
+    identifier: String,
+    span: SvSpan,
+    datakind: SvDataKind,
+    datatype: &sv_parser::DataTypeOrImplicit,
+    syntax_tree: &SyntaxTree,
+) -> SvSignal {
+    SvSignal {
+        identifier,
+        datakind,
+        datatype: match datatype {
+            sv_parser::DataTypeOrImplicit::DataType(x) => {
+                source_text(syntax_tree.get_str(x.as_ref()))
+            }
+            _ => None,
+        },
+        packed_dimensions: port_packeddim_ansi(RefNode::DataTypeOrImplicit(datatype), syntax_tree),
+        unpacked_dimensions: Vec::new(),
+        signed: signal_signed(datatype),
+        span,
+    }
+}
+
+// Integer atom types other than time are signed unless declared unsigned, all other types are unsigned unless declared signed
+fn signal_signed(datatype: &sv_parser::DataTypeOrImplicit) -> bool {
+    match unwrap_node!(datatype, Signing) {
+        Some(RefNode::Signing(sv_parser::Signing::Signed(_))) => true,
+        Some(_) => false,
+        None => match unwrap_node!(datatype, IntegerAtomType) {
+            Some(RefNode::IntegerAtomType(sv_parser::IntegerAtomType::Time(_))) => false,
+            Some(_) => true,
+            None => false,
+        },
+    }
+}
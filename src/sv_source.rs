@@ -0,0 +1,161 @@
+use crate::structures::{SvData, SvInterface, SvModuleDeclaration, SvPackageDeclaration};
+use crate::sv_error::SvError;
+use crate::{
+    sv_defines, sv_includes, sv_syntax_tree, sv_to_structure, sv_walk_text, SvSourceTexts,
+};
+use pyo3::prelude::*;
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use sv_parser::SyntaxTree;
+
+/// A systemverilog file that is parsed once, so that it can be queried repeatedly without being
+/// parsed again.
+///
+/// The syntax tree holds the preprocessed text of the file, so the source text and the spans of
+/// the extracted data stay available for as long as the `SvSource` is alive. The file is read
+/// once, when it is opened, so the locations in the warnings refer to the file as it was parsed,
+/// even if it changes afterwards. Included files are read once, the first time they are needed.
+///
+/// Args:
+///    file_path (str): The path to the systemverilog file.
+///    defines (dict[str, str | None] | None): Preprocessor defines, mapping each macro name to
+///        its replacement text. A value of `None` defines the macro without replacement text.
+///    include_dirs (list[str] | None): Directories that are searched, in order, for files
+///        included with a relative path.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::sv_source::SvSource;
+/// let source = SvSource::open("tests/systemverilog/ansi_module.sv", None, None).unwrap();
+///
+/// let svdata = source.sv_data(false).unwrap();
+/// assert_eq!(svdata.modules[0].identifier, "ansi_module_a");
+///
+/// let mut modules = 0;
+/// source
+///     .walk_text(|kind, _, enter| {
+///         if enter && kind == "ModuleDeclarationAnsi" {
+///             modules += 1;
+///         }
+///         Ok::<(), ()>(())
+///     })
+///     .unwrap();
+/// assert_eq!(modules, svdata.modules.len());
+/// ```
+#[pyclass]
+pub struct SvSource {
+    #[pyo3(get)]
+    pub file_path: String,
+    syntax_tree: Arc<SyntaxTree>,
+    texts: Mutex<SvSourceTexts>,
+}
+
+impl SvSource {
+    /// Parses a systemverilog file, or returns an `SvError` describing why it could not be read.
+    pub fn open(
+        file_path: &str,
+        defines: Option<HashMap<String, Option<String>>>,
+        include_dirs: Option<Vec<String>>,
+    ) -> Result<SvSource, SvError> {
+        let defines = sv_defines(defines.unwrap_or_default());
+        let includes = sv_includes(include_dirs.unwrap_or_default());
+        let (syntax_tree, _) = sv_syntax_tree(file_path, &defines, &includes)?;
+
+        let mut texts = SvSourceTexts::new();
+        texts.insert(PathBuf::from(file_path), fs::read_to_string(file_path).ok());
+
+        Ok(SvSource {
+            file_path: String::from(file_path),
+            syntax_tree: Arc::new(syntax_tree),
+            texts: Mutex::new(texts),
+        })
+    }
+
+    /// The syntax tree of the file.
+    pub fn syntax_tree(&self) -> &SyntaxTree {
+        &self.syntax_tree
+    }
+
+    /// Extracts the data of the file, the same as `parse_sv_file` does.
+    pub fn sv_data(&self, strict: bool) -> Result<SvData, SvError> {
+        let mut svdata = SvData {
+            modules: Vec::new(),
+            packages: Vec::new(),
+            interfaces: Vec::new(),
+            classes: Vec::new(),
+            warnings: Vec::new(),
+            directives: Vec::new(),
+        };
+
+        let mut texts = self.texts.lock().unwrap();
+        sv_to_structure(
+            &self.syntax_tree,
+            &self.file_path,
+            strict,
+            &mut svdata,
+            &mut texts,
+        )?;
+
+        Ok(svdata)
+    }
+
+    /// Walks the syntax tree, calling `visit(kind, text, enter)` the same as `walk_sv_file` does.
+    pub fn walk_text<E, F>(&self, visit: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &str, bool) -> Result<(), E>,
+    {
+        sv_walk_text(&self.syntax_tree, visit)
+    }
+}
+
+#[pymethods]
+impl SvSource {
+    #[new]
+    #[pyo3(signature = (file_path, defines = None, include_dirs = None))]
+    fn new(
+        py: Python,
+        file_path: &str,
+        defines: Option<HashMap<String, Option<String>>>,
+        include_dirs: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        Ok(py.allow_threads(|| SvSource::open(file_path, defines, include_dirs))?)
+    }
+
+    /// Extracts the data of the file, the same as `read_sv_file` does.
+    #[pyo3(name = "data", signature = (strict = false))]
+    fn py_data(&self, py: Python, strict: bool) -> PyResult<SvData> {
+        Ok(py.allow_threads(|| self.sv_data(strict))?)
+    }
+
+    /// Extracts the modules of the file.
+    fn modules(&self, py: Python) -> PyResult<Vec<SvModuleDeclaration>> {
+        Ok(self.py_data(py, false)?.modules)
+    }
+
+    /// Extracts the packages of the file.
+    fn packages(&self, py: Python) -> PyResult<Vec<SvPackageDeclaration>> {
+        Ok(self.py_data(py, false)?.packages)
+    }
+
+    /// Extracts the interfaces of the file.
+    fn interfaces(&self, py: Python) -> PyResult<Vec<SvInterface>> {
+        Ok(self.py_data(py, false)?.interfaces)
+    }
+
+    /// Walks the syntax tree, calling `callback(kind, text, enter)` the same as `walk_sv` does.
+    fn walk(&self, py: Python, callback: PyObject) -> PyResult<()> {
+        self.walk_text(|kind, text, enter| -> PyResult<()> {
+            callback.call1(py, (kind, text, enter))?;
+            Ok(())
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SvSource({:?})", self.file_path)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::structures::{SvArgument, SvFunction, SvPortDirection, SvTask};
+use crate::sv_misc::{identifier, source_text, span};
+use sv_parser::{unwrap_node, RefNode, SyntaxTree};
+
+pub fn function_declaration(
+    p: &sv_parser::FunctionDeclaration,
+    syntax_tree: &SyntaxTree,
+) -> SvFunction {
+    SvFunction {
+        identifier: function_identifier(p, syntax_tree),
+        return_type: function_return_type(p, syntax_tree),
+        arguments: subroutine_arguments(RefNode::FunctionDeclaration(p), syntax_tree),
+        span: span(RefNode::FunctionDeclaration(p), syntax_tree),
+    }
+}
+
+pub fn task_declaration(p: &sv_parser::TaskDeclaration, syntax_tree: &SyntaxTree) -> SvTask {
+    SvTask {
+        identifier: task_identifier(p, syntax_tree),
+        arguments: subroutine_arguments(RefNode::TaskDeclaration(p), syntax_tree),
+        span: span(RefNode::TaskDeclaration(p), syntax_tree),
+    }
+}
+
+fn function_identifier(p: &sv_parser::FunctionDeclaration, syntax_tree: &SyntaxTree) -> String {
+    if let Some(id) = unwrap_node!(p, FunctionIdentifier) {
+        identifier(id, syntax_tree).unwrap()
+    } else {
+        unreachable!()
+    }
+}
+
+fn task_identifier(p: &sv_parser::TaskDeclaration, syntax_tree: &SyntaxTree) -> String {
+    if let Some(id) = unwrap_node!(p, TaskIdentifier) {
+        identifier(id, syntax_tree).unwrap()
+    } else {
+        unreachable!()
+    }
+}
+
+// The return type is None for a function with an implicit return type
+fn function_return_type(
+    p: &sv_parser::FunctionDeclaration,
+    syntax_tree: &SyntaxTree,
+) -> Option<String> {
+    match unwrap_node!(p, FunctionDataTypeOrImplicit) {
+        Some(RefNode::FunctionDataTypeOrImplicit(x)) => source_text(syntax_tree.get_str(x)),
+        _ => None,
+    }
+}
+
+// Arguments are declared either in a port list or as declarations in the body
+fn subroutine_arguments(m: RefNode, syntax_tree: &SyntaxTree) -> Vec<SvArgument> {
+    let mut ret: Vec<SvArgument> = Vec::new();
+
+    for node in m {
+        match node {
+            RefNode::TfPortItem(x) => {
+                let (_, direction, _, datatype, port) = &x.nodes;
+
+                // An argument without a type, like b in (input logic [7:0] a, b), is parsed as
+                // a type without an identifier, so the type is the identifier
+                let (id, datatype) = match port {
+                    Some((id, _, _)) => (
+                        identifier(RefNode::PortIdentifier(id), syntax_tree),
+                        source_text(syntax_tree.get_str(datatype)),
+                    ),
+                    None => (
+                        identifier(RefNode::DataTypeOrImplicit(datatype), syntax_tree),
+                        None,
+                    ),
+                };
+
+                if let Some(id) = id {
+                    // An argument without a direction inherits the direction of the previous
+                    // argument, and also its type when no type is given. The first argument
+                    // defaults to an input of type logic.
+                    let (direction, datatype) = match (direction, datatype) {
+                        (Some(direction), datatype) => (
+                            subroutine_direction(direction),
+                            datatype.unwrap_or(String::from("logic")),
+                        ),
+                        (None, Some(datatype)) => match ret.last() {
+                            Some(prev) => (prev.direction.clone(), datatype),
+                            None => (SvPortDirection::Input, datatype),
+                        },
+                        (None, None) => match ret.last() {
+                            Some(prev) => (prev.direction.clone(), prev.datatype.clone()),
+                            None => (SvPortDirection::Input, String::from("logic")),
+                        },
+                    };
+
+                    ret.push(SvArgument {
+                        identifier: id,
+                        direction,
+                        datatype,
+                    });
+                }
+            }
+
+            RefNode::TfPortDeclaration(x) => {
+                let (_, direction, _, datatype, ids, _) = &x.nodes;
+                let datatype =
+                    source_text(syntax_tree.get_str(datatype)).unwrap_or(String::from("logic"));
+
+                for id in ids {
+                    match id {
+                        RefNode::PortIdentifier(y) => ret.push(SvArgument {
+                            identifier: identifier(RefNode::PortIdentifier(y), syntax_tree)
+                                .unwrap(),
+                            direction: subroutine_direction(direction),
+                            datatype: datatype.clone(),
+                        }),
+
+                        _ => (),
+                    }
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+fn subroutine_direction(direction: &sv_parser::TfPortDirection) -> SvPortDirection {
+    match direction {
+        sv_parser::TfPortDirection::PortDirection(x) => match x.as_ref() {
+            sv_parser::PortDirection::Inout(_) => SvPortDirection::Inout,
+            sv_parser::PortDirection::Input(_) => SvPortDirection::Input,
+            sv_parser::PortDirection::Output(_) => SvPortDirection::Output,
+            sv_parser::PortDirection::Ref(_) => SvPortDirection::Ref,
+        },
+        sv_parser::TfPortDirection::ConstRef(_) => SvPortDirection::Ref,
+    }
+}
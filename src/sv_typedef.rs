@@ -0,0 +1,242 @@
+use crate::structures::{
+    SvDataType, SvPackedDimension, SvParameter, SvTypedef, SvTypedefKind, SvTypedefMember,
+};
+use crate::sv_const_eval::{const_cast, const_expression};
+use crate::sv_misc::{get_string, identifier, keyword, source_text, span};
+use crate::sv_port::{port_packeddim_ansi, port_unpackeddim_ansi, port_width_ansi};
+use crate::sv_primlit_integral::{usize_to_primlit, SvPrimaryLiteralIntegral};
+use sv_parser::{RefNode, SyntaxTree};
+
+/// Extracts a typedef of a data type, e.g. a struct, union, enum or vector.
+///
+/// The values of the members of an enum are resolved against the parameters in `scope`, and have
+/// the width and signedness of the base type of the enum.
+///
+/// # Examples
+///
+/// ```
+/// # use python_svdata::parse_sv_file;
+/// # use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+/// let svdata = parse_sv_file("tests/systemverilog/typedefs.sv", None, None, false).unwrap();
+/// let members = &svdata.packages[0].typedefs[2].members;
+///
+/// let exp = |x: usize| SvPrimaryLiteralIntegral {
+///     data_01: vec![x],
+///     data_xz: None,
+///     size: 3,
+///     signed: false,
+/// };
+///
+/// assert_eq!(members[0].value, Some(exp(0)));
+/// assert_eq!(members[1].value, Some(exp(4)));
+/// assert_eq!(members[2].value, Some(exp(5)));
+/// ```
+pub fn typedef_declaration(
+    p: &sv_parser::TypeDeclarationDataType,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> SvTypedef {
+    let (_, datatype, id, dimensions, _) = &p.nodes;
+
+    let mut ret = SvTypedef {
+        identifier: identifier(RefNode::TypeIdentifier(id), syntax_tree).unwrap(),
+        kind: SvTypedefKind::Scalar,
+        datatype: None,
+        packed_dimensions: Vec::new(),
+        unpacked_dimensions: Vec::new(),
+        members: Vec::new(),
+        span: span(RefNode::TypeDeclarationDataType(p), syntax_tree),
+    };
+
+    for dimension in dimensions {
+        ret.unpacked_dimensions.extend(port_unpackeddim_ansi(
+            RefNode::VariableDimension(dimension),
+            syntax_tree,
+        ));
+    }
+
+    match datatype {
+        sv_parser::DataType::StructUnion(x) => {
+            let (struct_union, _, members, dimensions) = &x.nodes;
+
+            ret.kind = match struct_union {
+                sv_parser::StructUnion::Struct(_) => SvTypedefKind::Struct,
+                _ => SvTypedefKind::Union,
+            };
+
+            for dimension in dimensions {
+                ret.packed_dimensions.extend(port_packeddim_ansi(
+                    RefNode::PackedDimension(dimension),
+                    syntax_tree,
+                ));
+            }
+
+            // Only the members of this struct, not those of any nested struct
+            let (first, rest) = &members.nodes.1;
+            for member in std::iter::once(first).chain(rest) {
+                ret.members
+                    .extend(typedef_struct_members(member, syntax_tree));
+            }
+        }
+
+        sv_parser::DataType::Enum(x) => {
+            let (_, base, _, dimensions) = &x.nodes;
+
+            ret.kind = SvTypedefKind::Enum;
+            if let Some(base) = base {
+                ret.datatype = source_text(syntax_tree.get_str(base));
+                ret.packed_dimensions =
+                    port_packeddim_ansi(RefNode::EnumBaseType(base), syntax_tree);
+            }
+
+            let (width, signed) = typedef_enum_base(base, &ret.packed_dimensions, syntax_tree);
+
+            for dimension in dimensions {
+                ret.packed_dimensions.extend(port_packeddim_ansi(
+                    RefNode::PackedDimension(dimension),
+                    syntax_tree,
+                ));
+            }
+
+            // The first member without an explicit value is 0, every other one follows the previous
+            let mut next: Option<SvPrimaryLiteralIntegral> = width.map(|_| usize_to_primlit(0));
+
+            for node in &**x {
+                match node {
+                    RefNode::EnumNameDeclaration(y) => {
+                        let mut member = typedef_enum_member(y, syntax_tree, scope);
+
+                        if member.expression.is_none() {
+                            member.value = next;
+                        }
+
+                        // The value is assigned to the base type, which wraps around a value
+                        // that follows the largest one
+                        member.value = match (member.value, width) {
+                            (Some(value), Some(width)) => const_cast(value, width, signed),
+                            (value, _) => value,
+                        };
+
+                        next = match (&member.value, width) {
+                            (Some(value), Some(_)) => Some(value.add_primlit(usize_to_primlit(1))),
+                            _ => None,
+                        };
+
+                        ret.members.push(member);
+                    }
+
+                    _ => (),
+                }
+            }
+        }
+
+        _ => {
+            ret.datatype = source_text(syntax_tree.get_str(datatype));
+            ret.packed_dimensions = port_packeddim_ansi(RefNode::DataType(datatype), syntax_tree);
+        }
+    }
+
+    ret
+}
+
+fn typedef_struct_members(
+    m: &sv_parser::StructUnionMember,
+    syntax_tree: &SyntaxTree,
+) -> Vec<SvTypedefMember> {
+    let (_, _, datatype, assignments, _) = &m.nodes;
+    let mut ret: Vec<SvTypedefMember> = Vec::new();
+
+    for node in assignments {
+        match node {
+            RefNode::VariableDeclAssignmentVariable(x) => {
+                let (id, dimensions, _) = &x.nodes;
+                let mut member = SvTypedefMember {
+                    identifier: identifier(RefNode::VariableIdentifier(id), syntax_tree).unwrap(),
+                    datatype: source_text(syntax_tree.get_str(datatype)),
+                    packed_dimensions: port_packeddim_ansi(
+                        RefNode::DataTypeOrVoid(datatype),
+                        syntax_tree,
+                    ),
+                    unpacked_dimensions: Vec::new(),
+                    expression: None,
+                    value: None,
+                };
+
+                for dimension in dimensions {
+                    member.unpacked_dimensions.extend(port_unpackeddim_ansi(
+                        RefNode::VariableDimension(dimension),
+                        syntax_tree,
+                    ));
+                }
+
+                ret.push(member);
+            }
+
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+fn typedef_enum_member(
+    m: &sv_parser::EnumNameDeclaration,
+    syntax_tree: &SyntaxTree,
+    scope: &[SvParameter],
+) -> SvTypedefMember {
+    let (id, _, assignment) = &m.nodes;
+
+    let (expression, value) = match assignment {
+        Some((_, x)) => (
+            get_string(RefNode::ConstantExpression(x), syntax_tree),
+            const_expression(x, syntax_tree, scope),
+        ),
+        None => (None, None),
+    };
+
+    SvTypedefMember {
+        identifier: identifier(RefNode::EnumIdentifier(id), syntax_tree).unwrap(),
+        datatype: None,
+        packed_dimensions: Vec::new(),
+        unpacked_dimensions: Vec::new(),
+        expression,
+        value,
+    }
+}
+
+// The width and signedness of the base type of an enum, which is an int if there is none. The
+// width is None if the base type is a typedef or its packed dimensions are not integer literals.
+fn typedef_enum_base(
+    base: &Option<sv_parser::EnumBaseType>,
+    packed_dimensions: &Vec<SvPackedDimension>,
+    syntax_tree: &SyntaxTree,
+) -> (Option<usize>, bool) {
+    let (signing, default_signed) = match base {
+        None => return (Some(32), true),
+        Some(sv_parser::EnumBaseType::Atom(x)) => (&x.nodes.1, true),
+        Some(sv_parser::EnumBaseType::Vector(x)) => (&x.nodes.1, false),
+        Some(sv_parser::EnumBaseType::Type(_)) => return (None, false),
+    };
+
+    let base = base.as_ref().unwrap();
+    let datatype = match keyword(RefNode::EnumBaseType(base), syntax_tree).as_deref() {
+        Some("bit") => SvDataType::Bit,
+        Some("logic") => SvDataType::Logic,
+        Some("reg") => SvDataType::Reg,
+        Some("byte") => SvDataType::Byte,
+        Some("shortint") => SvDataType::Shortint,
+        Some("int") => SvDataType::Int,
+        Some("longint") => SvDataType::Longint,
+        Some("integer") => SvDataType::Integer,
+        Some("time") => SvDataType::Time,
+        _ => return (None, false),
+    };
+
+    let signed = match signing {
+        Some(sv_parser::Signing::Signed(_)) => true,
+        Some(sv_parser::Signing::Unsigned(_)) => false,
+        None => default_signed && datatype != SvDataType::Time,
+    };
+
+    (port_width_ansi(&datatype, packed_dimensions), signed)
+}
@@ -0,0 +1,149 @@
+use proptest::prelude::*;
+use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+use serde_json::json;
+
+// Literals whose padding bits, unsized widths or X/Z extension have broken the round trip before.
+const SEEDS: [&str; 14] = [
+    "'0",
+    "'1",
+    "'x",
+    "'z",
+    "42",
+    "-5",
+    "1'b1",
+    "1'sb1",
+    "4'dz",
+    "65'h1_ffff_ffff_ffff_ffff",
+    "65'sh1_ffff_ffff_ffff_ffff",
+    "70'bx",
+    "130'sbz1",
+    "'h1_0000_0000_0000_0000",
+];
+
+fn bits(xz: bool) -> impl Strategy<Value = Vec<char>> {
+    let bit = if xz {
+        prop_oneof![4 => Just('0'), 4 => Just('1'), 1 => Just('x'), 1 => Just('z')].boxed()
+    } else {
+        prop_oneof![Just('0'), Just('1')].boxed()
+    };
+
+    prop::collection::vec(bit, 1..300)
+}
+
+fn primlit(xz: bool) -> impl Strategy<Value = SvPrimaryLiteralIntegral> {
+    (bits(xz), any::<bool>()).prop_map(|(bits, signed)| {
+        SvPrimaryLiteralIntegral::from_bit_vector(&bits, signed).unwrap()
+    })
+}
+
+// Rendering keeps the width and the signedness, so the parsed value must be identical, not only equal after _minimum_width.
+fn assert_round_trip(value: &SvPrimaryLiteralIntegral, rendered: String) {
+    assert_eq!(
+        SvPrimaryLiteralIntegral::from_sv_literal(&rendered).as_ref(),
+        Ok(value),
+        "{} did not parse back",
+        rendered
+    );
+}
+
+#[test]
+fn seeds_round_trip() {
+    for seed in SEEDS {
+        let value = SvPrimaryLiteralIntegral::from_sv_literal(seed).unwrap();
+
+        assert_round_trip(&value, value.to_string());
+        assert_round_trip(&value, value.to_string_radix(2));
+        if !value.contains_xz() {
+            assert_round_trip(&value, value.to_string_radix(8));
+            assert_round_trip(&value, value.to_string_radix(16));
+        }
+    }
+}
+
+// Serializing to JSON and back must give an identical value as well.
+fn assert_json_round_trip(value: &SvPrimaryLiteralIntegral) {
+    let json = serde_json::to_string(value).unwrap();
+
+    assert_eq!(
+        &serde_json::from_str::<SvPrimaryLiteralIntegral>(&json).unwrap(),
+        value,
+        "{} did not deserialize back",
+        json
+    );
+}
+
+#[test]
+fn seeds_json_round_trip() {
+    for seed in SEEDS {
+        assert_json_round_trip(&SvPrimaryLiteralIntegral::from_sv_literal(seed).unwrap());
+    }
+}
+
+#[test]
+fn json_representation() {
+    let value = SvPrimaryLiteralIntegral::from_sv_literal("4'b1x0z").unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"size":4,"signed":false,"data_01":[9],"data_xz":[5]}"#
+    );
+    assert_eq!(
+        serde_json::from_value::<SvPrimaryLiteralIntegral>(json!({
+            "size": 70,
+            "signed": true,
+            "data_01": [1, 2],
+        }))
+        .unwrap(),
+        SvPrimaryLiteralIntegral {
+            data_01: vec![1, 2],
+            data_xz: None,
+            size: 70,
+            signed: true,
+        }
+    );
+}
+
+#[test]
+fn json_rejects_invalid_values() {
+    let invalid = [
+        // Too few elements for the width
+        json!({"size": 70, "signed": false, "data_01": [1], "data_xz": null}),
+        // Too many elements for the width
+        json!({"size": 3, "signed": false, "data_01": [1, 0], "data_xz": null}),
+        // Mismatched lengths of data_01 and data_xz
+        json!({"size": 70, "signed": false, "data_01": [1, 0], "data_xz": [1]}),
+        // Bits set beyond the width
+        json!({"size": 3, "signed": false, "data_01": [8], "data_xz": null}),
+        json!({"size": 3, "signed": false, "data_01": [0], "data_xz": [15]}),
+    ];
+
+    for value in invalid {
+        assert!(
+            serde_json::from_value::<SvPrimaryLiteralIntegral>(value.clone()).is_err(),
+            "{} was accepted",
+            value
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn json_round_trips(value in primlit(true)) {
+        assert_json_round_trip(&value);
+    }
+
+    #[test]
+    fn display_round_trips(value in primlit(true)) {
+        assert_round_trip(&value, value.to_string());
+    }
+
+    #[test]
+    fn binary_round_trips(value in primlit(true)) {
+        assert_round_trip(&value, value.to_string_radix(2));
+    }
+
+    #[test]
+    fn radix_round_trips(value in primlit(false), radix in prop_oneof![Just(2), Just(8), Just(16)]) {
+        assert_round_trip(&value, value.to_string_radix(radix));
+    }
+}
@@ -0,0 +1,86 @@
+use python_svdata::sv_primlit_integral::SvPrimaryLiteralIntegral;
+
+// The widths around the element boundaries, up to the widest ones in the documented examples.
+fn widths() -> Vec<usize> {
+    let bits = usize::BITS as usize;
+
+    vec![1, 2, bits - 1, bits, bits + 1, 2 * bits - 1, 2 * bits, 2 * bits + 1]
+}
+
+// All ones, only the MSB set, and an X or a Z in the MSB above ones, which covers the values
+// whose bits are set up to the top of every element.
+fn values(width: usize, signed: bool) -> Vec<SvPrimaryLiteralIntegral> {
+    ['1', '0', 'x', 'z']
+        .into_iter()
+        .map(|rest| {
+            let mut bits = vec![if rest == '0' { '1' } else { rest }; width];
+            bits[1..].fill(if rest == '0' { '0' } else { '1' });
+
+            SvPrimaryLiteralIntegral::from_bit_vector(&bits, signed).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn shifts_and_rotations() {
+    for width in widths() {
+        for signed in [false, true] {
+            for value in values(width, signed) {
+                for n in 0..=width + 1 {
+                    assert_eq!(value.ror(n).rol(n), value, "{} rotated by {}", value, n);
+                    assert_eq!(value.lsr(n).size, width);
+                    assert_eq!(value.asr(n).size, width);
+                    assert_eq!(value.lsl(n).size, width + n);
+                }
+
+                if !value.contains_xz() {
+                    assert!(value.lsr(width).is_zero());
+                    assert_eq!(value.inv().inv(), value);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn sign_extension() {
+    for width in widths() {
+        for value in values(width, true) {
+            let mut extended = value.clone();
+            extended._sign_extend();
+            assert_eq!(extended.size % usize::BITS as usize, 0);
+
+            extended._truncate(width);
+            assert_eq!(extended, value);
+
+            let mut left = value.clone();
+            let mut right = values(1, true)[0].clone();
+            left._matched_sign_extend(&mut right);
+            assert_eq!(left.size, right.size);
+        }
+    }
+}
+
+#[test]
+fn minimum_width_and_truncation() {
+    for width in widths() {
+        for signed in [false, true] {
+            for value in values(width, signed) {
+                if !value.contains_xz() {
+                    let mut minimized = value.clone();
+                    minimized._minimum_width();
+                    assert!(minimized.size <= width);
+                }
+
+                for size in 1..=width {
+                    let mut truncated = value.clone();
+                    truncated._truncate(size);
+                    assert_eq!(truncated.size, size);
+                    assert_eq!(truncated.data_01.len(), size.div_ceil(usize::BITS as usize));
+                }
+
+                let _ = value.wildcard_eq(value.clone());
+            }
+        }
+    }
+}
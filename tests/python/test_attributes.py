@@ -0,0 +1,27 @@
+from python_svdata import read_sv_file
+
+module = read_sv_file("tests/systemverilog/attributes.sv").modules[0]
+
+
+def test_module_attributes() -> None:
+    assert module.attributes == [("keep", '"true"'), ("dont_touch", None)]
+
+
+def test_module_doc_comment() -> None:
+    assert module.doc_comment == "A module with attributes.\nIt has two ports."
+
+
+def test_port_attributes() -> None:
+    assert [port.attributes for port in module.ports] == [
+        [],
+        [("mark_debug", '"true"')],
+        [],
+    ]
+
+
+def test_port_doc_comments() -> None:
+    assert [port.doc_comment for port in module.ports] == [
+        "The clock.",
+        None,
+        "The output.",
+    ]
@@ -0,0 +1,45 @@
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/classes.sv")
+base_driver = sv_data.classes[0]
+fifo_driver = sv_data.classes[1]
+
+
+def test_class_identifiers() -> None:
+    assert [x.identifier for x in sv_data.classes] == [
+        "base_driver",
+        "fifo_driver",
+        "fifo_entry",
+        "module_entry",
+    ]
+    assert sv_data.packages[0].functions == []
+    assert sv_data.packages[0].tasks == []
+
+
+def test_class_inheritance() -> None:
+    assert base_driver.extends is None
+    assert base_driver.is_virtual
+    assert fifo_driver.extends == "base_driver"
+    assert not fifo_driver.is_virtual
+
+
+def test_class_parameters() -> None:
+    assert base_driver.parameters == []
+    assert [x.identifier for x in fifo_driver.parameters] == ["DEPTH"]
+    assert fifo_driver.parameters[0].value.to_int() == 4
+
+
+def test_class_parameters_not_in_scope() -> None:
+    assert sv_data.packages[0].parameters == []
+    assert sv_data.modules[0].parameters == []
+
+
+def test_class_methods() -> None:
+    assert [x.identifier for x in fifo_driver.functions] == ["drive", "size"]
+    assert [x.return_type for x in fifo_driver.functions] == ["void", "int unsigned"]
+    assert [x.identifier for x in fifo_driver.tasks] == ["flush"]
+    assert [x.identifier for x in fifo_driver.tasks[0].arguments] == ["cycles"]
+
+    assert base_driver.functions == []
+    assert [x.identifier for x in base_driver.tasks] == ["run"]
+    assert [x.identifier for x in sv_data.classes[2].functions] == ["hidden"]
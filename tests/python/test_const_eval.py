@@ -0,0 +1,35 @@
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/const_eval.sv")
+parameters = sv_data.modules[0].parameters
+
+
+def test_folded_parameters() -> None:
+    assert parameters[1].value.data_01[0] == 15
+    assert parameters[2].value.data_01[0] == 16
+    assert parameters[3].value.data_01[0] == 31
+    assert parameters[4].value.data_01[0] == 3
+    assert parameters[5].value < parameters[0].value
+    assert parameters[9].value.data_01[0] == 1
+
+
+def test_declared_type_parameters() -> None:
+    assert parameters[10].value.size == 4
+    assert parameters[10].value.data_01[0] == 15
+    assert parameters[11].value.size == 32
+    assert parameters[11].value.to_int() == -8
+    assert parameters[12].value.size == 8
+    assert parameters[12].value.data_01[0] == 255
+    assert parameters[13].value.size == 8
+    assert parameters[13].value.data_01[0] == 1
+
+
+def test_unresolved_parameters() -> None:
+    assert parameters[6].value is None
+    assert parameters[7].value is None
+    assert parameters[8].value is None
+
+
+def test_body_localparam() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+    assert sv_data.modules[0].parameters[3].value.data_01[0] == 16
@@ -0,0 +1,61 @@
+from typing import Dict, List, Optional
+
+from python_svdata import read_sv_file
+
+
+def test_defines() -> None:
+    module = read_sv_file(
+        "tests/systemverilog/defines_module.sv",
+        defines={"SYNTHESIS": None, "WIDTH": "8"},
+    ).modules[0]
+
+    assert [port.identifier for port in module.ports] == ["synth_only", "a"]
+    assert module.parameters[0].identifier == "WIDTH"
+    assert module.parameters[0].expression == "8"
+
+
+def test_defines_undefined() -> None:
+    module = read_sv_file(
+        "tests/systemverilog/defines_module.sv", defines={"WIDTH": "8"}
+    ).modules[0]
+
+    assert [port.identifier for port in module.ports] == ["a"]
+
+
+def conditional_modules(defines: Dict[str, Optional[str]]) -> List[str]:
+    sv_data = read_sv_file(
+        "tests/systemverilog/conditional_compilation.sv", defines=defines
+    )
+    return [module.identifier for module in sv_data.modules]
+
+
+def test_conditional_compilation_undefined() -> None:
+    assert conditional_modules({}) == ["sim_only", "target_generic", "sim_other"]
+
+
+def test_conditional_compilation_ifdef() -> None:
+    assert conditional_modules({"SYNTH": None}) == [
+        "synth_only",
+        "target_generic",
+        "synth_other",
+    ]
+
+
+def test_conditional_compilation_elsif() -> None:
+    assert conditional_modules({"ASIC": None}) == [
+        "sim_only",
+        "target_asic",
+        "sim_other",
+    ]
+
+
+def test_conditional_compilation_nested() -> None:
+    assert conditional_modules({"SYNTH": None, "FPGA": "1"}) == [
+        "synth_only",
+        "target_fpga",
+        "synth_fpga",
+    ]
+    assert conditional_modules({"FPGA": None, "ASIC": None}) == [
+        "sim_only",
+        "target_fpga",
+    ]
@@ -0,0 +1,45 @@
+from python_svdata import SvDiff, read_sv_file
+
+old = read_sv_file("tests/systemverilog/diff_old.sv")
+new = read_sv_file("tests/systemverilog/diff_new.sv")
+
+
+def test_diff_modules() -> None:
+    diff = old.diff(new)
+
+    assert isinstance(diff, SvDiff)
+    assert diff.added_modules == ["diff_added"]
+    assert diff.removed_modules == ["diff_removed"]
+    assert [x.identifier for x in diff.changed_modules] == ["diff_kept"]
+    assert not diff.is_empty()
+
+
+def test_diff_ports() -> None:
+    module = old.diff(new).changed_modules[0]
+
+    assert module.added_ports == ["ready"]
+    assert module.removed_ports == []
+    assert module.renamed_ports == [("clk", "clock")]
+    assert module.retyped_ports == ["valid"]
+
+
+def test_diff_parameters() -> None:
+    module = old.diff(new).changed_modules[0]
+
+    assert module.added_parameters == ["COUNT"]
+    assert module.removed_parameters == ["DEPTH"]
+    assert module.changed_parameters == ["WIDTH"]
+
+
+def test_diff_reversed() -> None:
+    diff = new.diff(old)
+
+    assert diff.added_modules == ["diff_removed"]
+    assert diff.removed_modules == ["diff_added"]
+    assert diff.changed_modules[0].renamed_ports == [("clock", "clk")]
+    assert diff.changed_modules[0].removed_ports == ["ready"]
+
+
+def test_diff_unchanged() -> None:
+    assert old.diff(old).is_empty()
+    assert SvDiff().is_empty()
@@ -0,0 +1,39 @@
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/directives.sv")
+
+
+def test_directive_names() -> None:
+    assert [directive.name for directive in sv_data.directives] == [
+        "timescale",
+        "default_nettype",
+        "celldefine",
+        "endcelldefine",
+        "resetall",
+        "default_nettype",
+    ]
+
+
+def test_directive_arguments() -> None:
+    assert [directive.arguments for directive in sv_data.directives] == [
+        ["1ns", "1ps"],
+        ["none"],
+        [],
+        [],
+        [],
+        ["wire"],
+    ]
+
+
+def test_directive_location() -> None:
+    assert (
+        sv_data.directives[1].location
+        == "tests/systemverilog/directives.sv at line 2, column 1"
+    )
+
+
+def test_directive_modules() -> None:
+    assert [module.identifier for module in sv_data.modules] == [
+        "directives_cell",
+        "directives_module",
+    ]
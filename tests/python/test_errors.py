@@ -0,0 +1,34 @@
+import pytest
+
+from python_svdata import ParseError, read_sv_file
+
+
+def test_parse_error() -> None:
+    with pytest.raises(ParseError, match=r"error_parse\.sv at line \d+, column \d+: "):
+        read_sv_file("tests/systemverilog/error_parse.sv")
+
+
+def test_parse_error_is_value_error() -> None:
+    with pytest.raises(ValueError):
+        read_sv_file("tests/systemverilog/error_parse.sv")
+
+
+def test_file_not_found() -> None:
+    with pytest.raises(IOError, match="does_not_exist.sv"):
+        read_sv_file("tests/systemverilog/does_not_exist.sv")
+
+
+def test_module_redefinition_warning() -> None:
+    sv_data = read_sv_file("tests/systemverilog/module_redefinition.sv")
+
+    assert [x.identifier for x in sv_data.modules] == ["redefined", "redefined"]
+    assert sv_data.warnings == [
+        "Module redefined is declared twice, in "
+        "tests/systemverilog/module_redefinition.sv at line 1, column 1 and in "
+        "tests/systemverilog/module_redefinition.sv at line 7, column 1."
+    ]
+
+
+def test_module_redefinition_strict() -> None:
+    with pytest.raises(ValueError, match=r"line 1, column 1 and in .* line 7, column 1"):
+        read_sv_file("tests/systemverilog/module_redefinition.sv", strict=True)
@@ -0,0 +1,55 @@
+from python_svdata import SvGenerateKind, read_sv_file
+
+generate_top = read_sv_file("tests/systemverilog/generate.sv").modules[1]
+gen_lane, gen_pair, gen_log = generate_top.generate_blocks
+
+
+def test_generate_for() -> None:
+    assert gen_lane.kind == SvGenerateKind.For
+    assert gen_lane.label == "gen_lane"
+    assert gen_lane.genvar == "i"
+    assert gen_lane.init == "0"
+    assert gen_lane.condition == "i<LANES"
+    assert gen_lane.step == "i++"
+    assert gen_lane.count == 4
+    assert gen_lane.indices == [0, 1, 2, 3]
+
+
+def test_generate_for_items() -> None:
+    assert [x.hierarchical_instance for x in gen_lane.instances] == ["u_lane"]
+    assert [x.identifier for x in gen_lane.signals] == ["lane_b"]
+    assert gen_lane.blocks == []
+
+
+def test_generate_for_step() -> None:
+    assert gen_pair.init == "LANES-1"
+    assert gen_pair.step == "j-=2"
+    assert gen_pair.count == 2
+    assert gen_pair.indices == [3, 1]
+
+
+def test_generate_for_unresolved() -> None:
+    assert gen_log.label == "gen_log"
+    assert gen_log.count is None
+    assert gen_log.indices is None
+
+
+def test_generate_if() -> None:
+    gen_extra, gen_none = gen_pair.blocks
+
+    assert gen_extra.kind == SvGenerateKind.If
+    assert gen_extra.label == "gen_extra"
+    assert gen_extra.condition == "USE_EXTRA"
+    assert [x.hierarchical_instance for x in gen_extra.instances] == ["u_extra"]
+
+    assert gen_none.kind == SvGenerateKind.Else
+    assert gen_none.label == "gen_none"
+    assert gen_none.condition is None
+    assert gen_none.instances == []
+
+    assert gen_pair.instances == []
+
+
+def test_generate_module_items() -> None:
+    assert generate_top.instances == []
+    assert generate_top.signals == []
@@ -0,0 +1,66 @@
+import pytest
+
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/hierarchy.sv")
+hierarchy = sv_data.hierarchy()
+
+
+def test_hierarchy_children() -> None:
+    assert hierarchy.children["hierarchy_top"] == ["hierarchy_mid", "black_box"]
+    assert hierarchy.children["hierarchy_mid"] == ["hierarchy_leaf"]
+    assert hierarchy.children["hierarchy_leaf"] == []
+
+
+def test_hierarchy_black_boxes() -> None:
+    assert hierarchy.black_boxes == {"hierarchy_top": ["black_box"]}
+
+
+def test_hierarchy_cycles() -> None:
+    assert hierarchy.cycles == [["hierarchy_ping", "hierarchy_pong", "hierarchy_ping"]]
+
+
+def test_elaborate_paths() -> None:
+    design = sv_data.elaborate("hierarchy_top")
+
+    assert design.top == "hierarchy_top"
+    assert [(x.path, x.module_identifier) for x in design.instances] == [
+        ("hierarchy_top", "hierarchy_top"),
+        ("hierarchy_top.u_mid_0", "hierarchy_mid"),
+        ("hierarchy_top.u_mid_0.u_leaf", "hierarchy_leaf"),
+        ("hierarchy_top.u_mid_1", "hierarchy_mid"),
+        ("hierarchy_top.u_mid_1.u_leaf", "hierarchy_leaf"),
+        ("hierarchy_top.u_black_box", "black_box"),
+    ]
+
+
+def test_elaborate_leaves() -> None:
+    design = sv_data.elaborate("hierarchy_top")
+
+    assert [x.path for x in design.leaves()] == [
+        "hierarchy_top.u_mid_0.u_leaf",
+        "hierarchy_top.u_mid_1.u_leaf",
+        "hierarchy_top.u_black_box",
+    ]
+    assert [x.path for x in design.black_boxes()] == ["hierarchy_top.u_black_box"]
+    assert sv_data.elaborate("hierarchy_leaf").instances[0].leaf
+
+
+def test_elaborate_generate() -> None:
+    design = sv_data.elaborate("hierarchy_generate")
+
+    assert [x.path for x in design.instances] == [
+        "hierarchy_generate",
+        "hierarchy_generate.gen_leaf[0].u_leaf",
+        "hierarchy_generate.gen_leaf[1].u_leaf",
+        "hierarchy_generate.genblk2[2].u_leaf",
+        "hierarchy_generate.genblk2[1].u_leaf",
+    ]
+    assert hierarchy.children["hierarchy_generate"] == ["hierarchy_leaf"]
+
+
+def test_elaborate_errors() -> None:
+    with pytest.raises(ValueError, match="hierarchy_ping -> hierarchy_pong"):
+        sv_data.elaborate("hierarchy_ping")
+    with pytest.raises(ValueError, match="not declared"):
+        sv_data.elaborate("missing")
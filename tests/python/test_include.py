@@ -0,0 +1,18 @@
+import pytest
+
+from python_svdata import read_sv_file
+
+
+def test_include_dirs() -> None:
+    module = read_sv_file(
+        "tests/systemverilog/include_module.sv",
+        include_dirs=["tests/systemverilog/include"],
+    ).modules[0]
+
+    assert module.identifier == "include_module"
+    assert module.parameters[0].identifier == "WIDTH"
+
+
+def test_include_not_found() -> None:
+    with pytest.raises(ValueError, match="does_not_exist.svh"):
+        read_sv_file("tests/systemverilog/error_include.sv")
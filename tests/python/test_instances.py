@@ -0,0 +1,65 @@
+from python_svdata import SvInstance, SvPortConnectionKind, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/instances.sv")
+child = sv_data.modules[0]
+parent = sv_data.modules[1]
+u_named = parent.instances[0]
+u_ordered = parent.instances[1]
+u_implicit = parent.instances[2]
+u_empty = parent.instances[3]
+
+
+def test_instance_names() -> None:
+    assert u_named.module_identifier == "child"
+    assert u_named.hierarchical_instance == "u_named"
+    assert u_ordered.hierarchical_instance == "u_ordered"
+
+
+def test_instance_parameter_overrides() -> None:
+    assert u_named.parameter_overrides == [["WIDTH", "8"], ["DEPTH", "4*2"]]
+    assert u_ordered.parameter_overrides == [["8"], ["2"]]
+
+
+def test_instance_parameter_values() -> None:
+    assert [x.to_int() for x in u_named.parameter_values] == [8, 8]
+    assert [x.to_int() for x in u_ordered.parameter_values] == [8, 2]
+
+
+def test_instance_resolve_parameters() -> None:
+    def values(instance: SvInstance) -> dict[str, int]:
+        return {
+            x.identifier: x.value.to_int()
+            for x in instance.resolve_parameters(child.parameters)
+        }
+
+    assert values(u_named) == {"WIDTH": 8, "DEPTH": 8, "SIZE": 64}
+    assert values(u_ordered) == {"WIDTH": 8, "DEPTH": 2, "SIZE": 16}
+    assert [x.value.to_int() for x in child.parameters] == [1, 1, 1]
+
+    expressions = [x.expression for x in u_named.resolve_parameters(child.parameters)]
+    assert expressions == ["8", "4*2", "WIDTH*DEPTH"]
+
+
+def test_instance_empty_parameter_override() -> None:
+    assert u_empty.parameter_overrides == [["WIDTH", "8"], ["DEPTH", ""]]
+    assert u_empty.parameter_values[1] is None
+
+    parameters = u_empty.resolve_parameters(child.parameters)
+    assert [x.value.to_int() for x in parameters] == [8, 1, 8]
+    assert [x.expression for x in parameters] == ["8", "1", "WIDTH*DEPTH"]
+
+
+def test_instance_connections() -> None:
+    assert u_named.connections == [["a", "x"], ["b", "y&x[0]"], ["c", "z"]]
+    assert u_ordered.connections == [["x"], ["y[0]"], ["z"]]
+
+
+def test_instance_connection_kinds() -> None:
+    assert u_named.connection_kinds == [SvPortConnectionKind.Explicit] * 3
+    assert u_ordered.connection_kinds == [SvPortConnectionKind.Explicit] * 3
+    assert u_implicit.connections == [["a", ""], ["b", "y"], []]
+    assert u_implicit.connection_kinds == [
+        SvPortConnectionKind.Implicit,
+        SvPortConnectionKind.Explicit,
+        SvPortConnectionKind.Wildcard,
+    ]
@@ -0,0 +1,62 @@
+from python_svdata import SvDataKind, SvPortDirection, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/interface.sv")
+bus_if = sv_data.interfaces[0]
+bus_user = sv_data.modules[0]
+
+
+def test_interface_name() -> None:
+    assert bus_if.identifier == "bus_if"
+
+
+def test_interface_ports() -> None:
+    assert bus_if.ports[0].identifier == "clk"
+    assert bus_if.ports[0].direction == SvPortDirection.Input
+
+
+def test_interface_modports() -> None:
+    assert [modport.identifier for modport in bus_if.modports] == [
+        "producer",
+        "consumer",
+    ]
+
+    assert bus_if.modports[0].ports == [
+        ("clk", SvPortDirection.Input),
+        ("ready", SvPortDirection.Input),
+        ("valid", SvPortDirection.Output),
+        ("data", SvPortDirection.Output),
+    ]
+    assert bus_if.modports[1].ports == [
+        ("clk", SvPortDirection.Input),
+        ("valid", SvPortDirection.Input),
+        ("data", SvPortDirection.Input),
+        ("ready", SvPortDirection.Output),
+    ]
+
+
+def test_interface_port() -> None:
+    in_bus = bus_user.ports[1]
+    assert in_bus.identifier == "in_bus"
+    assert in_bus.datakind == SvDataKind.Interface
+    assert in_bus.interface_name == "bus_if"
+    assert in_bus.modport_name == "consumer"
+
+    out_bus = bus_user.ports[2]
+    assert out_bus.interface_name == "bus_if"
+    assert out_bus.modport_name == "producer"
+    assert out_bus.unpacked_dimensions == [("2", None)]
+
+    any_bus = bus_user.ports[3]
+    assert any_bus.datakind == SvDataKind.Interface
+    assert any_bus.interface_name is None
+    assert any_bus.modport_name == "producer"
+
+
+def test_interface_port_data_ports() -> None:
+    for port in (bus_user.ports[0], bus_user.ports[4]):
+        assert port.interface_name is None
+        assert port.modport_name is None
+
+    assert bus_user.ports[0].datakind == SvDataKind.Net
+    assert bus_user.ports[4].datakind == SvDataKind.Variable
+    assert bus_user.ports[4].direction == SvPortDirection.Output
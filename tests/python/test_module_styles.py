@@ -0,0 +1,14 @@
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/module_styles.sv")
+
+
+def test_ansi_module() -> None:
+    assert [x.identifier for x in sv_data.modules] == ["styles_ansi"]
+    assert sv_data.modules[0].is_ansi
+
+
+def test_nonansi_module() -> None:
+    # Non-ANSI modules are not extracted yet, only reported
+    assert len(sv_data.warnings) == 1
+    assert "Non-ANSI module styles_nonansi" in sv_data.warnings[0]
@@ -0,0 +1,34 @@
+from python_svdata import SvParamType, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+parameters_module = sv_data.modules[0]
+parameters_module_body = sv_data.modules[1]
+
+
+def test_port_parameters() -> None:
+    assert parameters_module.parameters[0].identifier == "WIDTH"
+    assert parameters_module.parameters[0].paramtype == SvParamType.Parameter
+    assert parameters_module.parameters[0].expression == "8"
+
+    assert parameters_module.parameters[1].identifier == "DEPTH"
+    assert parameters_module.parameters[1].paramtype == SvParamType.LocalParam
+
+
+def test_body_parameters() -> None:
+    assert parameters_module.parameters[2].identifier == "BODY_PARAM"
+    assert parameters_module.parameters[2].paramtype == SvParamType.LocalParam
+
+    assert parameters_module.parameters[3].identifier == "BODY_LOCALPARAM"
+    assert parameters_module.parameters[3].paramtype == SvParamType.LocalParam
+    assert parameters_module.parameters[3].expression == "WIDTH*2"
+
+
+def test_body_parameters_without_port_list() -> None:
+    assert parameters_module_body.parameters[0].identifier == "BODY_PARAM"
+    assert parameters_module_body.parameters[0].paramtype == SvParamType.Parameter
+
+    assert parameters_module_body.parameters[1].identifier == "BODY_LOCALPARAM"
+    assert parameters_module_body.parameters[1].paramtype == SvParamType.LocalParam
+
+    assert parameters_module_body.parameters[2].identifier == "NO_DEFAULT"
+    assert parameters_module_body.parameters[2].expression is None
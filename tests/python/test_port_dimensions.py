@@ -0,0 +1,38 @@
+from python_svdata import read_sv_file
+
+port_dimensions = read_sv_file("tests/systemverilog/port_dimensions.sv").modules[0]
+
+
+def test_packed_dimensions() -> None:
+    assert port_dimensions.ports[0].packed_dimensions == [("3", "0"), ("7", "0")]
+    assert port_dimensions.ports[0].unpacked_dimensions == []
+
+
+def test_unpacked_dimensions() -> None:
+    assert port_dimensions.ports[1].packed_dimensions == []
+    assert port_dimensions.ports[1].unpacked_dimensions == [("0", "3")]
+
+
+def test_dimensions_raw_text() -> None:
+    assert port_dimensions.ports[2].packed_dimensions == [("WIDTH-1", "0")]
+    assert port_dimensions.ports[2].unpacked_dimensions == [("4", None), ("2", None)]
+
+
+def test_no_dimensions() -> None:
+    assert port_dimensions.ports[3].packed_dimensions == []
+    assert port_dimensions.ports[3].unpacked_dimensions == []
+
+
+def test_width() -> None:
+    assert port_dimensions.ports[0].width == 32
+    assert port_dimensions.ports[1].width == 1
+    assert port_dimensions.ports[3].width == 1
+
+
+def test_width_parameter() -> None:
+    assert port_dimensions.ports[2].width is None
+
+
+def test_width_unsized_decimal_overflow() -> None:
+    assert port_dimensions.ports[4].width is None
+    assert port_dimensions.ports[5].width == 2
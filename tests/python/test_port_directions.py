@@ -0,0 +1,30 @@
+from python_svdata import SvPortDirection, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/port_directions.sv")
+module = sv_data.modules[0]
+inherited = sv_data.modules[1]
+
+
+def test_port_by_name() -> None:
+    port = module.port_by_name("c")
+    assert port is not None
+    assert port.direction == SvPortDirection.Inout
+    assert module.port_by_name("f") is None
+
+
+def test_port_directions() -> None:
+    assert [x.identifier for x in module.inputs()] == ["a", "d"]
+    assert [x.identifier for x in module.outputs()] == ["b", "e"]
+    assert [x.identifier for x in module.inouts()] == ["c"]
+
+
+def test_port_directions_inherited() -> None:
+    assert [x.direction for x in inherited.ports] == [
+        SvPortDirection.Inout,
+        SvPortDirection.Input,
+        SvPortDirection.Input,
+        SvPortDirection.Output,
+        SvPortDirection.Output,
+    ]
+    assert inherited.ports[4].width == 4
+    assert str(inherited.ports[4].direction) == "Output"
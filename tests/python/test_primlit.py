@@ -0,0 +1,156 @@
+import pytest
+
+from python_svdata import (
+    SvPrimaryLiteralIntegral,
+    bit_0,
+    bit_1,
+    logic_0,
+    logic_1,
+    logic_x,
+    read_sv_file,
+)
+
+sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+width = sv_data.modules[0].parameters[0].value
+depth = sv_data.modules[0].parameters[1].value
+
+
+def test_value() -> None:
+    assert width.data_01 == [8]
+    assert width.data_xz is None
+    assert width.size == 32
+    assert width.signed
+    assert repr(width) == "32'sh00000008"
+
+
+def test_new() -> None:
+    a = SvPrimaryLiteralIntegral(70, signed=True)
+    assert a.data_01 == [0, 0]
+    assert a.data_xz is None
+    assert a.size == 70
+    assert a.signed
+
+
+def test_arithmetic() -> None:
+    assert (width + depth).data_01 == [24]
+    assert (width + depth).size == 33
+    assert (depth - width).data_01 == [8]
+    assert (width * depth).data_01 == [128]
+
+
+def test_negation() -> None:
+    assert -width < width
+    assert -(-width) == width
+
+
+def test_negation_unsigned() -> None:
+    with pytest.raises(ValueError):
+        -SvPrimaryLiteralIntegral()
+
+
+def test_shifts() -> None:
+    assert (width << 1).data_01 == [16]
+    assert (width << 1).size == 33
+    assert (depth >> 2).data_01 == [4]
+    assert (depth >> 2).size == 32
+
+
+def test_bitwise() -> None:
+    assert (width & depth).data_01 == [0]
+    assert (width | depth).data_01 == [24]
+    assert (width ^ depth).data_01 == [24]
+    assert (~width).data_01 == [4294967287]
+
+
+def test_comparisons() -> None:
+    assert width < depth
+    assert width <= depth
+    assert depth > width
+    assert depth >= width
+    assert width == width
+    assert width != depth
+    assert (width > depth) is False
+
+
+def test_comparisons_x() -> None:
+    assert (logic_x() == width) is None
+    assert (logic_x() != width) is None
+    assert (logic_x() < width) is None
+
+
+def test_constants() -> None:
+    assert (logic_0().data_01, logic_0().data_xz) == ([0], [0])
+    assert (logic_1().data_01, logic_1().data_xz) == ([1], [0])
+    assert (logic_x().data_01, logic_x().data_xz) == ([0], [1])
+    assert (bit_0().data_01, bit_0().data_xz) == ([0], None)
+    assert (bit_1().data_01, bit_1().data_xz) == ([1], None)
+    assert all(x.size == 1 and not x.signed for x in [logic_0(), bit_1()])
+    assert (logic_1() == bit_1()) is True
+    assert (logic_x() == bit_1()) is None
+
+
+def test_from_binary_str() -> None:
+    a = SvPrimaryLiteralIntegral.from_binary_str("10xz")
+    assert a.data_01 == [9]
+    assert a.data_xz == [3]
+    assert a.size == 4
+    assert not a.signed
+
+    b = SvPrimaryLiteralIntegral.from_binary_str("1000", signed=True)
+    assert b.data_01 == [8]
+    assert b.data_xz is None
+    assert b.signed
+    assert b < SvPrimaryLiteralIntegral.from_binary_str("0000", signed=True)
+
+
+def test_from_binary_str_invalid() -> None:
+    with pytest.raises(ValueError):
+        SvPrimaryLiteralIntegral.from_binary_str("")
+    with pytest.raises(ValueError):
+        SvPrimaryLiteralIntegral.from_binary_str("10?1")
+
+
+def test_to_int() -> None:
+    assert width.to_int() == 8
+    assert (-width).to_int() == -8
+    assert SvPrimaryLiteralIntegral.from_binary_str("1" * 100).to_int() == 2**100 - 1
+    assert SvPrimaryLiteralIntegral.from_binary_str("1" * 100, signed=True).to_int() == -1
+    with pytest.raises(ValueError):
+        logic_x().to_int()
+
+
+@pytest.mark.parametrize(
+    "value, bits, signed",
+    [
+        (0, 1, False),
+        (2**64, 65, False),
+        (2**130 - 1, 130, False),
+        (-(2**69), 70, True),
+        (-1, 200, True),
+        (2**99 + 12345, 101, True),
+    ],
+)
+def test_from_int(value: int, bits: int, signed: bool) -> None:
+    a = SvPrimaryLiteralIntegral.from_int(value, bits, signed)
+
+    assert a.size == bits
+    assert a.signed == signed
+    assert a.data_xz is None
+    assert a.to_int() == value
+
+
+def test_from_int_two_complement() -> None:
+    a = SvPrimaryLiteralIntegral.from_int(-1, 70, signed=True)
+    assert a.data_01 == [2**64 - 1, 63]
+
+    b = SvPrimaryLiteralIntegral.from_int(2**64, 65)
+    assert b.data_01 == [0, 1]
+
+
+def test_from_int_out_of_range() -> None:
+    with pytest.raises(ValueError):
+        SvPrimaryLiteralIntegral.from_int(256, 8)
+    with pytest.raises(ValueError):
+        SvPrimaryLiteralIntegral.from_int(-1, 8)
+    with pytest.raises(ValueError):
+        SvPrimaryLiteralIntegral.from_int(128, 8, signed=True)
@@ -0,0 +1,92 @@
+import pytest
+
+from python_svdata import read_sv_file, read_sv_files
+
+
+def test_read_sv_files() -> None:
+    sv_data = read_sv_files(
+        ["tests/systemverilog/ansi_module.sv", "tests/systemverilog/interface.sv"]
+    )
+
+    assert sv_data.modules[0].identifier == "ansi_module_a"
+    assert sv_data.modules[0].filepath == "tests/systemverilog/ansi_module.sv"
+    assert sv_data.interfaces[0].identifier == "bus_if"
+    assert sv_data.interfaces[0].filepath == "tests/systemverilog/interface.sv"
+
+
+@pytest.mark.parametrize(
+    "file_path",
+    [
+        "tests/systemverilog/ansi_module.sv",
+        "tests/systemverilog/instances.sv",
+        "tests/systemverilog/interface.sv",
+        "tests/systemverilog/parameters_module.sv",
+        "tests/systemverilog/port_dimensions.sv",
+        "tests/systemverilog/subroutines.sv",
+        "tests/systemverilog/typedefs.sv",
+    ],
+)
+def test_read_sv_files_matches_read_sv_file(file_path: str) -> None:
+    assert read_sv_files([file_path]) == read_sv_file(file_path)
+
+
+def test_read_sv_files_duplicate_module() -> None:
+    sv_data = read_sv_files(
+        [
+            "tests/systemverilog/ansi_module.sv",
+            "tests/systemverilog/duplicate_module.sv",
+        ]
+    )
+
+    assert [x.identifier for x in sv_data.modules] == ["ansi_module_a", "ansi_module_a"]
+    assert len(sv_data.warnings) == 1
+    assert "ansi_module_a" in sv_data.warnings[0]
+
+    with pytest.raises(ValueError) as e:
+        read_sv_files(
+            [
+                "tests/systemverilog/ansi_module.sv",
+                "tests/systemverilog/duplicate_module.sv",
+            ],
+            strict=True,
+        )
+
+    assert "ansi_module_a" in str(e.value)
+    assert "tests/systemverilog/ansi_module.sv" in str(e.value)
+    assert "tests/systemverilog/duplicate_module.sv" in str(e.value)
+
+
+def test_read_sv_files_parallel() -> None:
+    file_paths = [
+        "tests/systemverilog/ansi_module.sv",
+        "tests/systemverilog/interface.sv",
+        "tests/systemverilog/parameters_module.sv",
+    ]
+    sv_data = read_sv_files(file_paths)
+    sv_data_parallel = read_sv_files(file_paths, parallel=True)
+
+    assert [x.identifier for x in sv_data_parallel.modules] == [
+        x.identifier for x in sv_data.modules
+    ]
+    assert [x.filepath for x in sv_data_parallel.modules] == [
+        x.filepath for x in sv_data.modules
+    ]
+    assert [x.identifier for x in sv_data_parallel.interfaces] == [
+        x.identifier for x in sv_data.interfaces
+    ]
+
+
+def test_read_sv_files_parallel_duplicate_module() -> None:
+    with pytest.raises(ValueError) as e:
+        read_sv_files(
+            [
+                "tests/systemverilog/ansi_module.sv",
+                "tests/systemverilog/duplicate_module.sv",
+            ],
+            parallel=True,
+            strict=True,
+        )
+
+    assert "ansi_module_a" in str(e.value)
+    assert "tests/systemverilog/ansi_module.sv" in str(e.value)
+    assert "tests/systemverilog/duplicate_module.sv" in str(e.value)
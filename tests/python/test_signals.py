@@ -0,0 +1,50 @@
+from python_svdata import SvDataKind, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/signals.sv")
+module = sv_data.modules[0]
+signals = {signal.identifier: signal for signal in module.signals}
+
+
+def test_signal_identifiers() -> None:
+    assert [signal.identifier for signal in module.signals] == [
+        "w",
+        "v",
+        "ws",
+        "mem",
+        "r",
+        "count",
+        "i",
+        "v_implicit",
+        "packed2d",
+    ]
+
+
+def test_nets() -> None:
+    assert signals["w"].datakind == SvDataKind.Net
+    assert signals["w"].datatype is None
+    assert signals["w"].packed_dimensions == [("3", "0")]
+    assert signals["v"].packed_dimensions == [("3", "0")]
+    assert not signals["w"].signed
+
+    assert signals["ws"].datakind == SvDataKind.Net
+    assert signals["ws"].packed_dimensions == [("7", "0")]
+    assert signals["ws"].signed
+
+
+def test_variables() -> None:
+    assert signals["mem"].datakind == SvDataKind.Variable
+    assert signals["mem"].datatype == "logic [7:0]"
+    assert signals["mem"].packed_dimensions == [("7", "0")]
+    assert signals["mem"].unpacked_dimensions == [("4", None)]
+
+    assert signals["r"].datatype == "reg"
+    assert signals["r"].packed_dimensions == []
+
+    assert signals["count"].datatype == "int unsigned"
+    assert not signals["count"].signed
+    assert signals["i"].signed
+
+    assert signals["v_implicit"].datakind == SvDataKind.Variable
+    assert signals["v_implicit"].datatype is None
+
+    assert signals["packed2d"].packed_dimensions == [("1", "0"), ("3", "0")]
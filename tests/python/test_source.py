@@ -0,0 +1,62 @@
+from pathlib import Path
+
+import pytest
+
+from python_svdata import ParseError, SvSource, read_sv_file
+
+source = SvSource("tests/systemverilog/interface.sv")
+
+
+def test_source_file_path() -> None:
+    assert source.file_path == "tests/systemverilog/interface.sv"
+
+
+def test_source_data() -> None:
+    sv_data = read_sv_file("tests/systemverilog/interface.sv")
+
+    assert source.data().to_dict() == sv_data.to_dict()
+    assert [x.identifier for x in source.modules()] == ["bus_user"]
+    assert [x.identifier for x in source.interfaces()] == ["bus_if"]
+    assert source.packages() == []
+
+
+def test_source_repeated_queries() -> None:
+    assert [x.identifier for x in source.modules()] == [
+        x.identifier for x in source.modules()
+    ]
+
+
+def test_source_walk() -> None:
+    identifiers = []
+
+    def visit(kind: str, text: str, enter: bool) -> None:
+        if enter and kind == "ModportItem":
+            identifiers.append(text.split("(")[0].strip())
+
+    source.walk(visit)
+    source.walk(visit)
+    assert identifiers == ["producer", "consumer"] * 2
+
+
+def test_source_strict() -> None:
+    warnings = SvSource("tests/systemverilog/warnings.sv")
+
+    assert len(warnings.data().warnings) == 3
+    with pytest.raises(ValueError):
+        warnings.data(strict=True)
+
+
+def test_source_locations_after_change(tmp_path: Path) -> None:
+    path = tmp_path / "warnings.sv"
+    path.write_text(Path("tests/systemverilog/warnings.sv").read_text())
+    warnings = SvSource(str(path))
+    expected = warnings.data().warnings
+
+    path.write_text("\n" * 10)
+    assert warnings.data().warnings == expected
+    assert "at line 4, column 1" in expected[0]
+
+
+def test_source_parse_error() -> None:
+    with pytest.raises(ParseError):
+        SvSource("tests/systemverilog/error_parse.sv")
@@ -0,0 +1,91 @@
+from python_svdata import line_column, read_sv_file
+
+
+def source(path: str, span: tuple) -> str:
+    with open(path, "rb") as f:
+        return f.read()[span[0] : span[1]].decode()
+
+
+def test_module_span() -> None:
+    path = "tests/systemverilog/ansi_module.sv"
+    module = read_sv_file(path).modules[0]
+
+    assert module.span[0] == 0
+    assert source(path, module.span).startswith("module ansi_module_a (")
+    assert source(path, module.span).endswith("endmodule")
+
+
+def test_port_span() -> None:
+    path = "tests/systemverilog/ansi_module.sv"
+    ports = read_sv_file(path).modules[0].ports
+
+    assert source(path, ports[0].span) == "input var logic a"
+    assert source(path, ports[1].span) == "input var logic b"
+    assert line_column(path, ports[0].span[0]) == (2, 3)
+    assert line_column(path, ports[1].span[0]) == (3, 3)
+
+
+def test_package_and_parameter_span() -> None:
+    path = "tests/systemverilog/const_eval.sv"
+    package = read_sv_file(path).packages[0]
+
+    assert source(path, package.span).startswith("package const_eval_pkg;")
+    assert source(path, package.span).endswith("endpackage")
+    assert source(path, package.parameters[0].span) == "PKG_PARAM = 3"
+
+
+def test_signal_span() -> None:
+    path = "tests/systemverilog/signals.sv"
+    signals = read_sv_file(path).modules[0].signals
+
+    assert source(path, signals[0].span) == "w"
+    assert source(path, signals[1].span) == "v"
+    assert line_column(path, signals[0].span[0]) == (5, 14)
+
+
+def test_interface_span() -> None:
+    path = "tests/systemverilog/interface.sv"
+    interface = read_sv_file(path).interfaces[0]
+
+    assert source(path, interface.span).startswith("interface bus_if (")
+    assert source(path, interface.span).endswith("endinterface")
+
+
+def test_typedef_span() -> None:
+    path = "tests/systemverilog/typedefs.sv"
+    typedefs = read_sv_file(path).packages[0].typedefs
+
+    assert source(path, typedefs[0].span) == "typedef logic [7:0] byte_t;"
+    assert line_column(path, typedefs[1].span[0]) == (4, 3)
+
+
+def test_subroutine_span() -> None:
+    path = "tests/systemverilog/subroutines.sv"
+    package = read_sv_file(path).packages[0]
+
+    assert source(path, package.functions[1].span) == (
+        "function void clear(ref int count);\n    count = 0;\n  endfunction"
+    )
+    assert source(path, package.tasks[0].span).startswith("task wait_cycles(")
+    assert source(path, package.tasks[0].span).endswith("endtask")
+
+
+def test_instance_span() -> None:
+    path = "tests/systemverilog/instances.sv"
+    instances = read_sv_file(path).modules[1].instances
+
+    assert source(path, instances[1].span) == "child #(8, 2) u_ordered (x, y[0], z);"
+    assert line_column(path, instances[0].span[0]) == (18, 3)
+
+
+def test_directive_span() -> None:
+    path = "tests/systemverilog/directives.sv"
+    directives = read_sv_file(path).directives
+
+    assert source(path, directives[0].span) == "`timescale 1ns / 1ps"
+    assert line_column(path, directives[1].span[0]) == (2, 1)
+
+
+def test_line_column_out_of_range() -> None:
+    assert line_column("tests/systemverilog/ansi_module.sv", 10000) is None
+    assert line_column("tests/systemverilog/missing.sv", 0) is None
@@ -0,0 +1,48 @@
+from python_svdata import SvPortDirection, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/subroutines.sv")
+subroutines_pkg = sv_data.packages[0]
+subroutines_module = sv_data.modules[0]
+
+
+def test_package_functions() -> None:
+    assert [function.identifier for function in subroutines_pkg.functions] == [
+        "add",
+        "clear",
+    ]
+
+    add = subroutines_pkg.functions[0]
+    assert add.return_type == "logic [7:0]"
+    assert [argument.identifier for argument in add.arguments] == ["a", "b", "carry"]
+    assert [argument.direction for argument in add.arguments] == [
+        SvPortDirection.Input,
+        SvPortDirection.Input,
+        SvPortDirection.Output,
+    ]
+    assert [argument.datatype for argument in add.arguments] == [
+        "logic [7:0]",
+        "logic [7:0]",
+        "logic",
+    ]
+
+    clear = subroutines_pkg.functions[1]
+    assert clear.return_type == "void"
+    assert clear.arguments[0].direction == SvPortDirection.Ref
+    assert clear.arguments[0].datatype == "int"
+
+
+def test_package_tasks() -> None:
+    wait_cycles = subroutines_pkg.tasks[0]
+    assert wait_cycles.identifier == "wait_cycles"
+    assert wait_cycles.arguments[0].direction == SvPortDirection.Input
+    assert wait_cycles.arguments[0].datatype == "int"
+    assert wait_cycles.arguments[1].direction == SvPortDirection.Inout
+
+
+def test_module_functions() -> None:
+    double = subroutines_module.functions[0]
+    assert double.identifier == "double"
+    assert double.return_type == "int"
+    assert double.arguments[0].identifier == "value"
+    assert double.arguments[0].direction == SvPortDirection.Input
+    assert double.arguments[0].datatype == "int"
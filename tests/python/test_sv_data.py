@@ -0,0 +1,189 @@
+import json
+import pickle
+from pathlib import Path
+
+import pytest
+
+from python_svdata import (
+    SvData,
+    read_sv_data,
+    read_sv_file,
+    read_sv_files,
+    write_sv_data,
+)
+
+
+def test_repr() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+
+    assert repr(sv_data) == "SvData(modules=2, packages=0, interfaces=0, classes=0)"
+    assert repr(SvData()) == "SvData(modules=0, packages=0, interfaces=0, classes=0)"
+
+
+def test_str() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+
+    assert "Identifier: parameters_module" in str(sv_data)
+
+
+def test_eq() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+
+    assert sv_data == read_sv_file("tests/systemverilog/parameters_module.sv")
+    assert sv_data != read_sv_file("tests/systemverilog/ansi_module.sv")
+    assert sv_data != SvData()
+    assert SvData() == SvData()
+
+
+def test_eq_other_type() -> None:
+    assert SvData() != "SvData"
+
+
+def test_pickle() -> None:
+    sv_data = read_sv_file("tests/systemverilog/typedefs.sv")
+    state = pickle.dumps(sv_data)
+
+    assert isinstance(state, bytes)
+    assert pickle.loads(state) == sv_data
+
+
+def test_pickle_parameter_values() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+    restored = pickle.loads(pickle.dumps(sv_data))
+
+    assert restored == sv_data
+    assert restored.modules[1].parameters[0].value.data_01 == [1024]
+
+
+def test_write_read_sv_data(tmp_path: Path) -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+    path = str(tmp_path / "design.json")
+
+    write_sv_data(sv_data, path)
+    restored = read_sv_data(path)
+
+    assert restored == sv_data
+    assert restored.modules[1].parameters[0].value.data_01 == [1024]
+    assert json.loads((tmp_path / "design.json").read_text()) == sv_data.to_dict()
+
+
+def test_read_sv_data_missing_fields(tmp_path: Path) -> None:
+    sv_data = read_sv_files(
+        ["tests/systemverilog/instances.sv", "tests/systemverilog/classes.sv"]
+    )
+    state = sv_data.to_dict()
+
+    for module in state["modules"]:
+        for instance in module["instances"]:
+            del instance["connection_kinds"]
+            del instance["span"]
+    for sv_class in state["classes"]:
+        del sv_class["span"]
+
+    (tmp_path / "old.json").write_text(json.dumps(state))
+    restored = read_sv_data(str(tmp_path / "old.json"))
+
+    assert restored.modules[1].instances[0].connection_kinds == []
+    assert restored.classes[0].span == (0, 0)
+    assert [x.identifier for x in restored.classes] == [
+        x.identifier for x in sv_data.classes
+    ]
+
+
+def test_write_read_sv_data_errors(tmp_path: Path) -> None:
+    with pytest.raises(ValueError, match="expected a .json file"):
+        write_sv_data(SvData(), str(tmp_path / "design.txt"))
+    with pytest.raises(IOError):
+        read_sv_data(str(tmp_path / "missing.json"))
+
+    (tmp_path / "invalid.json").write_text("{")
+    with pytest.raises(ValueError, match="invalid.json"):
+        read_sv_data(str(tmp_path / "invalid.json"))
+
+
+def test_to_dict() -> None:
+    sv_data = read_sv_file("tests/systemverilog/ansi_module.sv")
+    data = sv_data.to_dict()
+
+    assert sorted(data) == [
+        "classes",
+        "directives",
+        "interfaces",
+        "modules",
+        "packages",
+        "warnings",
+    ]
+    module = data["modules"][0]
+    assert module["identifier"] == "ansi_module_a"
+    assert [port["identifier"] for port in module["ports"]] == ["a", "b"]
+    assert module["ports"][0]["direction"] == "Input"
+    assert module["ports"][0]["datakind"] == "Variable"
+    assert module["ports"][0]["span"] == [25, 42]
+
+
+def test_to_dict_parameter_values() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+    value = sv_data.to_dict()["modules"][1]["parameters"][0]["value"]
+
+    assert value["data_01"] == [1024]
+    assert SvData().to_dict()["modules"] == []
+
+
+def test_iter() -> None:
+    sv_data = read_sv_file("tests/systemverilog/parameters_module.sv")
+
+    assert len(sv_data) == 2
+    assert [x.identifier for x in sv_data] == ["parameters_module", "parameters_module_body"]
+    assert len(SvData()) == 0
+
+
+def test_find_module() -> None:
+    sv_data = read_sv_file("tests/systemverilog/ansi_module.sv")
+    module = sv_data.find_module("ansi_module_a")
+
+    assert module is not None
+    assert module.filepath == "tests/systemverilog/ansi_module.sv"
+    assert [x.identifier for x in module.ports] == [x.identifier for x in sv_data.modules[0].ports]
+    assert sv_data.find_module("does_not_exist") is None
+
+
+def test_merge() -> None:
+    sv_data = read_sv_file("tests/systemverilog/ansi_module.sv")
+    sv_data.merge(read_sv_file("tests/systemverilog/interface.sv"))
+
+    assert sv_data.modules[0].identifier == "ansi_module_a"
+    assert sv_data.interfaces[0].identifier == "bus_if"
+
+
+def test_merge_duplicate_module() -> None:
+    sv_data = read_sv_file("tests/systemverilog/ansi_module.sv")
+    duplicate = read_sv_file("tests/systemverilog/duplicate_module.sv")
+
+    with pytest.raises(ValueError):
+        sv_data.merge(duplicate, strict=True)
+    assert sv_data == read_sv_file("tests/systemverilog/ansi_module.sv")
+
+    merged = read_sv_file("tests/systemverilog/ansi_module.sv")
+    merged.merge(duplicate)
+    assert len(merged.modules) == 2
+    assert len(merged.warnings) == 1
+
+    sv_data.merge(duplicate, overwrite=True)
+    assert sv_data.modules[0].filepath == "tests/systemverilog/duplicate_module.sv"
+
+
+def test_merge_duplicate_package() -> None:
+    sv_data = read_sv_file("tests/systemverilog/duplicate_package_a.sv")
+    duplicate = read_sv_file("tests/systemverilog/duplicate_package_b.sv")
+
+    with pytest.raises(ValueError) as e:
+        sv_data.merge(duplicate, strict=True)
+    assert str(e.value) == (
+        "Package shared_pkg is declared in both "
+        "tests/systemverilog/duplicate_package_a.sv and "
+        "tests/systemverilog/duplicate_package_b.sv."
+    )
+
+    sv_data.merge(duplicate, overwrite=True)
+    assert len(sv_data.packages) == 1
+    assert sv_data.packages[0].filepath == "tests/systemverilog/duplicate_package_b.sv"
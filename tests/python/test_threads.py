@@ -0,0 +1,42 @@
+import threading
+import time
+from pathlib import Path
+
+from python_svdata import read_sv_file
+
+
+def write_large_module(path: Path, identifier: str) -> None:
+    lines = [f"module {identifier} (input logic clk);"]
+    for i in range(4000):
+        lines.append(f"  logic [31:0] sig_{i};")
+        lines.append(f"  assign sig_{i} = 32'd{i} + sig_{i} * 2;")
+    lines.append("endmodule")
+    path.write_text("\n".join(lines))
+
+
+def test_read_sv_file_releases_gil(tmp_path: Path) -> None:
+    file_path = tmp_path / "large.sv"
+    write_large_module(file_path, "large_module")
+
+    ticks: list[float] = []
+    done = threading.Event()
+
+    def heartbeat() -> None:
+        while not done.is_set():
+            ticks.append(time.perf_counter())
+            time.sleep(0.001)
+
+    thread = threading.Thread(target=heartbeat)
+    thread.start()
+    try:
+        start = time.perf_counter()
+        sv_data = read_sv_file(str(file_path))
+        end = time.perf_counter()
+    finally:
+        done.set()
+        thread.join()
+
+    assert sv_data.modules[0].identifier == "large_module"
+
+    # With the GIL held, the heartbeat could not tick until the parse had finished
+    assert len([x for x in ticks if start < x < end]) > 10
@@ -0,0 +1,75 @@
+from python_svdata import SvTypedefKind, read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/typedefs.sv")
+typedefs_pkg = sv_data.packages[0]
+typedefs_module = sv_data.modules[0]
+
+
+def test_typedef_names() -> None:
+    assert [typedef.identifier for typedef in typedefs_pkg.typedefs] == [
+        "byte_t",
+        "packed_t",
+        "state_e",
+        "union_t",
+        "unpacked_t",
+    ]
+
+
+def test_typedef_scalar() -> None:
+    byte_t = typedefs_pkg.typedefs[0]
+    assert byte_t.kind == SvTypedefKind.Scalar
+    assert byte_t.datatype == "logic [7:0]"
+    assert byte_t.packed_dimensions == [("7", "0")]
+    assert byte_t.members == []
+
+
+def test_typedef_struct() -> None:
+    packed_t = typedefs_pkg.typedefs[1]
+    assert packed_t.kind == SvTypedefKind.Struct
+    assert [member.identifier for member in packed_t.members] == ["a", "b", "c"]
+    assert packed_t.members[0].datatype == "logic [3:0]"
+    assert packed_t.members[0].packed_dimensions == [("3", "0")]
+    assert packed_t.members[2].datatype == "logic"
+
+    unpacked_t = typedefs_pkg.typedefs[4]
+    assert unpacked_t.members[0].datatype == "int"
+    assert unpacked_t.members[0].unpacked_dimensions == [("4", None)]
+
+
+def test_typedef_enum() -> None:
+    state_e = typedefs_pkg.typedefs[2]
+    assert state_e.kind == SvTypedefKind.Enum
+    assert state_e.datatype == "logic [2:0]"
+    assert [member.identifier for member in state_e.members] == ["IDLE", "BUSY", "DONE"]
+    assert [member.expression for member in state_e.members] == [None, "4", None]
+
+
+def test_typedef_union() -> None:
+    union_t = typedefs_pkg.typedefs[3]
+    assert union_t.kind == SvTypedefKind.Union
+    assert union_t.members[1].datatype == "byte_t"
+
+
+def test_typedef_module() -> None:
+    count_t = typedefs_module.typedefs[0]
+    assert count_t.identifier == "count_t"
+    assert count_t.datatype == "int"
+    assert count_t.unpacked_dimensions == [("2", None)]
+
+
+def test_typedef_enum_values() -> None:
+    enums_pkg = read_sv_file("tests/systemverilog/enums.sv").packages[0]
+    values = {
+        typedef.identifier: [member.value for member in typedef.members]
+        for typedef in enums_pkg.typedefs
+    }
+
+    assert [value.to_int() for value in values["mixed_e"]] == [0, 2, 3]
+    assert [value.to_int() for value in values["wrap_e"]] == [3, 0, 1]
+    assert [value.to_int() for value in values["int_e"]] == [0, 5, 6]
+    assert [value.to_int() for value in values["byte_e"]] == [-1, 0]
+    assert [value.to_int() for value in values["hex_e"]] == [10, 11]
+
+    assert [(value.size, value.signed) for value in values["wrap_e"]] == [(2, False)] * 3
+    assert [(value.size, value.signed) for value in values["int_e"]] == [(32, True)] * 3
+    assert [(value.size, value.signed) for value in values["byte_e"]] == [(8, True)] * 2
@@ -0,0 +1,55 @@
+import pytest
+
+from python_svdata import ParseError, walk_sv
+
+
+def test_walk_port_identifiers() -> None:
+    identifiers = []
+
+    def visit(kind: str, text: str, enter: bool) -> None:
+        if enter and kind == "PortIdentifier":
+            identifiers.append(text.strip())
+
+    walk_sv("tests/systemverilog/port_directions.sv", visit)
+    assert identifiers[:5] == ["a", "b", "c", "d", "e"]
+
+
+def test_walk_enter_and_leave() -> None:
+    events = []
+    walk_sv(
+        "tests/systemverilog/ansi_module.sv",
+        lambda kind, text, enter: events.append((kind, enter)),
+    )
+
+    assert events[0] == ("SourceText", True)
+    assert events[-1] == ("SourceText", False)
+    assert events.count(("ModuleDeclarationAnsi", True)) == 1
+    assert events.count(("ModuleDeclarationAnsi", False)) == 1
+
+
+def test_walk_defines() -> None:
+    identifiers = []
+
+    def visit(kind: str, text: str, enter: bool) -> None:
+        if enter and kind == "PortIdentifier":
+            identifiers.append(text.strip())
+
+    walk_sv(
+        "tests/systemverilog/defines_module.sv",
+        visit,
+        defines={"SYNTHESIS": None, "WIDTH": "8"},
+    )
+    assert identifiers == ["synth_only", "a"]
+
+
+def test_walk_callback_exception() -> None:
+    def visit(kind: str, text: str, enter: bool) -> None:
+        raise KeyError(kind)
+
+    with pytest.raises(KeyError):
+        walk_sv("tests/systemverilog/ansi_module.sv", visit)
+
+
+def test_walk_parse_error() -> None:
+    with pytest.raises(ParseError):
+        walk_sv("tests/systemverilog/error_parse.sv", lambda *_: None)
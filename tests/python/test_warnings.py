@@ -0,0 +1,27 @@
+import pytest
+
+from python_svdata import read_sv_file
+
+sv_data = read_sv_file("tests/systemverilog/warnings.sv")
+
+
+def test_warnings() -> None:
+    assert sv_data.warnings == [
+        "Program warnings_program in tests/systemverilog/warnings.sv at line 4, column 1 is not supported.",
+        "Checker warnings_checker in tests/systemverilog/warnings.sv at line 7, column 1 is not supported.",
+        "Bind warnings_module in tests/systemverilog/warnings.sv at line 16, column 1 is not supported.",
+    ]
+
+
+def test_warnings_strict() -> None:
+    with pytest.raises(ValueError) as error:
+        read_sv_file("tests/systemverilog/warnings.sv", strict=True)
+
+    assert str(error.value) == "Unsupported constructs: " + " ".join(sv_data.warnings)
+    assert read_sv_file("tests/systemverilog/ansi_module.sv", strict=True).warnings == []
+
+
+def test_warnings_supported() -> None:
+    assert [x.identifier for x in sv_data.modules] == ["warnings_module"]
+    assert [x.identifier for x in sv_data.classes] == ["warnings_class"]
+    assert read_sv_file("tests/systemverilog/ansi_module.sv").warnings == []
@@ -0,0 +1,13 @@
+// A module with attributes.
+// It has two ports.
+(* keep = "true", dont_touch *)
+module attributes_module (
+  // The clock.
+  input var logic clk,
+  (* mark_debug = "true" *)
+  input var logic a, // Trailing comment of a.
+  /* The output. */
+  output var logic q
+);
+
+endmodule
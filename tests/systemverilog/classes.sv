@@ -0,0 +1,40 @@
+package classes_pkg;
+  virtual class base_driver #(type T = int);
+    pure virtual function void drive(T item);
+
+    virtual task run();
+    endtask
+  endclass
+
+  class fifo_driver #(type T = int, int DEPTH = 4) extends base_driver #(T);
+    localparam int WIDTH = 8;
+
+    T queue[$];
+
+    function new();
+      super.new();
+    endfunction
+
+    virtual function void drive(T item);
+      queue.push_back(item);
+    endfunction
+
+    function int unsigned size();
+      return queue.size();
+    endfunction
+
+    task flush(input int cycles);
+    endtask
+
+    class fifo_entry;
+      function void hidden();
+      endfunction
+    endclass
+  endclass
+endpackage
+
+module classes_module;
+  class module_entry #(int SIZE = 2);
+    localparam int HALF = SIZE / 2;
+  endclass
+endmodule
@@ -0,0 +1,35 @@
+`ifdef SYNTH
+module synth_only;
+endmodule
+`endif
+
+`ifndef SYNTH
+module sim_only;
+endmodule
+`endif
+
+`ifdef FPGA
+module target_fpga;
+endmodule
+`elsif ASIC
+module target_asic;
+endmodule
+`else
+module target_generic;
+endmodule
+`endif
+
+`ifdef SYNTH
+  `ifdef FPGA
+module synth_fpga;
+endmodule
+  `else
+module synth_other;
+endmodule
+  `endif
+`else
+  `ifndef FPGA
+module sim_other;
+endmodule
+  `endif
+`endif
@@ -0,0 +1,24 @@
+package const_eval_pkg;
+  localparam int PKG_PARAM = 3;
+endpackage
+
+module const_eval_module #(
+  parameter int WIDTH = 8,
+  localparam int N = WIDTH * 2 - 1
+) (
+  input var logic a
+);
+  localparam int SHIFTED = (1 << WIDTH) >> 4;
+  localparam int MASKED = N & 'hF | 'h10 ^ 1;
+  localparam int POWER = 2 ** 3 % 5;
+  localparam int QUOTIENT = -N / 3;
+  localparam int FROM_PACKAGE = const_eval_pkg::PKG_PARAM + 1;
+  localparam int FROM_FUNCTION = $clog2(WIDTH);
+  localparam int FROM_UNRESOLVED = FROM_FUNCTION + 1;
+  localparam int COMPARED = N < WIDTH * 2 == WIDTH >= 8;
+  localparam logic [3:0] TRUNCATED = 8'hff;
+  localparam int EXTENDED = 4'sb1000;
+  localparam NEGATED = -8'd1;
+  localparam SHIFTED_OUT = (8'h81 << 1) >> 1;
+
+endmodule
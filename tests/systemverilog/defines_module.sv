@@ -0,0 +1,10 @@
+module defines_module #(
+  parameter int WIDTH = `WIDTH
+) (
+`ifdef SYNTHESIS
+  input var logic synth_only,
+`endif
+  input var logic a
+);
+
+endmodule
@@ -0,0 +1,20 @@
+module diff_kept #(
+  parameter int WIDTH = 16,
+  parameter int COUNT = 2
+) (
+  input  logic       clock,
+  input  logic [7:0] data,
+  output logic [1:0] valid,
+  output logic       ready
+);
+endmodule
+
+module diff_unchanged (
+  input logic a
+);
+endmodule
+
+module diff_added (
+  input logic a
+);
+endmodule
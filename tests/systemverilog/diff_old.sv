@@ -0,0 +1,19 @@
+module diff_kept #(
+  parameter int WIDTH = 8,
+  parameter int DEPTH = 4
+) (
+  input  logic       clk,
+  input  logic [7:0] data,
+  output logic       valid
+);
+endmodule
+
+module diff_unchanged (
+  input logic a
+);
+endmodule
+
+module diff_removed (
+  input logic a
+);
+endmodule
@@ -0,0 +1,14 @@
+`timescale 1ns / 1ps
+`default_nettype none
+
+`celldefine
+module directives_cell (
+  input var logic a
+);
+endmodule
+`endcelldefine
+
+`resetall
+`default_nettype wire
+module directives_module;
+endmodule
@@ -0,0 +1,5 @@
+module ansi_module_a (
+  input var logic c
+);
+
+endmodule
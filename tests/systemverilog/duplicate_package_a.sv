@@ -0,0 +1,3 @@
+package shared_pkg;
+  localparam int WIDTH = 8;
+endpackage
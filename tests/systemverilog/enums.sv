@@ -0,0 +1,31 @@
+package enums_pkg;
+  localparam int BASE = 5;
+
+  typedef enum logic [1:0] {
+    A,
+    B = 2,
+    C
+  } mixed_e;
+
+  typedef enum logic [1:0] {
+    W0 = 2'd3,
+    W1,
+    W2
+  } wrap_e;
+
+  typedef enum {
+    I0,
+    I1 = BASE,
+    I2
+  } int_e;
+
+  typedef enum byte {
+    NEG = -1,
+    ZERO
+  } byte_e;
+
+  typedef enum bit [3:0] {
+    H0 = 4'ha,
+    H1
+  } hex_e;
+endpackage
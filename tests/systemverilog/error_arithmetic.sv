@@ -0,0 +1,5 @@
+module error_arithmetic #(
+  parameter logic [9223372036854775807:0][9223372036854775807:0] A = '0
+) ();
+
+endmodule
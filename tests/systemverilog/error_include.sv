@@ -0,0 +1,7 @@
+`include "does_not_exist.svh"
+
+module error_include (
+  input var logic a
+);
+
+endmodule
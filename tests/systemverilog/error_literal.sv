@@ -0,0 +1,5 @@
+module error_literal #(
+  parameter A = 99999999999999999999'd0
+) ();
+
+endmodule
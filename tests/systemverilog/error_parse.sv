@@ -0,0 +1,6 @@
+module error_parse (
+  input var logic a
+  input var logic b
+);
+
+endmodule
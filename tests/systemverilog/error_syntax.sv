@@ -0,0 +1,5 @@
+module error_syntax #(
+  localparam int A
+) ();
+
+endmodule
@@ -0,0 +1,40 @@
+module generate_lane (
+  input  logic a,
+  output logic b
+);
+  assign b = a;
+endmodule
+
+module generate_top #(
+  parameter int LANES = 4,
+  parameter bit USE_EXTRA = 1
+) (
+  input  logic [LANES-1:0] a,
+  output logic [LANES-1:0] b
+);
+  for (genvar i = 0; i < LANES; i++) begin : gen_lane
+    logic lane_b;
+
+    generate_lane u_lane (
+      .a(a[i]),
+      .b(lane_b)
+    );
+
+    assign b[i] = lane_b;
+  end
+
+  generate
+    for (genvar j = LANES - 1; j >= 0; j -= 2) begin : gen_pair
+      if (USE_EXTRA) begin : gen_extra
+        generate_lane u_extra (
+          .a(a[j]),
+          .b()
+        );
+      end else begin : gen_none
+      end
+    end
+  endgenerate
+
+  for (genvar k = 0; k < $clog2(LANES); k++) begin : gen_log
+  end
+endmodule
@@ -0,0 +1,47 @@
+module hierarchy_top (
+  input var logic a
+);
+  hierarchy_mid u_mid_0 (.a(a));
+  hierarchy_mid u_mid_1 (.a(a));
+  black_box u_black_box (.a(a));
+
+endmodule
+
+module hierarchy_mid (
+  input var logic a
+);
+  hierarchy_leaf u_leaf (.a(a));
+
+endmodule
+
+module hierarchy_leaf (
+  input var logic a
+);
+
+endmodule
+
+module hierarchy_generate (
+  input var logic a
+);
+  for (genvar i = 0; i < 2; i++) begin : gen_leaf
+    hierarchy_leaf u_leaf (.a(a));
+  end
+
+  for (genvar i = 2; i > 0; i--)
+    hierarchy_leaf u_leaf (.a(a));
+
+endmodule
+
+module hierarchy_ping (
+  input var logic a
+);
+  hierarchy_pong u_pong (.a(a));
+
+endmodule
+
+module hierarchy_pong (
+  input var logic a
+);
+  hierarchy_ping u_ping (.a(a));
+
+endmodule
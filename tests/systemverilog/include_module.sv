@@ -0,0 +1,7 @@
+module include_module #(
+  `include "include_params.svh"
+) (
+  input var logic a
+);
+
+endmodule
@@ -0,0 +1,37 @@
+module child #(
+  parameter int WIDTH = 1,
+  parameter int DEPTH = 1
+) (
+  input var logic [WIDTH-1:0] a,
+  input var logic b,
+  output var logic c
+);
+  localparam int SIZE = WIDTH * DEPTH;
+
+endmodule
+
+module parent (
+  input var logic [7:0] x,
+  input var logic y,
+  output var logic z
+);
+  child #(.WIDTH(8), .DEPTH(4 * 2)) u_named (
+    .a(x),
+    .b(y & x[0]),
+    .c(z)
+  );
+
+  child #(8, 2) u_ordered (x, y[0], z);
+
+  logic [7:0] a;
+  logic c;
+
+  child #(8, 2) u_implicit (
+    .a,
+    .b(y),
+    .*
+  );
+
+  child #(.WIDTH(8), .DEPTH()) u_empty (x, y, z);
+
+endmodule
@@ -0,0 +1,27 @@
+interface bus_if (
+  input var logic clk
+);
+  logic valid;
+  logic ready;
+  logic [7:0] data;
+
+  modport producer (
+    input clk, ready,
+    output valid, data
+  );
+
+  modport consumer (
+    input clk, valid, data,
+    output ready
+  );
+
+endinterface
+
+module bus_user (
+  input logic clk,
+  bus_if.consumer in_bus,
+  bus_if.producer out_bus [2],
+  interface.producer any_bus,
+  output logic done
+);
+endmodule
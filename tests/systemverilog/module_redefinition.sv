@@ -0,0 +1,11 @@
+module redefined (
+  input var logic a
+);
+
+endmodule
+
+module redefined (
+  input var logic b
+);
+
+endmodule
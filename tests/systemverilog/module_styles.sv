@@ -0,0 +1,10 @@
+module styles_ansi (
+  input  logic clk,
+  output logic ready
+);
+endmodule
+
+module styles_nonansi (clk, ready);
+  input  clk;
+  output ready;
+endmodule
@@ -0,0 +1,19 @@
+module parameters_module #(
+  parameter int WIDTH = 8,
+  localparam int DEPTH = 16
+) (
+  input var logic a
+);
+  parameter int BODY_PARAM = 4;
+  localparam int BODY_LOCALPARAM = WIDTH * 2;
+
+endmodule
+
+module parameters_module_body (
+  input var logic a
+);
+  parameter int BODY_PARAM = 1_024;
+  localparam int BODY_LOCALPARAM = 3;
+  parameter int NO_DEFAULT;
+
+endmodule
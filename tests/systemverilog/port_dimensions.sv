@@ -0,0 +1,12 @@
+module port_dimensions #(
+  parameter int WIDTH = 8
+) (
+  input var logic [3:0][7:0] a,
+  input var logic b [0:3],
+  input var logic [WIDTH-1:0] c [4][2],
+  output var logic d,
+  output var logic [4294967295:0] e,
+  output var logic [2147483647:2147483646] f
+);
+
+endmodule
@@ -0,0 +1,17 @@
+module port_directions (
+  input var logic a,
+  output var logic b,
+  inout wire logic c,
+  input var logic d,
+  output var logic e
+);
+
+endmodule
+
+module port_directions_inherited (
+  wire logic a,
+  input var logic b, c,
+  output var logic [3:0] d, e
+);
+
+endmodule
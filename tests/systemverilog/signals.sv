@@ -0,0 +1,24 @@
+module signals (
+  input  logic       clk,
+  output logic [7:0] q
+);
+  wire [3:0] w, v;
+  wire signed [7:0] ws;
+  logic [7:0] mem [4];
+  reg r;
+  int unsigned count;
+  integer i;
+  var v_implicit;
+  logic [1:0][3:0] packed2d;
+
+  function automatic int local_var;
+    int tmp;
+    return tmp;
+  endfunction
+
+  always_ff @(posedge clk) begin : blk
+    logic block_local;
+    q <= mem[0];
+  end
+
+endmodule
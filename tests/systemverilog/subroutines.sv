@@ -0,0 +1,28 @@
+package subroutines_pkg;
+  function automatic logic [7:0] add(input logic [7:0] a, b, output logic carry);
+    {carry, add} = a + b;
+  endfunction
+
+  function void clear(ref int count);
+    count = 0;
+  endfunction
+
+  task wait_cycles(int cycles, inout logic done);
+    done = 1'b1;
+  endtask
+
+  class helper;
+    function void method();
+    endfunction
+  endclass
+endpackage
+
+module subroutines_module (
+  input var logic a
+);
+  function int double;
+    input int value;
+    double = value * 2;
+  endfunction
+
+endmodule
@@ -0,0 +1,30 @@
+package typedefs_pkg;
+  typedef logic [7:0] byte_t;
+
+  typedef struct packed {
+    logic [3:0] a;
+    logic b, c;
+  } packed_t;
+
+  typedef enum logic [2:0] {
+    IDLE,
+    BUSY = 4,
+    DONE
+  } state_e;
+
+  typedef union packed {
+    logic [7:0] a;
+    byte_t b;
+  } union_t;
+
+  typedef struct {
+    int x [4];
+  } unpacked_t;
+endpackage
+
+module typedefs_module (
+  input var logic a
+);
+  typedef int count_t [2];
+
+endmodule
@@ -0,0 +1,16 @@
+class warnings_class;
+endclass
+
+program warnings_program;
+endprogram
+
+checker warnings_checker;
+endchecker
+
+module warnings_module (
+  input var logic a
+);
+
+endmodule
+
+bind warnings_module warnings_checker u_checker ();